@@ -154,5 +154,8 @@ fn testnet_genesis(
 			key: Some(root_key),
 		},
 		transaction_payment: Default::default(),
+		// The oracle authorities, which need an offchain worker key of type `ocw!` in the keystore
+		// of their node, are added with `add_authority` once their keys are inserted.
+		template_module: Default::default(),
 	}
 }
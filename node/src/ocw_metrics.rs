@@ -0,0 +1,89 @@
+//! Exports the metrics the offchain worker of the template pallet keeps in the offchain local
+//! storage, see `pallet_template::OcwMetrics`, to Prometheus.
+//!
+//! The metrics are read through the handler of the `offchain_localStorageGet` RPC, so Prometheus
+//! sees the same values an operator gets by querying the node.
+
+use codec::Decode;
+use futures_timer::Delay;
+use pallet_template::{OcwMetrics, METRICS_KEY};
+use sc_client_api::backend::OffchainStorage;
+use sc_rpc::{offchain::Offchain, DenyUnsafe};
+use sc_rpc_api::offchain::OffchainApiServer;
+use sp_core::{offchain::StorageKind, Bytes};
+use std::time::Duration;
+use substrate_prometheus_endpoint::{register, Gauge, PrometheusError, Registry, U64};
+
+/// How often the metrics are read from the offchain local storage.
+const POLL_INTERVAL: Duration = Duration::from_secs(6);
+
+/// The gauges of the fields of `OcwMetrics`.
+struct Metrics {
+	fetch_successes: Gauge<U64>,
+	fetch_failures: Gauge<U64>,
+	submissions: Gauge<U64>,
+	submission_latency_ms: Gauge<U64>,
+	last_processed_block: Gauge<U64>,
+}
+
+impl Metrics {
+	fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		let gauge = |name: &str, help: &str| register(Gauge::new(name, help)?, registry);
+		Ok(Self {
+			fetch_successes: gauge(
+				"substrate_ocw_fetch_successes",
+				"Number of values fetched by the offchain worker",
+			)?,
+			fetch_failures: gauge(
+				"substrate_ocw_fetch_failures",
+				"Number of failed fetches of the offchain worker",
+			)?,
+			submissions: gauge(
+				"substrate_ocw_submissions",
+				"Number of values submitted by the offchain worker",
+			)?,
+			submission_latency_ms: gauge(
+				"substrate_ocw_submission_latency_ms",
+				"Milliseconds between the start of the last run and its submission",
+			)?,
+			last_processed_block: gauge(
+				"substrate_ocw_last_processed_block",
+				"Last block processed by the offchain worker",
+			)?,
+		})
+	}
+
+	fn set(&self, metrics: &OcwMetrics) {
+		self.fetch_successes.set(metrics.fetch_successes);
+		self.fetch_failures.set(metrics.fetch_failures);
+		self.submissions.set(metrics.submissions);
+		self.submission_latency_ms.set(metrics.submission_latency_ms);
+		self.last_processed_block.set(metrics.last_processed_block);
+	}
+}
+
+/// Export the metrics of the offchain worker kept in `storage` to `registry`, every
+/// `POLL_INTERVAL`, until the node stops.
+pub async fn run<S: OffchainStorage + 'static>(storage: S, registry: Registry) {
+	let metrics = match Metrics::register(&registry) {
+		Ok(metrics) => metrics,
+		Err(e) => {
+			log::warn!("Failed to register the offchain worker metrics: {}", e);
+			return
+		},
+	};
+	let offchain = Offchain::new(storage, DenyUnsafe::No);
+
+	loop {
+		match offchain.get_local_storage(StorageKind::PERSISTENT, Bytes(METRICS_KEY.to_vec())) {
+			Ok(Some(raw)) => match OcwMetrics::decode(&mut &raw[..]) {
+				Ok(ocw_metrics) => metrics.set(&ocw_metrics),
+				Err(e) => log::warn!("Failed to decode the offchain worker metrics: {}", e),
+			},
+			// The offchain worker didn't run yet.
+			Ok(None) => {},
+			Err(e) => log::warn!("Failed to read the offchain worker metrics: {}", e),
+		}
+		Delay::new(POLL_INTERVAL).await;
+	}
+}
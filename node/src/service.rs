@@ -1,8 +1,9 @@
 //! Service and ServiceFactory implementation. Specialized wrapper over substrate service.
 
+use codec::Decode;
 use futures::FutureExt;
 use node_template_runtime::{self, opaque::Block, RuntimeApi};
-use sc_client_api::{Backend, BlockBackend};
+use sc_client_api::{backend::OffchainStorage, Backend, BlockBackend};
 use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
 use sc_consensus_grandpa::SharedVoterState;
 pub use sc_executor::NativeElseWasmExecutor;
@@ -10,6 +11,7 @@ use sc_service::{error::Error as ServiceError, Configuration, TaskManager, WarpS
 use sc_telemetry::{Telemetry, TelemetryWorker};
 use sc_transaction_pool_api::OffchainTransactionPoolFactory;
 use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
+use sp_core::offchain::STORAGE_PREFIX;
 use std::{sync::Arc, time::Duration};
 
 // Our native executor instance.
@@ -199,6 +201,16 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
 			.run(client.clone(), task_manager.spawn_handle())
 			.boxed(),
 		);
+
+		if let (Some(storage), Some(registry)) =
+			(backend.offchain_storage(), config.prometheus_registry())
+		{
+			task_manager.spawn_handle().spawn(
+				"offchain-worker-metrics",
+				"offchain-worker",
+				crate::ocw_metrics::run(storage, registry.clone()),
+			);
+		}
 	}
 
 	let role = config.role.clone();
@@ -207,14 +219,19 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
 	let name = config.network.node_name.clone();
 	let enable_grandpa = !config.disable_grandpa;
 	let prometheus_registry = config.prometheus_registry().cloned();
+	let offchain_storage = backend.offchain_storage();
 
 	let rpc_extensions_builder = {
 		let client = client.clone();
 		let pool = transaction_pool.clone();
 
-		Box::new(move |deny_unsafe, _| {
-			let deps =
-				crate::rpc::FullDeps { client: client.clone(), pool: pool.clone(), deny_unsafe };
+		Box::new(move |deny_unsafe, subscription_executor| {
+			let deps = crate::rpc::FullDeps {
+				client: client.clone(),
+				pool: pool.clone(),
+				deny_unsafe,
+				subscription_executor,
+			};
 			crate::rpc::create_full(deps).map_err(Into::into)
 		})
 	};
@@ -252,16 +269,29 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
 				select_chain,
 				block_import,
 				proposer_factory,
-				create_inherent_data_providers: move |_, ()| async move {
-					let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
-
-					let slot =
-						sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
-							*timestamp,
-							slot_duration,
-						);
-
-					Ok((slot, timestamp))
+				create_inherent_data_providers: move |_, ()| {
+					// The latest value fetched by the offchain worker of the default instance
+					// of the template pallet, which keeps it without a key scope.
+					let value = offchain_storage
+						.as_ref()
+						.and_then(|storage| {
+							storage.get(STORAGE_PREFIX, pallet_template::FETCHED_VALUE_KEY)
+						})
+						.and_then(|raw| u64::decode(&mut &raw[..]).ok());
+
+					async move {
+						let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
+
+						let slot =
+							sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+								*timestamp,
+								slot_duration,
+							);
+
+						let value = pallet_template::InherentDataProvider::new(value);
+
+						Ok((slot, timestamp, value))
+					}
 				},
 				force_authoring,
 				backoff_authoring_blocks,
@@ -0,0 +1,153 @@
+//! RPC interface of the template pallet, exposing the results of the offchain workers through
+//! `pallet_template::runtime_api::OcwApi`, so that operators can monitor the oracle without an
+//! indexer.
+
+#![warn(missing_docs)]
+
+use std::sync::Arc;
+
+use codec::Codec;
+use futures::{future, FutureExt, StreamExt};
+use jsonrpsee::{
+	core::{RpcResult, SubscriptionResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+	SubscriptionSink,
+};
+pub use pallet_template::runtime_api::OcwApi as OcwRuntimeApi;
+use pallet_template::{RoundIndex, RoundInfo};
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// The results of the offchain workers and the state of the rounds.
+#[rpc(client, server)]
+pub trait OcwApi<BlockHash, AccountId, BlockNumber> {
+	/// The latest value submitted by an offchain worker, at the best block unless `at` is given.
+	#[method(name = "ocw_latestValue")]
+	fn latest_value(&self, at: Option<BlockHash>) -> RpcResult<Option<u64>>;
+
+	/// The current round, at the best block unless `at` is given.
+	#[method(name = "ocw_roundInfo")]
+	fn round_info(&self, at: Option<BlockHash>) -> RpcResult<RoundInfo<BlockNumber>>;
+
+	/// The blocks with tasks the offchain workers didn't confirm yet, along with the number of
+	/// their tasks, at the best block unless `at` is given.
+	#[method(name = "ocw_pendingTasks")]
+	fn pending_tasks(&self, at: Option<BlockHash>) -> RpcResult<Vec<(BlockNumber, u32)>>;
+
+	/// The accounts of the offchain worker keys allowed to submit data, at the best block
+	/// unless `at` is given.
+	#[method(name = "ocw_authorities")]
+	fn authorities(&self, at: Option<BlockHash>) -> RpcResult<Vec<AccountId>>;
+
+	/// Subscribe to the rounds finalized in the finalized blocks, as their index and the median
+	/// of their submissions.
+	#[subscription(
+		name = "ocw_subscribeFinalizedRounds" => "ocw_finalizedRound",
+		unsubscribe = "ocw_unsubscribeFinalizedRounds",
+		item = (RoundIndex, u64),
+	)]
+	fn subscribe_finalized_rounds(&self);
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to the runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// Provides RPC methods to query the results of the offchain workers.
+pub struct Ocw<C, Block> {
+	client: Arc<C>,
+	executor: SubscriptionTaskExecutor,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Ocw<C, Block> {
+	/// Create a new `Ocw` RPC handler, spawning the subscriptions with `executor`.
+	pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
+		Self { client, executor, _marker: Default::default() }
+	}
+}
+
+/// The error of a failed call to the runtime API, described by `message`.
+fn runtime_error(message: &'static str, e: impl std::fmt::Display) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(Error::RuntimeError.into(), message, Some(e.to_string())))
+		.into()
+}
+
+impl<C, Block, AccountId, BlockNumber> OcwApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber>
+	for Ocw<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block>,
+	C: Send + Sync + 'static,
+	C::Api: OcwRuntimeApi<Block, AccountId, BlockNumber>,
+	AccountId: Codec + serde::Serialize + Send + Sync + 'static,
+	BlockNumber: Codec + serde::Serialize + Send + Sync + 'static,
+{
+	fn latest_value(&self, at: Option<Block::Hash>) -> RpcResult<Option<u64>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.latest_value(at)
+			.map_err(|e| runtime_error("Unable to query the latest value.", e))
+	}
+
+	fn round_info(&self, at: Option<Block::Hash>) -> RpcResult<RoundInfo<BlockNumber>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.round_info(at)
+			.map_err(|e| runtime_error("Unable to query the current round.", e))
+	}
+
+	fn pending_tasks(&self, at: Option<Block::Hash>) -> RpcResult<Vec<(BlockNumber, u32)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.pending_tasks(at)
+			.map_err(|e| runtime_error("Unable to query the pending tasks.", e))
+	}
+
+	fn authorities(&self, at: Option<Block::Hash>) -> RpcResult<Vec<AccountId>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.authorities(at)
+			.map_err(|e| runtime_error("Unable to query the authorities.", e))
+	}
+
+	fn subscribe_finalized_rounds(&self, mut sink: SubscriptionSink) -> SubscriptionResult {
+		let client = self.client.clone();
+		let mut last_round = None;
+		// The latest round stays the same in the blocks until the next one is finalized, it is
+		// only sent once.
+		let rounds = self.client.finality_notification_stream().filter_map(move |notification| {
+			let round = client.runtime_api().latest_round(notification.hash).ok().flatten();
+			let new_round =
+				round.filter(|(index, _)| last_round.map_or(true, |last| *index > last));
+			if let Some((index, _)) = new_round {
+				last_round = Some(index);
+			}
+			future::ready(new_round)
+		});
+
+		let fut = async move {
+			sink.pipe_from_stream(rounds).await;
+		};
+		self.executor.spawn("ocw-rpc-subscription", Some("rpc"), fut.boxed());
+		Ok(())
+	}
+}
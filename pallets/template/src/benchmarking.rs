@@ -2,34 +2,1021 @@
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 
+use crate::keys::KeyKind;
 #[allow(unused)]
 use crate::Pallet as Template;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::{
+	traits::{ConstU32, Currency, EnsureOrigin, Get, ReservableCurrency},
+	unsigned::{TransactionSource, ValidateUnsigned},
+	weights::Weight,
+	BoundedVec,
+};
+use frame_system::{
+	offchain::{AppCrypto, SignedPayload},
+	pallet_prelude::BlockNumberFor,
+	RawOrigin,
+};
+use sp_runtime::{
+	traits::{Bounded, Hash, IdentifyAccount, One, Saturating, Zero},
+	RuntimeAppPublic,
+};
+use sp_std::{vec, vec::Vec};
 
-#[benchmarks]
+#[instance_benchmarks]
 mod benchmarks {
 	use super::*;
 
 	#[benchmark]
 	fn do_something() {
-		let value = 100u32.into();
+		// Worst case: the first value of the block, linked to the previous block with values.
+		ValuesByBlock::<T, I>::insert(
+			BlockNumberFor::<T>::zero(),
+			BoundedVec::truncate_from(vec![1]),
+		);
+		LatestValuesAt::<T, I>::put(BlockNumberFor::<T>::zero());
+		frame_system::Pallet::<T>::set_block_number(One::one());
+		let value = 100u32;
 		let caller: T::AccountId = whitelisted_caller();
 		#[extrinsic_call]
 		do_something(RawOrigin::Signed(caller), value);
 
-		assert_eq!(Something::<T>::get(), Some(value));
+		assert_eq!(Template::<T, I>::latest_n(1), vec![(One::one(), 100u64)]);
 	}
 
 	#[benchmark]
 	fn cause_error() {
-		Something::<T>::put(100u32);
+		// Worst case: the incremented value is the first of the block.
+		ValuesByBlock::<T, I>::insert(
+			BlockNumberFor::<T>::zero(),
+			BoundedVec::truncate_from(vec![100]),
+		);
+		LatestValuesAt::<T, I>::put(BlockNumberFor::<T>::zero());
+		frame_system::Pallet::<T>::set_block_number(One::one());
 		let caller: T::AccountId = whitelisted_caller();
 		#[extrinsic_call]
 		cause_error(RawOrigin::Signed(caller));
 
-		assert_eq!(Something::<T>::get(), Some(101u32));
+		assert_eq!(Template::<T, I>::latest_n(1), vec![(One::one(), 101u64)]);
+	}
+
+	#[benchmark]
+	fn write_key_to_ocs() {
+		// Worst case: the last commitment that fits the block is pushed.
+		let block_number = frame_system::Pallet::<T>::block_number();
+		let existing = T::MaxIndexedPerBlock::get().saturating_sub(1) as usize;
+		let commitments: BoundedVec<T::Hash, T::MaxIndexedPerBlock> =
+			vec![T::Hash::default(); existing].try_into().unwrap();
+		IndexCommitments::<T, I>::insert(block_number, commitments);
+		let kinds: BoundedVec<KeyKind, T::MaxIndexedPerBlock> =
+			vec![KeyKind::FetchPrice; existing].try_into().unwrap();
+		IndexedKeys::<T, I>::insert(block_number, kinds);
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value() / 2u32.into());
+		#[extrinsic_call]
+		write_key_to_ocs(RawOrigin::Signed(caller), 100u64);
+
+		assert_eq!(IndexCommitments::<T, I>::get(block_number).len(), existing + 1);
+	}
+
+	#[benchmark]
+	fn queue_task() {
+		// Worst case: the largest task is hashed, compared and indexed with a nearly full queue.
+		let block_number = frame_system::Pallet::<T>::block_number();
+		let existing = T::MaxIndexedPerBlock::get().saturating_sub(1) as usize;
+		let commitments: BoundedVec<T::Hash, T::MaxIndexedPerBlock> =
+			vec![T::Hash::default(); existing].try_into().unwrap();
+		IndexCommitments::<T, I>::insert(block_number, commitments);
+		let kinds: BoundedVec<KeyKind, T::MaxIndexedPerBlock> =
+			vec![KeyKind::FetchPrice; existing].try_into().unwrap();
+		IndexedKeys::<T, I>::insert(block_number, kinds);
+		let message = vec![b'x'; MAX_NOTIFY_LEN as usize].try_into().unwrap();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value() / 2u32.into());
+		#[extrinsic_call]
+		queue_task(RawOrigin::Signed(caller), Task::Notify(message));
+
+		assert_eq!(IndexCommitments::<T, I>::get(block_number).len(), existing + 1);
+	}
+
+	#[benchmark]
+	fn set_oracle_endpoints() -> Result<(), BenchmarkError> {
+		let url: BoundedVec<u8, T::MaxEndpointLen> =
+			vec![b'x'; T::MaxEndpointLen::get() as usize].try_into().unwrap();
+		let endpoints: BoundedVec<_, T::MaxEndpoints> =
+			vec![url; T::MaxEndpoints::get() as usize].try_into().unwrap();
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		set_oracle_endpoints(origin, endpoints);
+
+		assert_eq!(OracleEndpoints::<T, I>::get().len(), T::MaxEndpoints::get() as usize);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_ocw_config() -> Result<(), BenchmarkError> {
+		let config = OcwConfigInfo {
+			endpoint: vec![b'x'; T::MaxEndpointLen::get() as usize].try_into().unwrap(),
+			..Template::<T, I>::runtime_ocw_config()
+		};
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		set_ocw_config(origin, Some(config.clone()));
+
+		assert_eq!(OcwConfig::<T, I>::get(), Some(config));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn report_retries_exhausted() {
+		let caller: T::AccountId = whitelisted_caller();
+		fill_authorities::<T, I>(T::MaxAuthorities::get().saturating_sub(1), Some(caller.clone()));
+		#[extrinsic_call]
+		report_retries_exhausted(RawOrigin::Signed(caller), 4);
+	}
+
+	#[benchmark]
+	fn add_authority() -> Result<(), BenchmarkError> {
+		let existing = T::MaxAuthorities::get().saturating_sub(1);
+		fill_authorities::<T, I>(existing, None);
+		let who: T::AccountId = account("authority", existing, 0);
+		T::Currency::make_free_balance_be(&who, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		add_authority(origin, who.clone());
+
+		assert!(Authorities::<T, I>::get().contains(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_authority() -> Result<(), BenchmarkError> {
+		// Worst case: the last of a full set of authorities is removed.
+		let who: T::AccountId = account("authority", 0, 1);
+		fill_authorities::<T, I>(T::MaxAuthorities::get().saturating_sub(1), Some(who.clone()));
+		T::Currency::make_free_balance_be(&who, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let bond = T::AuthorityBond::get();
+		T::Currency::reserve(&who, bond).unwrap();
+		Bonds::<T, I>::insert(&who, bond);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		remove_authority(origin, who.clone());
+
+		assert!(!Authorities::<T, I>::get().contains(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn report_submission_failure() {
+		let caller: T::AccountId = whitelisted_caller();
+		fill_authorities::<T, I>(T::MaxAuthorities::get().saturating_sub(1), Some(caller.clone()));
+		#[extrinsic_call]
+		report_submission_failure(RawOrigin::Signed(caller), FailureReason::Submit);
+	}
+
+	#[benchmark]
+	fn submit_data() {
+		// Worst case: the deposit of a payload of maximum length is reserved.
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let data: BoundedVec<u8, T::MaxDataLen> =
+			vec![0u8; T::MaxDataLen::get() as usize].try_into().unwrap();
+		#[extrinsic_call]
+		submit_data(RawOrigin::Signed(caller.clone()), data);
+
+		assert!(SubmittedData::<T, I>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn clear_data() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let data: BoundedVec<u8, T::MaxDataLen> =
+			vec![0u8; T::MaxDataLen::get() as usize].try_into().unwrap();
+		Template::<T, I>::submit_data(RawOrigin::Signed(caller.clone()).into(), data).unwrap();
+		#[extrinsic_call]
+		clear_data(RawOrigin::Signed(caller.clone()));
+
+		assert!(!SubmittedData::<T, I>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn confirm_tasks(n: Linear<1, { T::MaxIndexedPerBlock::get() }>) {
+		// Worst case: every deposit belongs to another account.
+		let block_number = frame_system::Pallet::<T>::block_number();
+		let deposit = T::IndexingDeposit::get();
+		let deposits = (0..n)
+			.map(|i| {
+				let depositor: T::AccountId = account("depositor", i, 0);
+				T::Currency::make_free_balance_be(&depositor, deposit.saturating_mul(2u32.into()));
+				T::Currency::reserve(&depositor, deposit).unwrap();
+				(depositor, deposit)
+			})
+			.collect::<Vec<_>>();
+		IndexDeposits::<T, I>::insert(
+			block_number,
+			BoundedVec::<_, T::MaxIndexedPerBlock>::try_from(deposits).unwrap(),
+		);
+		let caller: T::AccountId = whitelisted_caller();
+		fill_authorities::<T, I>(T::MaxAuthorities::get().saturating_sub(1), Some(caller.clone()));
+		frame_system::Pallet::<T>::set_block_number(block_number + One::one());
+		#[extrinsic_call]
+		confirm_tasks(RawOrigin::Signed(caller), block_number);
+
+		assert!(IndexDeposits::<T, I>::get(block_number).is_empty());
+	}
+
+	#[benchmark]
+	fn heartbeat() {
+		let DataPayload { block_number, public, .. } = signed_payload::<T, I>(0);
+		let payload = HeartbeatPayload { block_number, public };
+		let signature = payload.sign::<T::AuthorityId>().unwrap();
+		#[extrinsic_call]
+		heartbeat(RawOrigin::None, payload.clone(), signature);
+
+		assert_eq!(LastSeen::<T, I>::get(payload.public.into_account()), Some(block_number));
+	}
+
+	#[benchmark]
+	fn write_batch_to_ocs(n: Linear<1, { T::MaxBatch::get() }>) {
+		// Worst case: every entry has the longest label.
+		let label: BoundedVec<u8, T::MaxBatchLabelLen> =
+			vec![b'x'; T::MaxBatchLabelLen::get() as usize].try_into().unwrap();
+		let entries: BoundedVec<_, T::MaxBatch> = (0..n)
+			.map(|i| (label.clone(), i as u64))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let caller: T::AccountId = whitelisted_caller();
+		#[extrinsic_call]
+		write_batch_to_ocs(RawOrigin::Signed(caller), entries);
+
+		let block_number = frame_system::Pallet::<T>::block_number();
+		assert_eq!(BatchCommitments::<T, I>::get(block_number).len(), n as usize);
+	}
+
+	#[benchmark]
+	fn clear_ocs_key() -> Result<(), BenchmarkError> {
+		// Worst case: the block has as many tasks and batch entries as it can hold.
+		let block_number = frame_system::Pallet::<T>::block_number();
+		let kinds: BoundedVec<KeyKind, T::MaxIndexedPerBlock> =
+			vec![KeyKind::FetchPrice; T::MaxIndexedPerBlock::get() as usize]
+				.try_into()
+				.unwrap();
+		IndexedKeys::<T, I>::insert(block_number, kinds);
+		let commitments: BoundedVec<T::Hash, T::MaxBatch> =
+			vec![T::Hash::default(); T::MaxBatch::get() as usize].try_into().unwrap();
+		BatchCommitments::<T, I>::insert(block_number, commitments);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		clear_ocs_key(origin, block_number);
+
+		assert!(!IndexedKeys::<T, I>::contains_key(block_number));
+		assert!(!BatchCommitments::<T, I>::contains_key(block_number));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn commit_value() {
+		// Worst case: the caller is the last of a full set of authorities.
+		let caller: T::AccountId = whitelisted_caller();
+		fill_authorities::<T, I>(T::MaxAuthorities::get().saturating_sub(1), Some(caller.clone()));
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let hash = Template::<T, I>::reveal_hash(100, &[0; 32]);
+		#[extrinsic_call]
+		commit_value(RawOrigin::Signed(caller.clone()), hash);
+
+		assert!(Commitments::<T, I>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn reveal_value() {
+		// Worst case: the value matches, so it is added to a full ring buffer of prices and
+		// recorded for the round of the caller, the last of a full set of authorities.
+		fill_prices::<T, I>();
+		let caller: T::AccountId = whitelisted_caller();
+		fill_authorities::<T, I>(T::MaxAuthorities::get().saturating_sub(1), Some(caller.clone()));
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let deposit = T::CommitDeposit::get();
+		T::Currency::reserve(&caller, deposit).unwrap();
+		let hash = Template::<T, I>::reveal_hash(100, &[0; 32]);
+		Commitments::<T, I>::insert(
+			&caller,
+			ValueCommitment { hash, committed_at: Zero::zero(), deposit },
+		);
+		frame_system::Pallet::<T>::set_block_number(T::RevealDelay::get());
+		#[extrinsic_call]
+		reveal_value(RawOrigin::Signed(caller.clone()), 100u64, [0; 32]);
+
+		assert_eq!(LatestValue::<T, I>::get(), Some(100u64));
+		assert!(!Commitments::<T, I>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn subscribe() {
+		// Worst case: the topic is new and has the maximum length.
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let topic: Topic<T, I> = vec![b'x'; T::MaxTopicLen::get() as usize].try_into().unwrap();
+		#[extrinsic_call]
+		subscribe(RawOrigin::Signed(caller.clone()), topic.clone());
+
+		assert!(Subscriptions::<T, I>::contains_key(&topic, &caller));
+	}
+
+	#[benchmark]
+	fn unsubscribe() {
+		// Worst case: the caller is the last subscriber, so the topic is removed.
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let topic: Topic<T, I> = vec![b'x'; T::MaxTopicLen::get() as usize].try_into().unwrap();
+		let deposit = T::SubscriptionDeposit::get();
+		T::Currency::reserve(&caller, deposit).unwrap();
+		Subscriptions::<T, I>::insert(&topic, &caller, deposit);
+		Topics::<T, I>::insert(&topic, 1);
+		#[extrinsic_call]
+		unsubscribe(RawOrigin::Signed(caller.clone()), topic.clone());
+
+		assert!(!Topics::<T, I>::contains_key(&topic));
+	}
+
+	#[benchmark]
+	fn update_topics(n: Linear<1, { T::MaxTopics::get() }>) {
+		// Worst case: the caller is the last of a full set of authorities.
+		let caller: T::AccountId = whitelisted_caller();
+		fill_authorities::<T, I>(T::MaxAuthorities::get().saturating_sub(1), Some(caller.clone()));
+		let updates: BoundedVec<_, T::MaxTopics> = (0..n)
+			.map(|i| {
+				let mut topic = i.to_le_bytes().to_vec();
+				topic.resize(T::MaxTopicLen::get() as usize, b'x');
+				let topic: Topic<T, I> = topic.try_into().unwrap();
+				Topics::<T, I>::insert(&topic, 1);
+				(topic, i as u64)
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		#[extrinsic_call]
+		update_topics(RawOrigin::Signed(caller), updates);
+	}
+
+	#[benchmark]
+	fn submit_data_signed() {
+		// Worst case: the ring buffer of prices is full, so the oldest price is removed, and
+		// the caller is the last of a full set of authorities.
+		fill_prices::<T, I>();
+		let caller: T::AccountId = whitelisted_caller();
+		fill_authorities::<T, I>(T::MaxAuthorities::get().saturating_sub(1), Some(caller.clone()));
+		#[extrinsic_call]
+		submit_data_signed(RawOrigin::Signed(caller), 100u64);
+
+		assert_eq!(LatestValue::<T, I>::get(), Some(100u64));
+	}
+
+	#[benchmark]
+	fn submit_number_unsigned() {
+		fill_prices::<T, I>();
+		let block_number = frame_system::Pallet::<T>::block_number();
+		#[extrinsic_call]
+		submit_number_unsigned(RawOrigin::None, block_number, 100u64);
+
+		assert_eq!(LatestValue::<T, I>::get(), Some(100u64));
+	}
+
+	#[benchmark]
+	fn submit_payload_unsigned_with_signed_payload() {
+		// Worst case: the signer is the last of a full set of authorities, so its value is
+		// recorded for the round.
+		fill_prices::<T, I>();
+		let payload = signed_payload::<T, I>(100u64);
+		let who = payload.public.clone().into_account();
+		fill_authorities::<T, I>(T::MaxAuthorities::get().saturating_sub(1), Some(who));
+		let signature = payload.sign::<T::AuthorityId>().unwrap();
+		#[extrinsic_call]
+		submit_payload_unsigned_with_signed_payload(RawOrigin::None, payload, signature);
+
+		assert_eq!(LatestValue::<T, I>::get(), Some(100u64));
+	}
+
+	#[benchmark]
+	fn register_asset() -> Result<(), BenchmarkError> {
+		// Worst case: the endpoint and the field have the maximum length.
+		let asset = T::BenchmarkHelper::asset_id(0);
+		let endpoint = max_endpoint::<T, I>();
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		register_asset(origin, asset, endpoint.clone(), endpoint);
+
+		assert!(Assets::<T, I>::contains_key(asset));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn deregister_asset() -> Result<(), BenchmarkError> {
+		// Worst case: a full set of authorities submitted a price of the asset.
+		let asset = T::BenchmarkHelper::asset_id(0);
+		register_assets::<T, I>(1);
+		fill_authorities::<T, I>(T::MaxAuthorities::get(), None);
+		for who in Authorities::<T, I>::get() {
+			AssetSubmissions::<T, I>::insert(asset, who, 1);
+		}
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		deregister_asset(origin, asset);
+
+		assert!(!Assets::<T, I>::contains_key(asset));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_set_value() -> Result<(), BenchmarkError> {
+		let asset = T::BenchmarkHelper::asset_id(0);
+		register_assets::<T, I>(1);
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		force_set_value(origin, asset, 42);
+
+		assert_eq!(Prices::<T, I>::get(asset).map(|info| info.value), Some(42));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_asset_prices(n: Linear<1, { T::MaxAssets::get() }>) {
+		// Worst case: the caller is the last of a full set of authorities.
+		let caller: T::AccountId = whitelisted_caller();
+		fill_authorities::<T, I>(T::MaxAuthorities::get().saturating_sub(1), Some(caller.clone()));
+		register_assets::<T, I>(n);
+		let prices: BoundedVec<_, T::MaxAssets> = (0..n)
+			.map(|i| (T::BenchmarkHelper::asset_id(i), 1u64))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		#[extrinsic_call]
+		submit_asset_prices(RawOrigin::Signed(caller), prices);
+	}
+
+	#[benchmark]
+	fn index_payload(l: Linear<1, { T::MaxPayloadLen::get() }>) {
+		let payload: BoundedVec<u8, T::MaxPayloadLen> = vec![b'x'; l as usize].try_into().unwrap();
+		let caller: T::AccountId = whitelisted_caller();
+		#[extrinsic_call]
+		index_payload(RawOrigin::Signed(caller), payload);
+
+		assert_eq!(PayloadRoots::<T, I>::iter().count(), 1);
+	}
+
+	#[benchmark]
+	fn verify_chunk() {
+		// Worst case: the longest proof, of a full chunk of the longest payload.
+		let payload = vec![b'x'; T::MaxPayloadLen::get() as usize];
+		let leaves = merkle::leaves::<T::Hashing>(&payload, PAYLOAD_CHUNK_LEN as usize);
+		let root = merkle::root::<T::Hashing>(&leaves).unwrap();
+		PayloadRoots::<T, I>::insert(root, (BlockNumberFor::<T>::zero(), leaves.len() as u32));
+		let proof = merkle::proof::<T::Hashing>(&leaves, 0).unwrap();
+		let chunk = payload
+			.chunks(PAYLOAD_CHUNK_LEN as usize)
+			.next()
+			.unwrap()
+			.to_vec()
+			.try_into()
+			.unwrap();
+		let caller: T::AccountId = whitelisted_caller();
+		#[extrinsic_call]
+		verify_chunk(RawOrigin::Signed(caller), proof, chunk);
+	}
+
+	#[benchmark]
+	fn set_endpoint_template() -> Result<(), BenchmarkError> {
+		// Worst case: the longest template, a placeholder at the end.
+		let mut template = vec![b'x'; T::MaxEndpointLen::get().saturating_sub(7) as usize];
+		template.extend_from_slice(b"{ASSET}");
+		let template: BoundedVec<u8, T::MaxEndpointLen> = template.try_into().unwrap();
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		set_endpoint_template(origin, template.clone());
+
+		assert_eq!(EndpointTemplate::<T, I>::get(), template);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prune_history(n: Linear<1, { T::MaxHistoryPrune::get() }>) -> Result<(), BenchmarkError> {
+		// Worst case: every pruned block has values, the latest of them included.
+		let mut previous = None;
+		for i in 0..n {
+			let block_number: BlockNumberFor<T> = i.into();
+			ValuesByBlock::<T, I>::insert(block_number, BoundedVec::truncate_from(vec![1]));
+			if let Some(previous) = previous {
+				PreviousValuesAt::<T, I>::insert(block_number, previous);
+			}
+			previous = Some(block_number);
+		}
+		LatestValuesAt::<T, I>::set(previous);
+		frame_system::Pallet::<T>::set_block_number(n.into());
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		prune_history(origin, n.into());
+
+		assert_eq!(HistoryCursor::<T, I>::get(), n.into());
+		assert_eq!(Template::<T, I>::latest_n(1), vec![]);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_record_unsigned() {
+		LatestRecord::<T, I>::put((1, 1));
+		let record = FeedRecord { since: 1, id: 2, value: 42 };
+		#[extrinsic_call]
+		submit_record_unsigned(RawOrigin::None, record);
+
+		assert_eq!(LatestRecord::<T, I>::get(), Some((2, 42)));
+	}
+
+	#[benchmark]
+	fn submit_record_unsigned_with_signed_payload() {
+		LatestRecord::<T, I>::put((1, 1));
+		let record = FeedRecord { since: 1, id: 2, value: 42 };
+		let public = signed_payload::<T, I>(0).public;
+		let payload = RecordPayload { record, public };
+		let signature = payload.sign::<T::AuthorityId>().unwrap();
+		#[extrinsic_call]
+		submit_record_unsigned_with_signed_payload(RawOrigin::None, payload, signature);
+
+		assert_eq!(LatestRecord::<T, I>::get(), Some((2, 42)));
+	}
+
+	#[benchmark]
+	fn request_document() -> Result<(), BenchmarkError> {
+		let cid: CidOf<T, I> = BENCHMARK_CID.to_vec().try_into().unwrap();
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		request_document(origin, cid.clone());
+
+		assert!(DocumentRequests::<T, I>::contains_key(&cid));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn anchor_document() {
+		// Worst case: the caller is the last of a full set of authorities.
+		let caller: T::AccountId = whitelisted_caller();
+		fill_authorities::<T, I>(T::MaxAuthorities::get().saturating_sub(1), Some(caller.clone()));
+		let cid: CidOf<T, I> = BENCHMARK_CID.to_vec().try_into().unwrap();
+		DocumentRequests::<T, I>::insert(&cid, frame_system::Pallet::<T>::block_number());
+		let hash = T::Hashing::hash(b"document");
+		#[extrinsic_call]
+		anchor_document(RawOrigin::Signed(caller), cid.clone(), hash);
+
+		assert_eq!(Documents::<T, I>::get(&cid), Some(hash));
+	}
+
+	#[benchmark]
+	fn set_attester() -> Result<(), BenchmarkError> {
+		let signer = AttesterKey::Ecdsa(sp_io::crypto::ecdsa_generate(KEY_TYPE, None));
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		set_attester(origin, signer.clone(), true);
+
+		assert!(Attesters::<T, I>::contains_key(&signer));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_attested_data(l: Linear<0, { T::MaxDataLen::get().min(T::MaxOcwPayload::get()) }>) {
+		// Worst case: an ECDSA attestation, whose verification is the slowest, submitted by the
+		// last of a full set of authorities.
+		let caller: T::AccountId = whitelisted_caller();
+		fill_authorities::<T, I>(T::MaxAuthorities::get().saturating_sub(1), Some(caller.clone()));
+		let public = sp_io::crypto::ecdsa_generate(KEY_TYPE, None);
+		let data: BoundedVec<u8, T::MaxDataLen> = vec![b'x'; l as usize].try_into().unwrap();
+		let signature = sp_io::crypto::ecdsa_sign(KEY_TYPE, &public, &data).unwrap();
+		let signer = AttesterKey::Ecdsa(public);
+		Attesters::<T, I>::insert(&signer, ());
+		#[extrinsic_call]
+		submit_attested_data(
+			RawOrigin::Signed(caller),
+			data,
+			Attestation::Ecdsa(signature),
+			signer.clone(),
+		);
+
+		assert!(AttestedData::<T, I>::contains_key(&signer));
+	}
+
+	#[benchmark]
+	fn pause() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		pause(origin);
+
+		assert!(Paused::<T, I>::get());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unpause() -> Result<(), BenchmarkError> {
+		Paused::<T, I>::put(true);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		unpause(origin);
+
+		assert!(!Paused::<T, I>::get());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn receive_remote_value() -> Result<(), BenchmarkError> {
+		let asset = T::BenchmarkHelper::asset_id(0);
+		register_assets::<T, I>(1);
+		let origin =
+			T::RemoteOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		receive_remote_value(origin, asset, 42);
+
+		assert_eq!(Prices::<T, I>::get(asset).map(|info| info.value), Some(42));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_task() -> Result<(), BenchmarkError> {
+		// Worst case: the largest task is pushed onto a nearly full schedule.
+		let at = frame_system::Pallet::<T>::block_number() + One::one();
+		let message: BoundedVec<u8, ConstU32<MAX_NOTIFY_LEN>> =
+			vec![b'x'; MAX_NOTIFY_LEN as usize].try_into().unwrap();
+		let existing = T::MaxIndexedPerBlock::get().saturating_sub(1) as usize;
+		let tasks: BoundedVec<Task, T::MaxIndexedPerBlock> =
+			vec![Task::Notify(message.clone()); existing].try_into().unwrap();
+		ScheduledTasks::<T, I>::insert(at, tasks);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		schedule_task(origin, at, Task::Notify(message));
+
+		assert_eq!(ScheduledTasks::<T, I>::get(at).len(), existing + 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn execute_task() -> Result<(), BenchmarkError> {
+		// Worst case: the largest task is pushed onto a nearly full schedule.
+		let now = frame_system::Pallet::<T>::block_number();
+		let message: BoundedVec<u8, ConstU32<MAX_NOTIFY_LEN>> =
+			vec![b'x'; MAX_NOTIFY_LEN as usize].try_into().unwrap();
+		let existing = T::MaxIndexedPerBlock::get().saturating_sub(1) as usize;
+		let tasks: BoundedVec<Task, T::MaxIndexedPerBlock> =
+			vec![Task::Notify(message.clone()); existing].try_into().unwrap();
+		ScheduledTasks::<T, I>::insert(now, tasks);
+		let origin =
+			T::SchedulerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		execute_task(origin, Task::Notify(message));
+
+		assert_eq!(ScheduledTasks::<T, I>::get(now).len(), existing + 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn finalize_round(
+		n: Linear<1, { T::MaxAuthorities::get() }>,
+		a: Linear<0, { T::MaxAssets::get() }>,
+	) {
+		// Worst case: each of `n` authorities submitted a value and a price of each of `a`
+		// assets, the observations of the TWAP are full and the round is the last of a
+		// snapshot period.
+		fill_authorities::<T, I>(n, None);
+		register_assets::<T, I>(a);
+		CurrentRound::<T, I>::mutate(|round| {
+			round.index = T::SnapshotPeriod::get().saturating_sub(1)
+		});
+		let round = CurrentRound::<T, I>::get().index;
+		for (i, who) in Authorities::<T, I>::get().into_iter().enumerate() {
+			Submissions::<T, I>::insert(round, &who, i as u64 + 1);
+			for j in 0..a {
+				let asset = T::BenchmarkHelper::asset_id(j);
+				AssetSubmissions::<T, I>::insert(asset, &who, i as u64 + 1);
+			}
+		}
+		let observations: BoundedVec<(u64, u64), T::MaxTwapObservations> =
+			vec![(0, 1); T::MaxTwapObservations::get() as usize].try_into().unwrap();
+		TwapObservations::<T, I>::put(observations);
+		#[block]
+		{
+			Template::<T, I>::finalize_round();
+		}
+
+		assert_eq!(CurrentRound::<T, I>::get().phase, RoundPhase::Finalized);
+		assert_eq!(LatestRound::<T, I>::get().map(|(index, _)| index), Some(round));
+	}
+
+	#[benchmark]
+	fn prune_commitments(n: Linear<0, 100>) {
+		// Worst case: each of the `n` pruned blocks holds full commitments, indexed keys and
+		// batch commitments, and unconfirmed tasks of as many accounts.
+		let commitments: BoundedVec<T::Hash, T::MaxIndexedPerBlock> =
+			vec![T::Hash::default(); T::MaxIndexedPerBlock::get() as usize]
+				.try_into()
+				.unwrap();
+		let kinds: BoundedVec<KeyKind, T::MaxIndexedPerBlock> =
+			vec![KeyKind::FetchPrice; T::MaxIndexedPerBlock::get() as usize]
+				.try_into()
+				.unwrap();
+		let batches: BoundedVec<T::Hash, T::MaxBatch> =
+			vec![T::Hash::default(); T::MaxBatch::get() as usize].try_into().unwrap();
+		let depositors = (0..T::MaxIndexedPerBlock::get())
+			.map(|i| account::<T::AccountId>("depositor", i, 0))
+			.collect::<Vec<_>>();
+		for who in &depositors {
+			let info = DepositInfo { reserved: n.into(), reclaimable: Zero::zero() };
+			Deposits::<T, I>::insert(who, info);
+		}
+		let deposits: BoundedVec<_, T::MaxIndexedPerBlock> = depositors
+			.iter()
+			.map(|who| (who.clone(), One::one()))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		for i in 0..n {
+			let block_number: BlockNumberFor<T> = i.into();
+			IndexCommitments::<T, I>::insert(block_number, commitments.clone());
+			IndexedKeys::<T, I>::insert(block_number, kinds.clone());
+			BatchCommitments::<T, I>::insert(block_number, batches.clone());
+			IndexDeposits::<T, I>::insert(block_number, deposits.clone());
+		}
+		let now = T::CommitmentRetention::get().saturating_add(n.into());
+		#[block]
+		{
+			Template::<T, I>::prune_commitments(now, Weight::MAX);
+		}
+
+		assert_eq!(CommitmentsCursor::<T, I>::get(), n.into());
+		assert_eq!(Deposits::<T, I>::get(&depositors[0]).reclaimable, n.into());
+	}
+
+	#[benchmark]
+	fn validate_signed_payload(n: Linear<1, { T::MaxAuthorities::get() }>) {
+		// Worst case: the signer is the last of `n` authorities.
+		let payload = signed_payload::<T, I>(100u64);
+		let who = payload.public.clone().into_account();
+		fill_authorities::<T, I>(n - 1, Some(who));
+		let signature = payload.sign::<T::AuthorityId>().unwrap();
+		let call = Call::<T, I>::submit_payload_unsigned_with_signed_payload { payload, signature };
+		#[block]
+		{
+			assert!(Template::<T, I>::validate_unsigned(TransactionSource::External, &call).is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn reclaim_deposit() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let deposit: BalanceOf<T, I> = 100u32.into();
+		T::Currency::reserve(&caller, deposit).unwrap();
+		Deposits::<T, I>::insert(&caller, DepositInfo { reserved: deposit, reclaimable: deposit });
+		#[extrinsic_call]
+		reclaim_deposit(RawOrigin::Signed(caller.clone()));
+
+		assert!(!Deposits::<T, I>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn raise_dispute() {
+		// Worst case: the round was finalized with the submissions of a full set of authorities.
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let submissions = (0..T::MaxAuthorities::get())
+			.map(|i| (account::<T::AccountId>("authority", i, 0), 1))
+			.collect::<Vec<_>>();
+		LatestSubmissions::<T, I>::put(BoundedVec::truncate_from(submissions));
+		LatestRound::<T, I>::put((0, 1));
+		let bond = T::MinDisputeBond::get();
+		#[extrinsic_call]
+		raise_dispute(RawOrigin::Signed(caller.clone()), 0, bond);
+
+		assert_eq!(Disputes::<T, I>::get(0).map(|dispute| dispute.who), Some(caller));
+	}
+
+	#[benchmark]
+	fn resolve_dispute(n: Linear<0, { T::MaxAuthorities::get() }>) -> Result<(), BenchmarkError> {
+		// `n` of a full set of authorities deviated from the upheld value and are slashed.
+		let max = T::MaxAuthorities::get();
+		fill_authorities::<T, I>(max, None);
+		let bond = T::AuthorityBond::get();
+		let mut submissions = Vec::new();
+		for i in 0..max {
+			let who: T::AccountId = account("authority", i, 0);
+			T::Currency::make_free_balance_be(&who, BalanceOf::<T, I>::max_value() / 2u32.into());
+			T::Currency::reserve(&who, bond).unwrap();
+			Bonds::<T, I>::insert(&who, bond);
+			submissions.push((who, if i < n { 1000 } else { 1 }));
+		}
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let dispute_bond = T::MinDisputeBond::get();
+		T::Currency::reserve(&caller, dispute_bond).unwrap();
+		Deposits::<T, I>::insert(
+			&caller,
+			DepositInfo { reserved: dispute_bond, reclaimable: Zero::zero() },
+		);
+		let dispute = DisputeInfo {
+			who: caller,
+			bond: dispute_bond,
+			submissions: BoundedVec::truncate_from(submissions),
+		};
+		Disputes::<T, I>::insert(0, dispute);
+		LatestRound::<T, I>::put((0, 1000));
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		resolve_dispute(origin, 0, DisputeOutcome::Upheld { value: 1 });
+
+		assert_eq!(Authorities::<T, I>::get().len() as u32, max - n);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_inherent_value() -> Result<(), BenchmarkError> {
+		if !T::AcceptInherentValues::get() {
+			return Err(BenchmarkError::Weightless)
+		}
+		fill_prices::<T, I>();
+		LatestRound::<T, I>::put((0, 100));
+		#[extrinsic_call]
+		set_inherent_value(RawOrigin::None, 100u64);
+
+		assert_eq!(LatestValue::<T, I>::get(), Some(100u64));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn request_value() {
+		// Worst case: a query of the maximum length.
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let query: BoundedVec<u8, T::MaxQueryLen> =
+			vec![b'x'; T::MaxQueryLen::get() as usize].try_into().unwrap();
+		let fee = T::Currency::minimum_balance();
+		#[extrinsic_call]
+		request_value(RawOrigin::Signed(caller.clone()), query, fee, None);
+
+		assert_eq!(Requests::<T, I>::get(0).map(|request| request.requester), Some(caller));
+	}
+
+	#[benchmark]
+	fn fulfill_request() {
+		// Worst case: the caller is the last of a full set of authorities, and is paid the fee
+		// of a request with a query of the maximum length.
+		let caller: T::AccountId = whitelisted_caller();
+		fill_authorities::<T, I>(T::MaxAuthorities::get().saturating_sub(1), Some(caller.clone()));
+		let requester: T::AccountId = account("requester", 0, 0);
+		T::Currency::make_free_balance_be(&requester, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let query: BoundedVec<u8, T::MaxQueryLen> =
+			vec![b'x'; T::MaxQueryLen::get() as usize].try_into().unwrap();
+		let fee = T::Currency::minimum_balance();
+		Template::<T, I>::request_value(RawOrigin::Signed(requester).into(), query, fee, None)
+			.unwrap();
+		#[extrinsic_call]
+		fulfill_request(RawOrigin::Signed(caller), 0, 100);
+
+		assert!(!Requests::<T, I>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn refund_request() {
+		let requester: T::AccountId = account("requester", 0, 0);
+		T::Currency::make_free_balance_be(&requester, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let query: BoundedVec<u8, T::MaxQueryLen> =
+			vec![b'x'; T::MaxQueryLen::get() as usize].try_into().unwrap();
+		let fee = T::Currency::minimum_balance();
+		Template::<T, I>::request_value(RawOrigin::Signed(requester).into(), query, fee, None)
+			.unwrap();
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number().saturating_add(T::RequestTtl::get()),
+		);
+		let caller: T::AccountId = whitelisted_caller();
+		#[extrinsic_call]
+		refund_request(RawOrigin::Signed(caller), 0);
+
+		assert!(!Requests::<T, I>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn expire_requests(n: Linear<0, { T::MaxPendingRequests::get() }>) {
+		// Worst case: each of the `n` requests times out, with a query of the maximum length.
+		let requester: T::AccountId = account("requester", 0, 0);
+		T::Currency::make_free_balance_be(&requester, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let query: BoundedVec<u8, T::MaxQueryLen> =
+			vec![b'x'; T::MaxQueryLen::get() as usize].try_into().unwrap();
+		let fee = T::Currency::minimum_balance();
+		for _ in 0..n {
+			let origin = RawOrigin::Signed(requester.clone()).into();
+			Template::<T, I>::request_value(origin, query.clone(), fee, None).unwrap();
+		}
+		let now = frame_system::Pallet::<T>::block_number().saturating_add(T::RequestTtl::get());
+		frame_system::Pallet::<T>::set_block_number(now);
+		#[block]
+		{
+			Template::<T, I>::expire_requests(now, now, n);
+		}
+
+		assert_eq!(Requests::<T, I>::count(), 0);
+	}
+
+	#[benchmark]
+	fn set_change_threshold() -> Result<(), BenchmarkError> {
+		let asset = T::BenchmarkHelper::asset_id(0);
+		register_assets::<T, I>(1);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		set_change_threshold(origin, asset, Some(500));
+
+		assert_eq!(ChangeThresholds::<T, I>::get(asset), Some(500));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn report_failure() {
+		// Worst case: the caller is the last of a full set of authorities, and the oldest of a
+		// full buffer of reports is dropped.
+		let caller: T::AccountId = whitelisted_caller();
+		fill_authorities::<T, I>(T::MaxAuthorities::get().saturating_sub(1), Some(caller.clone()));
+		let report = FailureReport {
+			who: caller.clone(),
+			code: 0,
+			context_hash: T::Hash::default(),
+			reported_at: frame_system::Pallet::<T>::block_number(),
+		};
+		let reports = vec![report; T::MaxFailureReports::get() as usize];
+		FailureReports::<T, I>::put(BoundedVec::truncate_from(reports));
+		#[extrinsic_call]
+		report_failure(RawOrigin::Signed(caller), 1, T::Hash::default());
+
+		assert_eq!(FailureReports::<T, I>::get().last().map(|report| report.code), Some(1));
 	}
 
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
 }
+
+/// Set `count` generated authorities, followed by `last` if given.
+fn fill_authorities<T: Config<I>, I: 'static>(count: u32, last: Option<T::AccountId>) {
+	let mut authorities = (0..count)
+		.map(|i| account::<T::AccountId>("authority", i, 0))
+		.collect::<Vec<_>>();
+	authorities.extend(last);
+	Authorities::<T, I>::put(BoundedVec::<_, T::MaxAuthorities>::try_from(authorities).unwrap());
+}
+
+/// A CIDv1 of the longest kind supported, see `ipfs`.
+const BENCHMARK_CID: &[u8] = b"bafkreichphvmdj4uyj3x4bwnmmaor6vdqanqlencshsyd4wwuigbt7jy3i";
+
+/// An endpoint of the maximum length.
+fn max_endpoint<T: Config<I>, I: 'static>() -> BoundedVec<u8, T::MaxEndpointLen> {
+	vec![b'x'; T::MaxEndpointLen::get() as usize].try_into().unwrap()
+}
+
+/// Register `count` assets with endpoints of the maximum length.
+fn register_assets<T: Config<I>, I: 'static>(count: u32) {
+	for i in 0..count {
+		let info = AssetInfo { endpoint: max_endpoint::<T, I>(), field: max_endpoint::<T, I>() };
+		Assets::<T, I>::insert(T::BenchmarkHelper::asset_id(i), info);
+	}
+}
+
+/// Fill the ring buffer of prices up to `T::MaxPrices`.
+fn fill_prices<T: Config<I>, I: 'static>() {
+	let prices: BoundedVec<u64, T::MaxPrices> =
+		vec![1u64; T::MaxPrices::get() as usize].try_into().unwrap();
+	PriceHistory::<T, I>::put(prices);
+}
+
+/// A payload for `value` at the current block, with the public part of a freshly generated
+/// offchain worker key.
+fn signed_payload<T: Config<I>, I: 'static>(
+	value: u64,
+) -> DataPayload<T::Public, BlockNumberFor<T>> {
+	let key =
+		<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::generate_pair(
+			None,
+		);
+	let public: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic = key.into();
+	DataPayload {
+		block_number: frame_system::Pallet::<T>::block_number(),
+		value,
+		public: public.into(),
+	}
+}
@@ -0,0 +1,58 @@
+//! Chunked storage of large blobs in the offchain database.
+//!
+//! Rather than under a single key, a blob indexed with `index` is split into chunks of at most
+//! `chunk_size` bytes, stored under the sub-keys `key ++ index` of its key, and a `Manifest` with
+//! their count and the hash of the whole blob is stored under the key itself. The offchain worker
+//! reassembles the blob with `read`, checking it against its manifest.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_runtime::{offchain::storage::StorageValueRef, traits::Hash};
+use sp_std::vec::Vec;
+
+/// The record of a blob stored under its key.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Manifest<Hash> {
+	/// The number of chunks of the blob.
+	pub chunk_count: u32,
+	/// The length of the blob.
+	pub len: u32,
+	/// The hash of the blob.
+	pub hash: Hash,
+}
+
+/// The key of the chunk at `index` of the blob indexed under `key`.
+pub fn chunk_key(key: &[u8], index: u32) -> Vec<u8> {
+	let mut chunk_key = key.to_vec();
+	index.encode_to(&mut chunk_key);
+	chunk_key
+}
+
+/// Index `blob` into the offchain database under `key`, split into chunks of at most
+/// `chunk_size` bytes.
+pub fn index<H: Hash>(key: &[u8], blob: &[u8], chunk_size: u32) -> Manifest<H::Output> {
+	let mut chunk_count = 0;
+	for chunk in blob.chunks(chunk_size.max(1) as usize) {
+		sp_io::offchain_index::set(&chunk_key(key, chunk_count), &chunk.encode());
+		chunk_count += 1;
+	}
+	let manifest = Manifest { chunk_count, len: blob.len() as u32, hash: H::hash(blob) };
+	sp_io::offchain_index::set(key, &manifest.encode());
+	manifest
+}
+
+/// The blob indexed under `key`, reassembled from its chunks. `None` if the manifest or one of
+/// the chunks is missing, or if the chunks don't add up to the blob of the manifest.
+///
+/// Can only be called from the offchain worker context.
+pub fn read<H: Hash>(key: &[u8]) -> Option<Vec<u8>> {
+	let manifest = StorageValueRef::persistent(key).get::<Manifest<H::Output>>().ok().flatten()?;
+	let mut blob = Vec::new();
+	for index in 0..manifest.chunk_count {
+		let chunk_key = chunk_key(key, index);
+		let chunk = StorageValueRef::persistent(&chunk_key).get::<Vec<u8>>().ok().flatten()?;
+		blob.extend(chunk);
+	}
+	(blob.len() == manifest.len as usize && H::hash(&blob) == manifest.hash).then_some(blob)
+}
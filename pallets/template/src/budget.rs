@@ -0,0 +1,25 @@
+//! The time budget of a run of the offchain worker, see `Config::WorkerBudgetMs`.
+//!
+//! These functions can only be called from the offchain worker context, as they rely on the
+//! offchain timestamp.
+
+use sp_runtime::offchain::{Duration, Timestamp};
+
+/// The deadline of a run of the offchain worker, checked between its stages.
+pub struct Budget {
+	deadline: Option<Timestamp>,
+}
+
+impl Budget {
+	/// Start a budget of `budget_ms` milliseconds from now, an unlimited one if it is zero.
+	pub fn start(budget_ms: u64) -> Self {
+		let deadline = (budget_ms > 0)
+			.then(|| sp_io::offchain::timestamp().add(Duration::from_millis(budget_ms)));
+		Self { deadline }
+	}
+
+	/// Whether the deadline of the budget is reached.
+	pub fn is_exhausted(&self) -> bool {
+		self.deadline.map_or(false, |deadline| sp_io::offchain::timestamp() >= deadline)
+	}
+}
@@ -0,0 +1,50 @@
+//! Compression of the payloads indexed into the offchain database.
+//!
+//! An encoded payload starts with a header byte telling its format: `RAW` or, with the
+//! `compression` feature, `LZ4` if compressing the payload makes it shorter. The header lets the
+//! offchain worker decode the payloads indexed by runtimes built with or without the feature,
+//! as long as its own runtime supports their format.
+
+use sp_std::vec::Vec;
+
+/// The header of a payload stored as is.
+pub const RAW: u8 = 0;
+
+/// The header of a payload compressed with LZ4, followed by its length as a little endian `u32`.
+pub const LZ4: u8 = 1;
+
+/// `payload` along with its header, compressed if the `compression` feature is enabled and it
+/// makes the payload shorter.
+pub fn encode(payload: &[u8]) -> Vec<u8> {
+	#[cfg(feature = "compression")]
+	{
+		let compressed = lz4_flex::block::compress_prepend_size(payload);
+		if compressed.len() < payload.len() {
+			return [&[LZ4][..], &compressed].concat()
+		}
+	}
+	[&[RAW][..], payload].concat()
+}
+
+/// The payload of `encoded`, decompressed if needed. `None` if its format is unknown or not
+/// supported by this build, or if it would decompress to more than `max_len` bytes.
+#[cfg_attr(not(feature = "compression"), allow(unused_variables))]
+pub fn decode(encoded: &[u8], max_len: u32) -> Option<Vec<u8>> {
+	let (header, body) = encoded.split_first()?;
+	match *header {
+		RAW => Some(body.to_vec()),
+		#[cfg(feature = "compression")]
+		LZ4 => {
+			if body.len() < 4 {
+				return None
+			}
+			let (len, compressed) = body.split_at(4);
+			let len = u32::from_le_bytes([len[0], len[1], len[2], len[3]]);
+			if len > max_len {
+				return None
+			}
+			lz4_flex::block::decompress(compressed, len as usize).ok()
+		},
+		_ => None,
+	}
+}
@@ -0,0 +1,86 @@
+//! A chain extension of `pallet_contracts` through which the smart contracts of the chain, e.g.
+//! ink! contracts, read the prices of the oracle and request values, with the `contracts`
+//! feature.
+//!
+//! `OracleExtension` is registered as the `pallet_contracts::Config::ChainExtension` of the
+//! runtime, on its own or in a tuple with others under its `RegisteredChainExtension::ID`. Its
+//! functions, by their id:
+//!
+//! - `GET_LATEST_VALUE` takes an encoded `Config::AssetId` and returns the latest price of the
+//!   asset and the block it was finalized in, the encoded `Option<(u64, BlockNumber)>` of
+//!   `ProvideOracleValue::get`.
+//! - `REQUEST_VALUE` takes the encoded query and fee of `Pallet::request_value`, and returns the
+//!   encoded `RequestId` of the request. The contract is the requester, its balance pays the fee.
+//!   The call of the contract fails with the error of the request, if any.
+
+use crate::{traits::ProvideOracleValue, BalanceOf, Config, NextRequestId, Pallet, WeightInfo};
+use codec::Encode;
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use pallet_contracts::chain_extension::{
+	ChainExtension, Environment, Ext, InitState, RegisteredChainExtension, RetVal,
+};
+use sp_runtime::DispatchError;
+use sp_std::marker::PhantomData;
+
+/// The id of the function returning the latest price of an asset.
+pub const GET_LATEST_VALUE: u16 = 1;
+
+/// The id of the function requesting a value.
+pub const REQUEST_VALUE: u16 = 2;
+
+/// The chain extension of the instance `I` of the pallet.
+pub struct OracleExtension<I = ()>(PhantomData<I>);
+
+impl<I> Default for OracleExtension<I> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T, I> ChainExtension<T> for OracleExtension<I>
+where
+	T: pallet_contracts::Config + Config<I>,
+	I: 'static,
+{
+	fn call<E: Ext<T = T>>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError> {
+		let func_id = env.func_id();
+		let mut env = env.buf_in_buf_out();
+		match func_id {
+			GET_LATEST_VALUE => {
+				env.charge_weight(<T as frame_system::Config>::DbWeight::get().reads(1))?;
+				let asset: <T as Config<I>>::AssetId = env.read_as()?;
+				let value = <Pallet<T, I> as ProvideOracleValue<_, _>>::get(&asset);
+				env.write(&value.encode(), false, None)?;
+			},
+			REQUEST_VALUE => {
+				env.charge_weight(<T as Config<I>>::WeightInfo::request_value())?;
+				let (query, fee): (BoundedVec<u8, T::MaxQueryLen>, BalanceOf<T, I>) =
+					env.read_as()?;
+				let contract = env.ext().address().clone();
+				let id = <NextRequestId<T, I>>::get();
+				Pallet::<T, I>::request_value(
+					RawOrigin::Signed(contract).into(),
+					query,
+					fee,
+					None,
+				)?;
+				env.write(&id.encode(), false, None)?;
+			},
+			_ => return Err(DispatchError::Other("unknown function of the oracle extension")),
+		}
+		Ok(RetVal::Converging(0))
+	}
+}
+
+impl<T, I> RegisteredChainExtension<T> for OracleExtension<I>
+where
+	T: pallet_contracts::Config + Config<I>,
+	I: 'static,
+{
+	/// `or`, for oracle.
+	const ID: u16 = 0x6f72;
+}
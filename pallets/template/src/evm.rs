@@ -0,0 +1,82 @@
+//! A precompile of `pallet_evm` through which the Solidity contracts of a Frontier-based runtime
+//! read the prices of the oracle, with the `evm` feature.
+//!
+//! `OraclePrecompile` is added to the `pallet_evm::Config::PrecompilesType` of the runtime at an
+//! address of its choice. Contracts call it as they would a contract with the interface:
+//!
+//! ```solidity
+//! interface Oracle {
+//!     function latestValue(uint256 asset)
+//!         external view returns (uint256 value, uint256 timestamp, uint256 round);
+//! }
+//! ```
+//!
+//! `value` and `round` are those of the `Prices` of the asset, and `timestamp` is its
+//! `PriceTimestamps` in seconds, as the `block.timestamp` of Solidity. The call reverts if the
+//! asset has no price, or if its id doesn't fit in a `Config::AssetId`.
+
+use crate::{Config, PriceTimestamps, Prices};
+use fp_evm::{
+	ExitRevert, ExitSucceed, Precompile, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+use frame_support::traits::Get;
+use pallet_evm::GasWeightMapping;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// The signature of the function returning the latest price of an asset.
+pub const LATEST_VALUE: &[u8] = b"latestValue(uint256)";
+
+/// The length of an ABI encoded word.
+const WORD_LEN: usize = 32;
+
+/// The precompile of the instance `I` of the pallet.
+pub struct OraclePrecompile<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T, I> Precompile for OraclePrecompile<T, I>
+where
+	T: pallet_evm::Config + Config<I>,
+	T::AssetId: TryFrom<u128>,
+	I: 'static,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let weight = <T as frame_system::Config>::DbWeight::get().reads(2);
+		handle.record_cost(<T as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight))?;
+
+		let input = handle.input();
+		let (selector, asset) = input.split_at(input.len().min(4));
+		if selector != &sp_io::hashing::keccak_256(LATEST_VALUE)[..4] {
+			return Err(revert("unknown function of the oracle precompile"))
+		}
+		let asset = decode_asset::<T::AssetId>(asset).ok_or_else(|| revert("invalid asset"))?;
+		let price = <Prices<T, I>>::get(asset).ok_or_else(|| revert("no price for the asset"))?;
+		let timestamp = <PriceTimestamps<T, I>>::get(asset).unwrap_or_default() / 1000;
+
+		let output = [price.value, timestamp, price.round.into()]
+			.into_iter()
+			.flat_map(encode_word)
+			.collect::<Vec<_>>();
+		Ok(PrecompileOutput { exit_status: ExitSucceed::Returned, output })
+	}
+}
+
+/// The asset id ABI encoded in `input`, a `uint256` which must fit in a `u128`.
+fn decode_asset<AssetId: TryFrom<u128>>(input: &[u8]) -> Option<AssetId> {
+	let (high, low) = input.get(..WORD_LEN)?.split_at(WORD_LEN / 2);
+	if high.iter().any(|byte| *byte != 0) {
+		return None
+	}
+	AssetId::try_from(u128::from_be_bytes(low.try_into().ok()?)).ok()
+}
+
+/// The ABI encoding of `value` as a `uint256`.
+fn encode_word(value: u64) -> [u8; WORD_LEN] {
+	let mut word = [0; WORD_LEN];
+	word[WORD_LEN - 8..].copy_from_slice(&value.to_be_bytes());
+	word
+}
+
+/// A revert of the call with `reason`.
+fn revert(reason: &str) -> PrecompileFailure {
+	PrecompileFailure::Revert { exit_status: ExitRevert::Reverted, output: reason.into() }
+}
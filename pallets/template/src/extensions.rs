@@ -0,0 +1,120 @@
+//! Signed extensions of the pallet, to be added to the `SignedExtra` of the runtime.
+
+use crate::{Call, Config, Pallet};
+use codec::{Decode, Encode};
+use frame_support::traits::IsSubType;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+};
+use sp_std::marker::PhantomData;
+
+/// Rejects the transactions of an account which already dispatched `Config::MaxCallsPerBlock`
+/// calls indexing data into the offchain database in the current block, see `Pallet::ocw_calls`.
+///
+/// The limit is checked when the transactions enter the pool, so that spam is rejected before
+/// it reaches block execution, and again when they are dispatched.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T, I))]
+pub struct CheckOcwRateLimit<T: Config<I> + Send + Sync, I: 'static + Send + Sync = ()>(
+	PhantomData<(T, I)>,
+);
+
+impl<T: Config<I> + Send + Sync, I: 'static + Send + Sync> CheckOcwRateLimit<T, I> {
+	/// Create a new `CheckOcwRateLimit`.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config<I> + Send + Sync, I: 'static + Send + Sync> Default for CheckOcwRateLimit<T, I> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config<I> + Send + Sync, I: 'static + Send + Sync> sp_std::fmt::Debug
+	for CheckOcwRateLimit<T, I>
+{
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckOcwRateLimit")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config<I> + Send + Sync, I: 'static + Send + Sync> CheckOcwRateLimit<T, I>
+where
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T, I>>,
+{
+	/// Whether `call` indexes data into the offchain database.
+	fn is_limited(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+		matches!(
+			call.is_sub_type(),
+			Some(
+				Call::write_key_to_ocs { .. } |
+					Call::queue_task { .. } |
+					Call::write_batch_to_ocs { .. } |
+					Call::index_payload { .. }
+			)
+		)
+	}
+
+	/// Check that `who` may dispatch one more call indexing data in the current block.
+	fn check(who: &T::AccountId) -> Result<(), TransactionValidityError> {
+		if Pallet::<T, I>::ocw_calls(who) >= T::MaxCallsPerBlock::get() {
+			return Err(InvalidTransaction::ExhaustsResources.into())
+		}
+		Ok(())
+	}
+}
+
+impl<T: Config<I> + Send + Sync, I: 'static + Send + Sync> SignedExtension
+	for CheckOcwRateLimit<T, I>
+where
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T, I>>,
+{
+	const IDENTIFIER: &'static str = "CheckOcwRateLimit";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if Self::is_limited(call) {
+			Self::check(who)?;
+		}
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<(), TransactionValidityError> {
+		if Self::is_limited(call) {
+			Self::check(who)?;
+			Pallet::<T, I>::note_ocw_call(who);
+		}
+		Ok(())
+	}
+}
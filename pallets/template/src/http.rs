@@ -0,0 +1,340 @@
+//! HTTP fetching for the offchain worker.
+//!
+//! These functions can only be called from the offchain worker context, as they rely on the
+//! offchain HTTP host functions.
+
+use crate::PRICE_DECIMALS;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_runtime::offchain::{
+	http,
+	storage::{StorageRetrievalError, StorageValueRef},
+	Duration,
+};
+use sp_std::vec::Vec;
+
+/// Prefix of the offchain local storage keys the HTTP responses are cached under.
+pub const CACHE_PREFIX: &[u8] = b"template::cache::";
+
+/// Prefix of the offchain local storage keys the time of the last request of each endpoint is
+/// kept under, see `throttle`.
+pub const LAST_REQUEST_PREFIX: &[u8] = b"template::last-request::";
+
+/// The body of a response cached in the offchain local storage.
+#[derive(Encode, Decode, RuntimeDebug)]
+pub struct CachedResponse {
+	pub body: Vec<u8>,
+	/// The offchain timestamp, in milliseconds, the entry expires at.
+	pub expires_at: u64,
+}
+
+/// The ways an HTTP request of the offchain worker can fail.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum HttpError {
+	/// The request was not answered before the deadline.
+	DeadlineReached,
+	/// The request could not be sent or its connection failed.
+	IoError,
+	/// The server answered with a status code other than 200.
+	Non200Status(u16),
+}
+
+impl From<http::Error> for HttpError {
+	fn from(error: http::Error) -> Self {
+		match error {
+			http::Error::DeadlineReached => HttpError::DeadlineReached,
+			http::Error::IoError | http::Error::Unknown => HttpError::IoError,
+		}
+	}
+}
+
+/// Errors that can happen while the offchain worker fetches and parses external data.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum OffchainError {
+	/// The HTTP request failed.
+	Http(HttpError),
+	/// The response body is not valid UTF-8.
+	InvalidBody,
+	/// No value could be parsed from the response body.
+	ParseFailed,
+	/// None of the endpoints queried returned a value.
+	NoSource,
+	/// The response body is larger than the maximum payload size of `OcwConfig`.
+	BodyTooLarge,
+	/// The response body is not the content it was requested by the hash of, see `ipfs`.
+	HashMismatch,
+	/// The response body is longer than the `max_len` it was read with, see `fetch`. The rest
+	/// of the body is not read.
+	ResponseTooLarge,
+	/// The endpoint was requested less than `Config::MinRequestIntervalMs` ago, the request
+	/// was not sent.
+	RateLimited,
+}
+
+impl From<HttpError> for OffchainError {
+	fn from(error: HttpError) -> Self {
+		OffchainError::Http(error)
+	}
+}
+
+impl From<http::Error> for OffchainError {
+	fn from(error: http::Error) -> Self {
+		OffchainError::Http(error.into())
+	}
+}
+
+/// The error of a wait on a pending request that did not complete before its deadline.
+fn deadline_reached<T>(_: T) -> OffchainError {
+	HttpError::DeadlineReached.into()
+}
+
+/// Fetch the body of `url` with a GET request, giving up after `timeout_ms` milliseconds.
+///
+/// At most `max_len` bytes of the body are read: a longer body fails with
+/// `OffchainError::ResponseTooLarge`, so that a misbehaving endpoint can't make the worker
+/// buffer megabytes. A request of `url` less than `min_interval_ms` milliseconds after the last
+/// one isn't sent, see `throttle`. The same holds for the other requests of this module.
+pub fn fetch(
+	url: &str,
+	timeout_ms: u64,
+	max_len: u32,
+	min_interval_ms: u64,
+) -> Result<Vec<u8>, OffchainError> {
+	// We set a deadline for sending of the request, note that awaiting response can
+	// have a separate deadline. Here we use the same deadline for both cases.
+	let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(timeout_ms));
+
+	throttle(url, min_interval_ms)?;
+	let pending = http::Request::get(url).deadline(deadline).send()?;
+
+	// The request is already being processed by the host, we are free to do anything
+	// else in the worker (we can send multiple concurrent requests too).
+	// At some point however we probably want to check the response though,
+	// so we can block current thread and wait for it to finish.
+	let response = pending.try_wait(deadline).map_err(deadline_reached)??;
+	into_body(response, max_len)
+}
+
+/// Fetch the bodies of all `urls` with GET requests sent concurrently, in the order of `urls`,
+/// giving up on the requests not answered after `timeout_ms` milliseconds.
+pub fn fetch_all(
+	urls: &[&str],
+	timeout_ms: u64,
+	max_len: u32,
+	min_interval_ms: u64,
+) -> Vec<Result<Vec<u8>, OffchainError>> {
+	let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(timeout_ms));
+
+	let mut bodies = Vec::with_capacity(urls.len());
+	let (mut indices, mut pending) = (Vec::new(), Vec::new());
+	for (i, url) in urls.iter().enumerate() {
+		let request = throttle(url, min_interval_ms)
+			.and_then(|()| Ok(http::Request::get(url).deadline(deadline).send()?));
+		match request {
+			Ok(request) => {
+				indices.push(i);
+				pending.push(request);
+				bodies.push(Err(HttpError::DeadlineReached.into()));
+			},
+			Err(error) => bodies.push(Err(error)),
+		}
+	}
+
+	let responses = http::PendingRequest::try_wait_all(pending, deadline);
+	for (i, response) in indices.into_iter().zip(responses) {
+		bodies[i] = response
+			.map_err(deadline_reached)
+			.and_then(|response| into_body(response?, max_len));
+	}
+	bodies
+}
+
+/// Send `body` to `url` with a POST request with the additional `headers`, giving up after
+/// `timeout_ms` milliseconds, and return the body of the response.
+pub fn post(
+	url: &str,
+	body: &[u8],
+	headers: &[(&str, &str)],
+	timeout_ms: u64,
+	max_len: u32,
+	min_interval_ms: u64,
+) -> Result<Vec<u8>, OffchainError> {
+	let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(timeout_ms));
+
+	throttle(url, min_interval_ms)?;
+
+	let mut request = http::Request::post(url, sp_std::vec![body]);
+	for (name, value) in headers {
+		request = request.add_header(name, value);
+	}
+	let pending = request.deadline(deadline).send()?;
+
+	let response = pending.try_wait(deadline).map_err(deadline_reached)??;
+	into_body(response, max_len)
+}
+
+/// The `0x` prefixed hexadecimal representation of `bytes`, e.g. for a header value.
+pub fn to_hex(bytes: &[u8]) -> Vec<u8> {
+	const DIGITS: &[u8; 16] = b"0123456789abcdef";
+	let mut hex = Vec::with_capacity(2 + 2 * bytes.len());
+	hex.extend_from_slice(b"0x");
+	for byte in bytes {
+		hex.push(DIGITS[(byte >> 4) as usize]);
+		hex.push(DIGITS[(byte & 0xf) as usize]);
+	}
+	hex
+}
+
+/// The offchain local storage key the time of the last request of `url` is kept under.
+pub fn last_request_key(url: &str) -> Vec<u8> {
+	[LAST_REQUEST_PREFIX, &sp_io::hashing::blake2_256(url.as_bytes())[..]].concat()
+}
+
+/// Record a request of `url` now, unless the last one was less than `min_interval_ms`
+/// milliseconds ago, in which case it fails with `OffchainError::RateLimited`.
+///
+/// The time is kept in the persistent local storage, so that the limit holds across the workers
+/// of successive blocks and the instances of the pallet, however the runtime configures them. A
+/// zero `min_interval_ms` disables the limit.
+fn throttle(url: &str, min_interval_ms: u64) -> Result<(), OffchainError> {
+	if min_interval_ms == 0 {
+		return Ok(())
+	}
+	let now = sp_io::offchain::timestamp().unix_millis();
+	StorageValueRef::persistent(&last_request_key(url))
+		.mutate(|last: Result<Option<u64>, StorageRetrievalError>| match last {
+			Ok(Some(at)) if now < at.saturating_add(min_interval_ms) => Err(()),
+			_ => Ok(now),
+		})
+		.map(|_| ())
+		.map_err(|_| OffchainError::RateLimited)
+}
+
+/// The offchain local storage key the response of a GET request of `url` is cached under.
+pub fn cache_key(url: &str) -> Vec<u8> {
+	[CACHE_PREFIX, &sp_io::hashing::blake2_256(url.as_bytes())[..]].concat()
+}
+
+/// The body cached for `url`, unless it expired.
+fn cached(url: &str) -> Option<Vec<u8>> {
+	let now = sp_io::offchain::timestamp().unix_millis();
+	StorageValueRef::persistent(&cache_key(url))
+		.get::<CachedResponse>()
+		.ok()
+		.flatten()
+		.filter(|entry| now < entry.expires_at)
+		.map(|entry| entry.body)
+}
+
+/// Cache `body` for `url` for `ttl_ms` milliseconds, nothing is cached for a zero `ttl_ms`.
+fn cache(url: &str, body: &[u8], ttl_ms: u64) {
+	if ttl_ms == 0 {
+		return
+	}
+	let expires_at = sp_io::offchain::timestamp().add(Duration::from_millis(ttl_ms)).unix_millis();
+	StorageValueRef::persistent(&cache_key(url))
+		.set(&CachedResponse { body: body.to_vec(), expires_at });
+}
+
+/// Like `fetch`, but reuse the body fetched from `url` within the last `ttl_ms` milliseconds,
+/// if any.
+pub fn fetch_cached(
+	url: &str,
+	timeout_ms: u64,
+	ttl_ms: u64,
+	max_len: u32,
+	min_interval_ms: u64,
+) -> Result<Vec<u8>, OffchainError> {
+	if let Some(body) = cached(url) {
+		return Ok(body)
+	}
+	let body = fetch(url, timeout_ms, max_len, min_interval_ms)?;
+	cache(url, &body, ttl_ms);
+	Ok(body)
+}
+
+/// Like `fetch_all`, but only the `urls` without a body fetched within the last `ttl_ms`
+/// milliseconds are requested.
+pub fn fetch_all_cached(
+	urls: &[&str],
+	timeout_ms: u64,
+	ttl_ms: u64,
+	max_len: u32,
+	min_interval_ms: u64,
+) -> Vec<Result<Vec<u8>, OffchainError>> {
+	let mut bodies = urls.iter().map(|url| cached(url).map(Ok)).collect::<Vec<_>>();
+	let missing = (0..urls.len()).filter(|i| bodies[*i].is_none()).collect::<Vec<_>>();
+	let missing_urls = missing.iter().map(|i| urls[*i]).collect::<Vec<_>>();
+	for (i, body) in
+		missing
+			.into_iter()
+			.zip(fetch_all(&missing_urls, timeout_ms, max_len, min_interval_ms))
+	{
+		if let Ok(body) = &body {
+			cache(urls[i], body, ttl_ms);
+		}
+		bodies[i] = Some(body);
+	}
+	bodies
+		.into_iter()
+		.map(|body| body.unwrap_or(Err(HttpError::IoError.into())))
+		.collect()
+}
+
+/// The body of `response`, if its status code is 200 and it is at most `max_len` bytes long.
+fn into_body(response: http::Response, max_len: u32) -> Result<Vec<u8>, OffchainError> {
+	if response.code != 200 {
+		return Err(HttpError::Non200Status(response.code).into())
+	}
+
+	// One byte more than allowed is enough to tell that the body is too long.
+	let body = response.body().take(max_len as usize + 1).collect::<Vec<u8>>();
+	if body.len() > max_len as usize {
+		return Err(OffchainError::ResponseTooLarge)
+	}
+	Ok(body)
+}
+
+/// Parse the first unsigned integer found in `body`.
+pub fn parse_value(body: &[u8]) -> Result<u64, OffchainError> {
+	let body = sp_std::str::from_utf8(body).map_err(|_| OffchainError::InvalidBody)?;
+
+	let start = body.find(|c: char| c.is_ascii_digit()).ok_or(OffchainError::ParseFailed)?;
+	let digits = &body[start..];
+	let end = digits.find(|c: char| !c.is_ascii_digit()).unwrap_or(digits.len());
+
+	digits[..end].parse::<u64>().map_err(|_| OffchainError::ParseFailed)
+}
+
+/// Parse the first decimal number found in `body` as a fixed point price with
+/// `PRICE_DECIMALS` decimals, e.g. `"12.345"` becomes `12_345_000`.
+///
+/// Decimals beyond `PRICE_DECIMALS` are truncated.
+pub fn parse_price(body: &[u8]) -> Result<u64, OffchainError> {
+	let body = sp_std::str::from_utf8(body).map_err(|_| OffchainError::InvalidBody)?;
+
+	let start = body.find(|c: char| c.is_ascii_digit()).ok_or(OffchainError::ParseFailed)?;
+	let number = &body[start..];
+	let end = number.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(number.len());
+	let mut parts = number[..end].splitn(2, '.');
+
+	let integer = parts
+		.next()
+		.unwrap_or_default()
+		.parse::<u64>()
+		.map_err(|_| OffchainError::ParseFailed)?;
+	let mut price = integer
+		.checked_mul(10u64.pow(PRICE_DECIMALS))
+		.ok_or(OffchainError::ParseFailed)?;
+
+	let fraction = parts.next().unwrap_or_default();
+	for (i, c) in fraction.chars().take(PRICE_DECIMALS as usize).enumerate() {
+		let digit = c.to_digit(10).ok_or(OffchainError::ParseFailed)? as u64;
+		price = price
+			.checked_add(digit * 10u64.pow(PRICE_DECIMALS - 1 - i as u32))
+			.ok_or(OffchainError::ParseFailed)?;
+	}
+
+	Ok(price)
+}
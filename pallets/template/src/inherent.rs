@@ -0,0 +1,76 @@
+//! Delivery of the value of a block by its author, as an inherent, in place of the transactions
+//! of the offchain workers.
+//!
+//! The author puts the latest value known to its node, e.g. the last one fetched by its offchain
+//! worker, into the inherent data with `InherentDataProvider`, and the pallet turns it into a
+//! `set_inherent_value` call, see `Pallet::create_inherent`. Every node checks that the value is
+//! within `Config::MaxDeviation` of the median of the latest finalized round, so that an author
+//! can't set a value the authorities didn't agree on.
+
+use codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+use sp_inherents::{InherentIdentifier, IsFatalError};
+
+/// The identifier of the value in the inherent data.
+pub const INHERENT_IDENTIFIER: InherentIdentifier = *b"ocwvalue";
+
+/// The ways the check of a value set with an inherent can fail.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
+pub enum InherentError {
+	/// The instance doesn't accept values from inherents, see `Config::AcceptInherentValues`.
+	Disabled,
+	/// The instance is paused, see `Pallet::pause`.
+	Paused,
+	/// No round was finalized yet, so there is no median to check the value against.
+	NoMedian,
+	/// The value deviates from the median of the latest finalized round by more than
+	/// `Config::MaxDeviation`.
+	TooFarFromMedian,
+}
+
+impl IsFatalError for InherentError {
+	fn is_fatal_error(&self) -> bool {
+		true
+	}
+}
+
+/// Provides the value of the blocks authored by the node, e.g. the last one fetched by its
+/// offchain worker, read from `FETCHED_VALUE_KEY` of its offchain database.
+#[cfg(feature = "std")]
+pub struct InherentDataProvider {
+	value: Option<u64>,
+}
+
+#[cfg(feature = "std")]
+impl InherentDataProvider {
+	/// A provider of `value`, which provides nothing if it is `None`.
+	pub fn new(value: Option<u64>) -> Self {
+		Self { value }
+	}
+}
+
+#[cfg(feature = "std")]
+#[async_trait::async_trait]
+impl sp_inherents::InherentDataProvider for InherentDataProvider {
+	async fn provide_inherent_data(
+		&self,
+		inherent_data: &mut sp_inherents::InherentData,
+	) -> Result<(), sp_inherents::Error> {
+		match self.value {
+			Some(value) => inherent_data.put_data(INHERENT_IDENTIFIER, &value),
+			None => Ok(()),
+		}
+	}
+
+	async fn try_handle_error(
+		&self,
+		identifier: &InherentIdentifier,
+		error: &[u8],
+	) -> Option<Result<(), sp_inherents::Error>> {
+		if *identifier != INHERENT_IDENTIFIER {
+			return None
+		}
+		let error = InherentError::decode(&mut &error[..]).ok()?;
+		Some(Err(sp_inherents::Error::Application(Box::from(format!("{:?}", error)))))
+	}
+}
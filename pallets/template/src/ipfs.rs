@@ -0,0 +1,147 @@
+//! Fetching of content addressed by its CID through an IPFS HTTP gateway, see `IpfsSource`.
+//!
+//! A gateway is not trusted to serve the content of a CID: the worker requests the raw block of
+//! the CID, with `?format=raw`, and checks it against the multihash of the CID before using it.
+//! Only content held in a single block with a SHA2-256 multihash is supported, addressed by a
+//! CIDv0 (`Qm...`) or by a CIDv1 in base32 (`b...`).
+
+use crate::http::{self, OffchainError};
+use frame_support::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// The multihash code of SHA2-256.
+pub const SHA2_256: u64 = 0x12;
+
+/// The length of a SHA2-256 digest.
+pub const DIGEST_LEN: usize = 32;
+
+/// The ways the parsing of a CID can fail.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum CidError {
+	/// The CID is not valid base58btc or base32, or its bytes are truncated.
+	InvalidEncoding,
+	/// The CID is neither a CIDv0 nor a CIDv1.
+	UnsupportedVersion,
+	/// The multihash of the CID is not a SHA2-256 digest.
+	UnsupportedHash,
+}
+
+/// The SHA2-256 digest of the multihash of `cid`.
+pub fn cid_digest(cid: &[u8]) -> Result<[u8; DIGEST_LEN], CidError> {
+	if cid.len() == 46 && cid.starts_with(b"Qm") {
+		return multihash_digest(&decode_base58(cid).ok_or(CidError::InvalidEncoding)?)
+	}
+	let Some((&b'b', encoded)) = cid.split_first() else {
+		return Err(CidError::UnsupportedVersion)
+	};
+	let bytes = decode_base32(encoded).ok_or(CidError::InvalidEncoding)?;
+	let mut rest = &bytes[..];
+	if read_varint(&mut rest).ok_or(CidError::InvalidEncoding)? != 1 {
+		return Err(CidError::UnsupportedVersion)
+	}
+	// Any codec is fine, the raw block is hashed as is.
+	read_varint(&mut rest).ok_or(CidError::InvalidEncoding)?;
+	multihash_digest(rest)
+}
+
+/// Whether `block` is the content of the CID with the SHA2-256 `digest`.
+pub fn verify(digest: &[u8; DIGEST_LEN], block: &[u8]) -> bool {
+	&sp_io::hashing::sha2_256(block) == digest
+}
+
+/// The digest of the SHA2-256 `multihash`.
+fn multihash_digest(multihash: &[u8]) -> Result<[u8; DIGEST_LEN], CidError> {
+	let mut rest = multihash;
+	let code = read_varint(&mut rest).ok_or(CidError::InvalidEncoding)?;
+	let len = read_varint(&mut rest).ok_or(CidError::InvalidEncoding)?;
+	if code != SHA2_256 || len != DIGEST_LEN as u64 {
+		return Err(CidError::UnsupportedHash)
+	}
+	rest.try_into().map_err(|_| CidError::InvalidEncoding)
+}
+
+/// Read an unsigned LEB128 varint from the front of `bytes`.
+fn read_varint(bytes: &mut &[u8]) -> Option<u64> {
+	let mut value = 0u64;
+	for shift in (0..63).step_by(7) {
+		let (byte, rest) = bytes.split_first()?;
+		*bytes = rest;
+		value |= u64::from(byte & 0x7f) << shift;
+		if byte & 0x80 == 0 {
+			return Some(value)
+		}
+	}
+	None
+}
+
+/// Decode the base58btc `encoded`, with the alphabet of Bitcoin.
+fn decode_base58(encoded: &[u8]) -> Option<Vec<u8>> {
+	const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+	// Big endian digits in base 256.
+	let mut bytes: Vec<u8> = Vec::new();
+	for c in encoded {
+		let mut carry = ALPHABET.iter().position(|a| a == c)? as u32;
+		for byte in bytes.iter_mut().rev() {
+			carry += *byte as u32 * 58;
+			*byte = carry as u8;
+			carry >>= 8;
+		}
+		while carry > 0 {
+			bytes.insert(0, carry as u8);
+			carry >>= 8;
+		}
+	}
+	let zeros = encoded.iter().take_while(|c| **c == b'1').count();
+	Some([&sp_std::vec![0; zeros][..], &bytes].concat())
+}
+
+/// Decode the lowercase, unpadded base32 of RFC 4648 `encoded`.
+fn decode_base32(encoded: &[u8]) -> Option<Vec<u8>> {
+	const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+	let mut bytes = Vec::with_capacity(encoded.len() * 5 / 8);
+	let (mut buffer, mut bits) = (0u32, 0);
+	for c in encoded {
+		buffer = (buffer << 5) | ALPHABET.iter().position(|a| a == c)? as u32;
+		bits += 5;
+		if bits >= 8 {
+			bits -= 8;
+			bytes.push((buffer >> bits) as u8);
+			buffer &= (1 << bits) - 1;
+		}
+	}
+	Some(bytes)
+}
+
+/// The content of CIDs, fetched from an IPFS HTTP gateway and verified against their CID.
+pub struct IpfsSource;
+
+impl IpfsSource {
+	/// The URL of the raw block of `cid` at `gateway`, e.g. `https://ipfs.io/ipfs/<cid>`.
+	pub fn url(gateway: &[u8], cid: &[u8]) -> Vec<u8> {
+		let gateway = gateway.strip_suffix(b"/").unwrap_or(gateway);
+		[gateway, b"/ipfs/", cid, b"?format=raw"].concat()
+	}
+
+	/// Fetch the content of `cid` from `gateway`, giving up after `timeout_ms` milliseconds,
+	/// with at most `max_len` bytes and `min_interval_ms` after the last request of the gateway
+	/// for `cid`, see `http::fetch`.
+	///
+	/// Fails with `OffchainError::HashMismatch` if the gateway answers with anything but the
+	/// content of the CID.
+	pub fn fetch(
+		gateway: &str,
+		cid: &[u8],
+		timeout_ms: u64,
+		max_len: u32,
+		min_interval_ms: u64,
+	) -> Result<Vec<u8>, OffchainError> {
+		let digest = cid_digest(cid).map_err(|_| OffchainError::ParseFailed)?;
+		let url = Self::url(gateway.as_bytes(), cid);
+		let url = sp_std::str::from_utf8(&url).map_err(|_| OffchainError::ParseFailed)?;
+		let block = http::fetch(url, timeout_ms, max_len, min_interval_ms)?;
+		if !verify(&digest, &block) {
+			return Err(OffchainError::HashMismatch)
+		}
+		Ok(block)
+	}
+}
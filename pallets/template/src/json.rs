@@ -0,0 +1,167 @@
+//! JSON parsing of the HTTP responses fetched by the offchain worker, and serialization of the
+//! reports and the snapshots it sends.
+//!
+//! Only top-level fields of a JSON object are supported, e.g. `{"USD": 5.12}`, along with the
+//! records of a paginated feed, see `parse_records`.
+
+use crate::http::OffchainError;
+use lite_json::{
+	json::{JsonObject, JsonValue, NumberValue},
+	Serialize,
+};
+use sp_std::vec::Vec;
+
+/// Serialize the JSON object with the unsigned integer `fields`, e.g. `{"round":1,"value":2}`.
+pub fn serialize_u64_fields(fields: &[(&str, u64)]) -> Vec<u8> {
+	let object = fields
+		.iter()
+		.map(|(key, value)| (key.chars().collect(), number(*value)))
+		.collect();
+	JsonValue::Object(object).serialize()
+}
+
+/// Serialize the snapshot of the `submissions` of `round`, pairs of a hex encoded account and
+/// its value, e.g. `{"round":1,"submissions":[{"account":"0x01","value":2}]}`.
+pub fn serialize_snapshot(round: u64, submissions: &[(Vec<u8>, u64)]) -> Vec<u8> {
+	let submissions = submissions
+		.iter()
+		.map(|(account, value)| {
+			let account = account.iter().map(|byte| *byte as char).collect();
+			JsonValue::Object(sp_std::vec![
+				("account".chars().collect(), JsonValue::String(account)),
+				("value".chars().collect(), number(*value)),
+			])
+		})
+		.collect();
+	JsonValue::Object(sp_std::vec![
+		("round".chars().collect(), number(round)),
+		("submissions".chars().collect(), JsonValue::Array(submissions)),
+	])
+	.serialize()
+}
+
+/// The JSON number of the unsigned integer `value`.
+fn number(value: u64) -> JsonValue {
+	JsonValue::Number(NumberValue {
+		integer: value,
+		fraction: 0,
+		fraction_length: 0,
+		exponent: 0,
+		negative: false,
+	})
+}
+
+/// The JSON object in `body`.
+fn object(body: &[u8]) -> Result<JsonObject, OffchainError> {
+	let body = sp_std::str::from_utf8(body).map_err(|_| OffchainError::InvalidBody)?;
+
+	match lite_json::parse_json(body) {
+		Ok(JsonValue::Object(object)) => Ok(object),
+		_ => Err(OffchainError::InvalidBody),
+	}
+}
+
+/// The value of the field `name` of `object`.
+fn field<'a>(object: &'a JsonObject, name: &str) -> Option<&'a JsonValue> {
+	object
+		.iter()
+		.find(|(key, _)| key.iter().copied().eq(name.chars()))
+		.map(|(_, value)| value)
+}
+
+/// The number of the field `name` of `object`.
+fn number_in(object: &JsonObject, name: &str) -> Result<NumberValue, OffchainError> {
+	match field(object, name) {
+		Some(JsonValue::Number(number)) => Ok(number.clone()),
+		_ => Err(OffchainError::ParseFailed),
+	}
+}
+
+/// The number of the top-level `field` of the JSON object in `body`.
+fn number_field(body: &[u8], field: &str) -> Result<NumberValue, OffchainError> {
+	number_in(&object(body)?, field)
+}
+
+/// Parse the top-level `field` of the JSON object in `body` as an unsigned integer.
+///
+/// Numbers with a fractional part, e.g. `1.5`, are rejected.
+pub fn parse_u64_field(body: &[u8], field: &str) -> Result<u64, OffchainError> {
+	as_u64(number_field(body, field)?)
+}
+
+/// `number` as an unsigned integer, if it has no fractional part.
+fn as_u64(number: NumberValue) -> Result<u64, OffchainError> {
+	if number.negative || number.fraction != 0 || number.exponent < 0 {
+		return Err(OffchainError::ParseFailed)
+	}
+
+	10u64
+		.checked_pow(number.exponent as u32)
+		.and_then(|scale| number.integer.checked_mul(scale))
+		.ok_or(OffchainError::ParseFailed)
+}
+
+/// Parse the top-level `field` of the JSON object in `body` as a fixed point number with
+/// `decimals` decimals, e.g. `12.345` becomes `12_345_000` with 6 decimals.
+///
+/// Decimals beyond `decimals` are truncated, negative numbers are rejected.
+pub fn parse_decimal_as_fixed(
+	body: &[u8],
+	field: &str,
+	decimals: u32,
+) -> Result<u64, OffchainError> {
+	as_fixed(number_field(body, field)?, decimals)
+}
+
+/// The records of the paginated feed in `body`, pairs of the unsigned integer `id` and of the
+/// `value` as a fixed point number with `decimals` decimals, e.g. `(13, 1_500_000)` with 6
+/// decimals for `{"records": [{"id": 13, "value": 1.5}]}`.
+///
+/// The whole page is rejected if one of its records is invalid.
+pub fn parse_records(body: &[u8], decimals: u32) -> Result<Vec<(u64, u64)>, OffchainError> {
+	let object = object(body)?;
+	let Some(JsonValue::Array(records)) = field(&object, "records") else {
+		return Err(OffchainError::ParseFailed)
+	};
+	records
+		.iter()
+		.map(|record| match record {
+			JsonValue::Object(record) => {
+				let id = as_u64(number_in(record, "id")?)?;
+				let value = as_fixed(number_in(record, "value")?, decimals)?;
+				Ok((id, value))
+			},
+			_ => Err(OffchainError::ParseFailed),
+		})
+		.collect()
+}
+
+/// `number` as a fixed point number with `decimals` decimals, see `parse_decimal_as_fixed`.
+fn as_fixed(number: NumberValue, decimals: u32) -> Result<u64, OffchainError> {
+	if number.negative && (number.integer != 0 || number.fraction != 0) {
+		return Err(OffchainError::ParseFailed)
+	}
+
+	// The number is `mantissa * 10^-fraction_length * 10^exponent`.
+	let mantissa = 10u128
+		.checked_pow(number.fraction_length)
+		.and_then(|scale| (number.integer as u128).checked_mul(scale))
+		.and_then(|integer| integer.checked_add(number.fraction as u128))
+		.ok_or(OffchainError::ParseFailed)?;
+	let scale = decimals as i64 - number.fraction_length as i64 + number.exponent as i64;
+
+	let fixed = if scale >= 0 {
+		u32::try_from(scale)
+			.ok()
+			.and_then(|scale| 10u128.checked_pow(scale))
+			.and_then(|scale| mantissa.checked_mul(scale))
+			.ok_or(OffchainError::ParseFailed)?
+	} else {
+		u32::try_from(-scale)
+			.ok()
+			.and_then(|scale| 10u128.checked_pow(scale))
+			.map_or(0, |scale| mantissa / scale)
+	};
+
+	u64::try_from(fixed).map_err(|_| OffchainError::ParseFailed)
+}
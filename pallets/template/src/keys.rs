@@ -0,0 +1,128 @@
+//! Derivation of the offchain database keys the tasks queued for the offchain worker are indexed
+//! under.
+//!
+//! A key is laid out as `scope ++ ONCHAIN_TX_KEY ++ "v1/" ++ namespace ++ "/" ++ block ++ index`,
+//! where the scope separates the instances of the pallet, see `Pallet::key_scope`, the namespace
+//! depends on the kind of task and the block number and the queue index are SCALE encoded. Keys are
+//! deterministic, so they can be derived both on chain, from `IndexedKeys`, and by tooling, and
+//! `decode_key` maps them back to the block and task they belong to.
+
+use crate::{Task, ONCHAIN_TX_KEY};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// The version of the key layout, bumped whenever it changes.
+pub const KEY_VERSION: &[u8] = b"v1/";
+
+/// The kind of the data indexed under a key, which namespaces the key.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum KeyKind {
+	FetchPrice,
+	SubmitValue,
+	VerifyPayload,
+	Notify,
+	/// An entry indexed with `write_batch_to_ocs`, rather than a task.
+	BatchEntry,
+}
+
+impl KeyKind {
+	/// All the kinds of keys.
+	pub const ALL: [KeyKind; 5] = [
+		KeyKind::FetchPrice,
+		KeyKind::SubmitValue,
+		KeyKind::VerifyPayload,
+		KeyKind::Notify,
+		KeyKind::BatchEntry,
+	];
+
+	/// The namespace of the keys of this kind.
+	pub fn namespace(&self) -> &'static [u8] {
+		match self {
+			KeyKind::FetchPrice => b"fetch-price",
+			KeyKind::SubmitValue => b"submit-value",
+			KeyKind::VerifyPayload => b"verify-payload",
+			KeyKind::Notify => b"notify",
+			KeyKind::BatchEntry => b"batch-entry",
+		}
+	}
+}
+
+impl From<&Task> for KeyKind {
+	fn from(task: &Task) -> Self {
+		match task {
+			Task::FetchPrice => KeyKind::FetchPrice,
+			Task::SubmitValue(_) => KeyKind::SubmitValue,
+			Task::VerifyPayload(_) => KeyKind::VerifyPayload,
+			Task::Notify(_) => KeyKind::Notify,
+		}
+	}
+}
+
+/// The parts an offchain database key is derived from.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct KeyDerivation<BlockNumber> {
+	pub kind: KeyKind,
+	pub block_number: BlockNumber,
+	pub index: u32,
+}
+
+impl<BlockNumber: Encode> KeyDerivation<BlockNumber> {
+	/// The prefix of the keys of `kind` in `scope`, common to all blocks.
+	pub fn prefix(scope: &[u8], kind: KeyKind) -> Vec<u8> {
+		[scope, ONCHAIN_TX_KEY, KEY_VERSION, kind.namespace(), b"/"].concat()
+	}
+
+	/// The prefix of the keys of `kind` in `scope` queued in `block_number`.
+	pub fn block_prefix(scope: &[u8], kind: KeyKind, block_number: &BlockNumber) -> Vec<u8> {
+		let mut key = Self::prefix(scope, kind);
+		block_number.encode_to(&mut key);
+		key
+	}
+
+	/// The offchain database key in `scope`.
+	pub fn key(&self, scope: &[u8]) -> Vec<u8> {
+		let mut key = Self::block_prefix(scope, self.kind, &self.block_number);
+		self.index.encode_to(&mut key);
+		key
+	}
+}
+
+/// The offchain database key in `scope` of the task queued at `index` in `block_number` in the
+/// layout before `KEY_VERSION`, `ONCHAIN_TX_KEY ++ "/" ++ block ++ index`.
+///
+/// The legacy keys predate the instances of the pallet, so only those of the default instance,
+/// whose scope is empty, were ever indexed.
+pub fn legacy_key<BlockNumber: Encode>(
+	scope: &[u8],
+	block_number: &BlockNumber,
+	index: u32,
+) -> Vec<u8> {
+	let mut key = [scope, ONCHAIN_TX_KEY, b"/"].concat();
+	block_number.encode_to(&mut key);
+	index.encode_to(&mut key);
+	key
+}
+
+/// The offchain database key in `scope` of the payload with the Merkle root `root` indexed with
+/// `index_payload`, which holds its `blob::Manifest`.
+pub fn payload_key<Hash: Encode>(scope: &[u8], root: &Hash) -> Vec<u8> {
+	let mut key = [scope, ONCHAIN_TX_KEY, KEY_VERSION, b"payload/"].concat();
+	root.encode_to(&mut key);
+	key
+}
+
+/// The parts `key` was derived from, if it is a key of `scope` of the current layout.
+pub fn decode_key<BlockNumber: Encode + Decode>(
+	scope: &[u8],
+	key: &[u8],
+) -> Option<KeyDerivation<BlockNumber>> {
+	let kind = KeyKind::ALL
+		.into_iter()
+		.find(|kind| key.starts_with(&KeyDerivation::<BlockNumber>::prefix(scope, *kind)))?;
+	let mut rest = &key[KeyDerivation::<BlockNumber>::prefix(scope, kind).len()..];
+	let block_number = BlockNumber::decode(&mut rest).ok()?;
+	let index = u32::decode(&mut rest).ok()?;
+	rest.is_empty().then_some(KeyDerivation { kind, block_number, index })
+}
@@ -13,96 +13,6558 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod blob;
+pub mod budget;
+pub mod compression;
+#[cfg(feature = "contracts")]
+pub mod contracts;
+#[cfg(feature = "evm")]
+pub mod evm;
+pub mod extensions;
+pub mod http;
+pub mod inherent;
+pub mod ipfs;
+pub mod json;
+pub mod keys;
+pub mod logging;
+pub mod logic;
+pub mod merkle;
+pub mod migrations;
+#[cfg(feature = "xcm")]
+pub mod remote;
+pub mod runtime_api;
+#[cfg(feature = "session")]
+pub mod session;
+pub mod sources;
+pub mod traits;
+pub mod url;
 pub mod weights;
 pub use weights::*;
 
+pub use extensions::CheckOcwRateLimit;
+#[cfg(feature = "std")]
+pub use inherent::InherentDataProvider;
+pub use inherent::{InherentError, INHERENT_IDENTIFIER};
+
+use sp_core::crypto::KeyTypeId;
+
+/// Defines application identifier for crypto keys of this module.
+///
+/// Every module that deals with signatures needs to declare its unique identifier for
+/// its crypto keys.
+/// When offchain worker is signing transactions it's going to request keys of type
+/// `KeyTypeId` from the keystore and use the ones it finds to sign the transaction.
+/// The keys can be inserted manually via RPC (see `author_insertKey`).
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"ocw!");
+
+/// Based on the above `KeyTypeId` we need to generate a pallet-specific crypto type wrappers.
+/// We can use from supported crypto kinds (`sr25519`, `ed25519` and `ecdsa`) and augment
+/// the types with this pallet-specific identifier.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_core::sr25519::Signature as Sr25519Signature;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		traits::Verify,
+		MultiSignature, MultiSigner,
+	};
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// The identifier used by runtimes to sign offchain worker transactions with the keys of
+	/// this pallet.
+	pub struct OcwAuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for OcwAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+
+	// implemented for runtimes which use plain sr25519 signatures
+	impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature>
+		for OcwAuthId
+	{
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
+/// Creates the identifiers of the assets registered by the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	/// The identifier of the `i`-th asset.
+	fn asset_id(i: u32) -> AssetId;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<AssetId: From<u32>> BenchmarkHelper<AssetId> for () {
+	fn asset_id(i: u32) -> AssetId {
+		i.into()
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::*;
+	use crate::{
+		blob, budget, compression,
+		inherent::{InherentError, INHERENT_IDENTIFIER},
+		keys::{self, KeyDerivation, KeyKind},
+		logging, logic, merkle,
+		sources::DataSources,
+		traits::{OnNewValue, ProvideOracleValue, WithAnswer},
+		url,
+	};
+	use frame_support::{
+		dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
+		pallet_prelude::*,
+		traits::{Currency, ExistenceRequirement, PalletInfoAccess, ReservableCurrency, UnixTime},
+		CloneNoBound, EqNoBound, PalletId, PartialEqNoBound, RuntimeDebugNoBound,
+	};
+	use frame_system::{
+		offchain::{
+			AppCrypto, CreateSignedTransaction, SendSignedTransaction, SendUnsignedTransaction,
+			SignedPayload, Signer, SigningTypes, SubmitTransaction,
+		},
+		pallet_prelude::*,
+	};
+	use sp_runtime::{
+		generic::Era,
+		offchain::{
+			storage::{StorageRetrievalError, StorageValueRef},
+			storage_lock::{BlockAndTime, StorageLock},
+			Duration, StorageKind,
+		},
+		traits::{
+			AccountIdConversion, BlockNumberProvider, Dispatchable, Hash, IdentifyAccount, One,
+			Saturating, Zero,
+		},
+		Perbill, RuntimeAppPublic, SaturatedConversion,
+	};
+	use sp_std::{any::TypeId, fmt::Debug, vec::Vec};
+
+	/// The log target of this pallet.
+	pub const LOG_TARGET: &str = "runtime::template";
+
+	/// Prefix of the keys under which the tasks queued for the offchain worker are indexed into
+	/// the offchain database, see `keys`.
+	pub const ONCHAIN_TX_KEY: &[u8] = b"template::indexing::";
+
+	/// The number of decimals of the fixed point prices handled by the pallet, a price of
+	/// `1_000_000` stands for `1.0`.
+	pub const PRICE_DECIMALS: u32 = 6;
+
+	/// Offchain local storage key of the latest value fetched over HTTP by the offchain worker.
+	pub const FETCHED_VALUE_KEY: &[u8] = b"template::fetched-value";
+
+	/// Offchain local storage key switching the offchain worker of a node to a dry run: while
+	/// it holds any value, e.g. set with the `offchain_localStorageSet` RPC, the worker only
+	/// logs what it would fetch and submit, see `is_disabled`.
+	pub const DISABLED_KEY: &[u8] = b"ocw::disabled";
+
+	/// Offchain local storage key of the `OcwMetrics` of the offchain worker.
+	pub const METRICS_KEY: &[u8] = b"template::metrics";
+
+	/// Offchain local storage key of the latest `Anomaly`s of the fetched values, see
+	/// `anomalies`.
+	pub const ANOMALIES_KEY: &[u8] = b"template::anomalies";
+
+	/// The number of `Anomaly`s kept in the offchain local storage, the oldest are dropped first.
+	pub const MAX_ANOMALIES: usize = 16;
+
+	/// Offchain local storage key of the seed of the randomness of the node, see `node_seed`.
+	pub const NODE_SEED_KEY: &[u8] = b"template::node-seed";
+
+	/// Offchain local storage key of the lock held while a worker runs the pipeline.
+	pub const LOCK_KEY: &[u8] = b"template::lock";
+
+	/// Offchain local storage key of the `WorkerStage` the next run of the offchain worker
+	/// starts with, if the previous run ran out of time before it, see `run_stages`.
+	pub const STAGE_KEY: &[u8] = b"template::stage";
+
+	/// After how many blocks the lock held by a worker expires, unless `Config::LockTimeout`
+	/// passes first.
+	pub const LOCK_BLOCK_EXPIRATION: u32 = 3;
+
+	/// For how many blocks an unsigned transaction submitted by the offchain worker stays valid.
+	pub const UNSIGNED_TX_LONGEVITY: u64 = 5;
+
+	/// After how many blocks without a new value the priority of a submission stops growing,
+	/// see `submission_priority`.
+	pub const PRIORITY_STALENESS_BLOCKS: u32 = 100;
+
+	/// Offchain local storage key of the `RetryState` of the pipeline.
+	pub const RETRY_KEY: &[u8] = b"template::retry";
+
+	/// Offchain local storage key of the `PipelineState` of the price of the current round,
+	/// along with the round and the `NextUnsignedAt` it is for, see `pipeline_state`.
+	pub const PIPELINE_KEY: &[u8] = b"template::pipeline";
+
+	/// Offchain local storage key of the last block whose indexed entries the garbage
+	/// collection of the offchain worker removed.
+	pub const GC_CURSOR_KEY: &[u8] = b"template::gc-cursor";
+
+	/// Maximum number of blocks whose indexed entries the offchain worker removes per run.
+	pub const GC_MAX_BLOCKS_PER_RUN: u32 = 16;
+
+	/// Offchain local storage key of the next block whose tasks the offchain worker moves from
+	/// their legacy key, see `migrate_legacy_keys`.
+	pub const LEGACY_KEYS_CURSOR_KEY: &[u8] = b"template::legacy-keys-cursor";
+
+	/// Offchain local storage key of the `PendingReveal` of the value committed by the worker.
+	pub const REVEAL_KEY: &[u8] = b"template::reveal";
+
+	/// Offchain local storage key of the last round the worker sent a report of.
+	pub const REPORT_KEY: &[u8] = b"template::last-report";
+
+	/// Prefix of the offchain database keys of the snapshots anchored in `Snapshots`, see
+	/// `snapshot_key`. The key of a round is the prefix followed by its encoded index.
+	pub const SNAPSHOT_KEY: &[u8] = b"template::snapshot::";
+
+	/// Offchain local storage key of the last round the worker archived the snapshot of.
+	pub const ARCHIVE_KEY: &[u8] = b"template::last-archive";
+
+	/// Offchain local storage key of the last round the worker submitted the prices of the
+	/// assets in.
+	pub const ASSET_ROUND_KEY: &[u8] = b"template::asset-round";
+
+	/// Prefix of the offchain local storage keys of the last round each local account submitted
+	/// a signed value in, see `claim_submission`. The key of an account is the prefix followed
+	/// by the encoded account.
+	pub const SUBMITTED_KEY: &[u8] = b"template::submitted";
+
+	/// Offchain local storage key of the id of the last record of the feed at
+	/// `Config::FeedEndpoint` the worker submitted, and of the block it submitted it in.
+	pub const FEED_CURSOR_KEY: &[u8] = b"template::feed-cursor";
+
+	/// Maximum number of records of the feed the offchain worker submits per run.
+	pub const MAX_FEED_RECORDS: usize = 16;
+
+	/// Prefix of the offchain local storage keys of the block the worker last sent the anchor
+	/// of a document in, see `send_document_anchors`. The key of a document is the prefix
+	/// followed by its CID.
+	pub const DOCUMENT_KEY: &[u8] = b"template::document::";
+
+	/// Maximum number of documents the offchain worker fetches per run.
+	pub const MAX_DOCUMENTS_PER_RUN: usize = 4;
+
+	/// Prefix of the offchain local storage keys of the block the worker last sent the
+	/// fulfillment of a request in, see `send_request_fulfillments`. The key of a request is the
+	/// prefix followed by its encoded id.
+	pub const REQUEST_KEY: &[u8] = b"template::request::";
+
+	/// Maximum number of requests the offchain worker fetches the answer of per run.
+	pub const MAX_REQUESTS_PER_RUN: usize = 4;
+
+	/// The in-code storage version.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
+
+	pub type BalanceOf<T, I = ()> =
+		<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// The index of a round of submissions, see `Config::RoundLength`.
+	pub type RoundIndex = u32;
+
+	/// The identifier of a request of a value, see `request_value`.
+	pub type RequestId = u64;
+
+	/// The phases a round goes through.
+	#[derive(
+		Clone,
+		Copy,
+		Default,
+		Encode,
+		Decode,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub enum RoundPhase {
+		/// The offchain workers submit their values.
+		#[default]
+		Open,
+		/// The last `Config::ClosingPeriod` blocks of the round: no new unsigned submissions
+		/// are accepted, so that those in flight can land before the round is finalized.
+		Closing,
+		/// The submissions were aggregated, the next round starts with the next block.
+		Finalized,
+	}
+
+	/// Work deferred to `on_idle`, see `PendingWork`.
+	#[derive(
+		Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub enum WorkItem<BlockNumber> {
+		/// Remove the values stored before block `up_to`, see `prune_history`.
+		PruneHistory { up_to: BlockNumber },
+	}
+
+	/// How an authority's submissions compared to the finalized medians.
+	#[derive(
+		Clone,
+		Default,
+		Encode,
+		Decode,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub struct ReputationInfo {
+		/// The number of finalized rounds the authority submitted a value in.
+		pub rounds: u32,
+		/// The number of rounds in a row the value of the authority deviated beyond
+		/// `Config::MaxDeviation` from the median.
+		pub strikes: u32,
+	}
+
+	/// The state of the current round.
+	#[derive(
+		Clone,
+		Default,
+		Encode,
+		Decode,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct RoundInfo<BlockNumber> {
+		pub index: RoundIndex,
+		/// The first block of the round.
+		pub started_at: BlockNumber,
+		pub phase: RoundPhase,
+		/// The seed of the elections of the round, see `Pallet::round_seed`.
+		pub seed: [u8; 32],
+	}
+
+	/// A payload stored with `submit_data`, along with the deposit reserved for it.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(MaxDataLen))]
+	#[codec(mel_bound(Balance: MaxEncodedLen))]
+	pub struct DataEntry<Balance: Clone + PartialEq + Eq + Debug, MaxDataLen: Get<u32>> {
+		pub data: BoundedVec<u8, MaxDataLen>,
+		pub deposit: Balance,
+	}
+
+	/// The `DataEntry` of an instance of the pallet.
+	pub type DataEntryOf<T, I = ()> = DataEntry<BalanceOf<T, I>, <T as Config<I>>::MaxDataLen>;
+
+	/// The format of the responses of the endpoints the price is fetched from, see
+	/// `OcwConfigInfo`.
+	#[derive(
+		Clone,
+		Copy,
+		Default,
+		Encode,
+		Decode,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum ResponseFormat {
+		/// A JSON object with the price in its `Config::PriceField` field, e.g. `{"USD": 5.12}`.
+		#[default]
+		Json,
+		/// The price with `PRICE_DECIMALS` decimals as a SCALE encoded `u64`.
+		Scale,
+		/// The price with `PRICE_DECIMALS` decimals as an unsigned integer in plain text, e.g.
+		/// `5120000`.
+		PlainTextU64,
+	}
+
+	/// When the rounds are finalized, see `Config::FinalizationMode`.
+	#[derive(
+		Clone,
+		Copy,
+		Default,
+		Encode,
+		Decode,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum FinalizationMode {
+		/// At the end of the round, see `Config::RoundLength`.
+		#[default]
+		RoundEnd,
+		/// As soon as `threshold` of the values submitted in the round are within `tolerance`
+		/// of one of them, at the start of the next block, or at the end of the round if they
+		/// never are.
+		Threshold { threshold: u32, tolerance: Perbill },
+	}
+
+	/// How the values submitted in a round are aggregated into its value, see `OcwConfigInfo`
+	/// and `logic::aggregate_with`.
+	#[derive(
+		Clone,
+		Copy,
+		Default,
+		Encode,
+		Decode,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum AggregationStrategy {
+		/// The median of the values.
+		#[default]
+		Median,
+		/// The mean of the values, but the lowest and the highest quarter of them.
+		TrimmedMean,
+		/// The mean of the values, but those further than `logic::MAD_THRESHOLD` median
+		/// absolute deviations from their median.
+		MadFiltered,
+	}
+
+	/// The behavior of the offchain worker and the aggregation of its submissions, set by
+	/// governance with `set_ocw_config` so that it can be changed without a runtime upgrade.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(MaxEndpointLen))]
+	#[codec(mel_bound(BlockNumber: MaxEncodedLen))]
+	pub struct OcwConfigInfo<BlockNumber: Clone + PartialEq + Eq + Debug, MaxEndpointLen: Get<u32>> {
+		/// The URL the price is fetched from while `OracleEndpoints` is empty, or
+		/// `Config::HttpEndpoint` if this is empty.
+		pub endpoint: BoundedVec<u8, MaxEndpointLen>,
+		/// Number of blocks between two submissions, see `NextUnsignedAt`.
+		pub fetch_interval: BlockNumber,
+		/// Maximum size in bytes of a response body, larger ones are rejected.
+		pub max_payload_size: u32,
+		/// Number of times a failed pipeline run is retried before the worker gives up.
+		pub max_retries: u32,
+		/// The format of the responses of the endpoints, see `logic::decode_response`.
+		pub format: ResponseFormat,
+		/// How the submissions of a round are aggregated, for the round and for each asset.
+		pub aggregation: AggregationStrategy,
+	}
+
+	/// The `OcwConfigInfo` of an instance of the pallet.
+	pub type OcwConfigOf<T, I = ()> =
+		OcwConfigInfo<BlockNumberFor<T>, <T as Config<I>>::MaxEndpointLen>;
+
+	/// An asset registered with `register_asset`.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(MaxEndpointLen))]
+	#[codec(mel_bound())]
+	pub struct AssetInfo<MaxEndpointLen: Get<u32>> {
+		/// The URL the price of the asset is fetched from, or empty to fetch it from the
+		/// `EndpointTemplate`. Either is a template whose `{ASSET}` placeholder stands for the
+		/// `field`, see `url`.
+		pub endpoint: BoundedVec<u8, MaxEndpointLen>,
+		/// The field of the JSON object returned by the endpoint the price is read from.
+		pub field: BoundedVec<u8, MaxEndpointLen>,
+	}
+
+	/// The `AssetInfo` of an instance of the pallet.
+	pub type AssetInfoOf<T, I = ()> = AssetInfo<<T as Config<I>>::MaxEndpointLen>;
+
+	/// The CID of a document, see `ipfs`.
+	pub type CidOf<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxCidLen>;
+
+	/// The latest finalized price of an asset.
+	#[derive(
+		Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub struct PriceInfo<BlockNumber> {
+		/// The median of the prices submitted by the authorities in the round.
+		pub value: u64,
+		/// The index of the round among the finalized rounds of the asset, starting at zero.
+		pub round: RoundIndex,
+		/// The block the round was finalized in.
+		pub updated_at: BlockNumber,
+	}
+
+	/// An entry indexed with `write_batch_to_ocs`: a label and its value.
+	pub type BatchEntry<T, I = ()> = (BoundedVec<u8, <T as Config<I>>::MaxBatchLabelLen>, u64);
+
+	/// A topic accounts subscribe to with `subscribe`, its value is fetched by the offchain
+	/// worker, see `Config::TopicEndpoint`.
+	pub type Topic<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxTopicLen>;
+
+	/// Maximum length of the message of a `Task::Notify`.
+	pub const MAX_NOTIFY_LEN: u32 = 128;
+
+	/// The length of the chunks of the payloads indexed with `index_payload`, the last chunk of
+	/// a payload may be shorter.
+	pub const PAYLOAD_CHUNK_LEN: u32 = 256;
+
+	/// A task queued by an extrinsic for the offchain worker of the same block.
+	///
+	/// Tasks are indexed into the offchain database, one key per task, and their hashes are
+	/// committed on chain in `IndexCommitments`. Tasks scheduled for a later block with
+	/// `schedule_task` are kept in `ScheduledTasks` instead.
+	#[derive(
+		Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub enum Task {
+		/// Fetch the price from `Config::HttpEndpoint` into the offchain local storage, without
+		/// submitting it.
+		FetchPrice,
+		/// Submit the value on chain instead of the fetched price.
+		SubmitValue(u64),
+		/// Check that the latest value on chain is the given one.
+		VerifyPayload(u64),
+		/// Log the message on the nodes running the offchain worker.
+		Notify(BoundedVec<u8, ConstU32<MAX_NOTIFY_LEN>>),
+	}
+
+	/// The first version tag of `IndexedTask`, above the variants of `Task`.
+	pub const FIRST_TASK_VERSION: u8 = 0x80;
+
+	/// A `Task` as indexed into the offchain database, tagged with the version of its layout so
+	/// that the layout can change without breaking the tasks indexed before the runtime upgrade.
+	///
+	/// The tags start at `FIRST_TASK_VERSION`, so that they are told apart from the bare `Task`s
+	/// indexed before the tags were introduced, which are decoded as `V1`. See `decode_versioned`.
+	#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
+	pub enum IndexedTask<AccountId> {
+		/// A task on its own.
+		#[codec(index = 0x80)]
+		V1(Task),
+		/// A task and the account which queued it.
+		#[codec(index = 0x81)]
+		V2 { task: Task, queued_by: AccountId },
+	}
+
+	impl<AccountId: Decode> IndexedTask<AccountId> {
+		/// Decode the indexed `bytes` of any version, `None` if their version is one of a later
+		/// runtime, unknown to this one.
+		///
+		/// The bytes after the layout of a known version are ignored, so that a later runtime
+		/// may append fields to a layout without making its tasks undecodable by this one.
+		pub fn decode_versioned(bytes: &[u8]) -> Result<Option<Self>, codec::Error> {
+			match bytes.first() {
+				Some(tag) if *tag < FIRST_TASK_VERSION =>
+					Task::decode(&mut &bytes[..]).map(|task| Some(Self::V1(task))),
+				Some(0x80..=0x81) => Self::decode(&mut &bytes[..]).map(Some),
+				Some(_) => Ok(None),
+				None => Err("empty indexed task".into()),
+			}
+		}
+
+		/// The task, whatever the version.
+		pub fn task(&self) -> &Task {
+			match self {
+				Self::V1(task) | Self::V2 { task, .. } => task,
+			}
+		}
+	}
+
+	/// Payload used by the offchain worker to submit a value with an unsigned transaction,
+	/// signed by one of its keys so the authority that produced the value can be verified.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct DataPayload<Public, BlockNumber> {
+		pub block_number: BlockNumber,
+		pub value: u64,
+		pub public: Public,
+	}
+
+	impl<T: SigningTypes> SignedPayload<T> for DataPayload<T::Public, BlockNumberFor<T>> {
+		fn public(&self) -> T::Public {
+			self.public.clone()
+		}
+	}
+
+	/// A record of the feed at `Config::FeedEndpoint`, submitted by the offchain worker with
+	/// `submit_record_unsigned`.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct FeedRecord {
+		/// The id of the record before this one, which must be the `feed_cursor` when this one
+		/// is submitted.
+		pub since: u64,
+		pub id: u64,
+		pub value: u64,
+	}
+
+	/// Payload used by the offchain worker to submit a record of the feed with an unsigned
+	/// transaction once there are authorities, signed by one of their keys.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct RecordPayload<Public> {
+		pub record: FeedRecord,
+		pub public: Public,
+	}
+
+	impl<T: SigningTypes> SignedPayload<T> for RecordPayload<T::Public> {
+		fn public(&self) -> T::Public {
+			self.public.clone()
+		}
+	}
+
+	/// The public key of an external signer of data, e.g. of a signed API, see
+	/// `submit_attested_data`.
+	#[derive(
+		Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub enum AttesterKey {
+		Ed25519(sp_core::ed25519::Public),
+		Ecdsa(sp_core::ecdsa::Public),
+	}
+
+	/// A signature of data by an `AttesterKey`.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub enum Attestation {
+		Ed25519(sp_core::ed25519::Signature),
+		Ecdsa(sp_core::ecdsa::Signature),
+	}
+
+	impl AttesterKey {
+		/// Whether `signature` is a signature of `message` by this key.
+		///
+		/// An ECDSA signature is over the `blake2_256` hash of `message`, an Ed25519 one over
+		/// `message` itself. A signature of the other scheme is never valid.
+		pub fn verify(&self, signature: &Attestation, message: &[u8]) -> bool {
+			match (self, signature) {
+				(Self::Ed25519(public), Attestation::Ed25519(signature)) =>
+					sp_io::crypto::ed25519_verify(signature, message, public),
+				(Self::Ecdsa(public), Attestation::Ecdsa(signature)) =>
+					sp_io::crypto::ecdsa_verify(signature, message, public),
+				_ => false,
+			}
+		}
+	}
+
+	/// Payload of a heartbeat, signed by the offchain worker key of an authority.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct HeartbeatPayload<Public, BlockNumber> {
+		pub block_number: BlockNumber,
+		pub public: Public,
+	}
+
+	impl<T: SigningTypes> SignedPayload<T> for HeartbeatPayload<T::Public, BlockNumberFor<T>> {
+		fn public(&self) -> T::Public {
+			self.public.clone()
+		}
+	}
+
+	/// How often the pipeline failed in a row, kept in the offchain local storage of a node.
+	#[derive(Encode, Decode, Default, RuntimeDebug)]
+	pub struct RetryState<BlockNumber> {
+		/// The number of consecutive failed attempts.
+		pub attempts: u32,
+		/// The pipeline backs off until this block.
+		pub next_retry_at: BlockNumber,
+	}
+
+	/// How far the pipeline got with the price of a round, kept in the offchain local storage of
+	/// a node under `PIPELINE_KEY`, so that the run following one which crashed or ran out of
+	/// time resumes after the last completed stage rather than fetching the price again.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+	pub enum PipelineState {
+		/// The price was fetched, but not checked yet.
+		Fetched(u64),
+		/// The price passed `check_value`, but was not submitted yet.
+		Parsed(u64),
+		/// The price was submitted, the next run starts over.
+		Submitted,
+	}
+
+	/// Counters of the health of the offchain worker of a node, kept in its offchain local
+	/// storage under `METRICS_KEY` so that the node can export them.
+	#[derive(Clone, Encode, Decode, Default, PartialEq, Eq, RuntimeDebug)]
+	pub struct OcwMetrics {
+		/// The number of times the price was fetched.
+		pub fetch_successes: u64,
+		/// The number of times the price couldn't be fetched.
+		pub fetch_failures: u64,
+		/// The number of values submitted on chain.
+		pub submissions: u64,
+		/// The milliseconds from the start of the run to the submission of the latest value.
+		pub submission_latency_ms: u64,
+		/// The block the worker last ran for.
+		pub last_processed_block: u64,
+	}
+
+	/// The stages of a run of the offchain worker, in the order they run in, see `run_stages`.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+	pub enum WorkerStage {
+		Heartbeats,
+		Pipeline,
+		CommitReveal,
+		Report,
+		Archive,
+		Topics,
+		AssetPrices,
+		Feed,
+		Documents,
+		Requests,
+		GarbageCollection,
+	}
+
+	impl WorkerStage {
+		/// All the stages, in the order they run in.
+		pub const ALL: [WorkerStage; 11] = [
+			WorkerStage::Heartbeats,
+			WorkerStage::Pipeline,
+			WorkerStage::CommitReveal,
+			WorkerStage::Report,
+			WorkerStage::Archive,
+			WorkerStage::Topics,
+			WorkerStage::AssetPrices,
+			WorkerStage::Feed,
+			WorkerStage::Documents,
+			WorkerStage::Requests,
+			WorkerStage::GarbageCollection,
+		];
+	}
+
+	/// Why a fetched value failed the sanity checks of `check_value`.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub enum AnomalyReason {
+		/// The value is lower than `Config::MinValue`.
+		BelowMin,
+		/// The value is higher than `Config::MaxValue`.
+		AboveMax,
+		/// The value changed by more than `Config::MaxChangePerRound` since the latest round.
+		TooLargeChange,
+	}
+
+	/// Whether a submission of an account would be accepted, see `Pallet::can_submit`.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub enum SubmissionValidity {
+		/// The submission would be accepted.
+		Valid,
+		/// The instance is paused, see `Pallet::pause`.
+		Paused,
+		/// The account is none of the `Authorities`.
+		NotAuthority,
+		/// Raw unsigned submissions are rejected once there are authorities, as they can't prove
+		/// which key they come from.
+		NeedsSignedPayload,
+		/// The submission is for a round other than the `current` one.
+		WrongRound { current: RoundIndex },
+		/// An unsigned value was already accepted in the current fetch interval, see
+		/// `NextUnsignedAt`.
+		TooEarly,
+		/// The current round is `RoundPhase::Closing`: only signed submissions are accepted.
+		Closing,
+		/// The current round is finalized: signed submissions are still accepted but no longer
+		/// count towards it, unsigned ones are rejected until the next round.
+		Finalized,
+		/// The value fails the sanity checks the offchain workers drop values with before
+		/// submitting them, see `Pallet::check_value`. Those don't apply on chain.
+		Anomalous(AnomalyReason),
+	}
+
+	/// A fetched value dropped by the offchain worker of a node instead of being submitted, kept
+	/// in its offchain local storage under `ANOMALIES_KEY`.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+	pub struct Anomaly<BlockNumber> {
+		/// The block the worker fetched the value for.
+		pub block_number: BlockNumber,
+		pub value: u64,
+		pub reason: AnomalyReason,
+	}
+
+	/// The hash of a value committed with `commit_value`, to be revealed later.
+	#[derive(
+		Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub struct ValueCommitment<Hash, BlockNumber, Balance> {
+		/// The hash of the value and the salt, see `Pallet::reveal_hash`.
+		pub hash: Hash,
+		/// The block the commitment was made at.
+		pub committed_at: BlockNumber,
+		/// The deposit reserved until the value is revealed.
+		pub deposit: Balance,
+	}
+
+	/// The deposits reserved from an account, see `Deposits`.
+	#[derive(
+		Clone,
+		Copy,
+		Default,
+		Encode,
+		Decode,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub struct DepositInfo<Balance> {
+		/// The total of the deposits of the data, the tasks, the commitments, the subscriptions
+		/// and the disputes of the account.
+		pub reserved: Balance,
+		/// The part of `reserved` for tasks whose commitments were pruned before the offchain
+		/// worker confirmed them, which the account may release with `reclaim_deposit`.
+		pub reclaimable: Balance,
+	}
+
+	/// A dispute of a finalized round, see `raise_dispute`.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(MaxAuthorities))]
+	#[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
+	pub struct DisputeInfo<
+		AccountId: Clone + PartialEq + Eq + Debug,
+		Balance: Clone + PartialEq + Eq + Debug,
+		MaxAuthorities: Get<u32>,
+	> {
+		/// The account which raised the dispute.
+		pub who: AccountId,
+		/// The bond reserved from `who` until the dispute is resolved.
+		pub bond: Balance,
+		/// The submissions the round was finalized with.
+		pub submissions: BoundedVec<(AccountId, u64), MaxAuthorities>,
+	}
+
+	/// The `DisputeInfo` of an instance of the pallet.
+	pub type DisputeInfoOf<T, I = ()> = DisputeInfo<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T, I>,
+		<T as Config<I>>::MaxAuthorities,
+	>;
+
+	/// A request of a value by an account, pending until an authority answers it with
+	/// `fulfill_request`, see `request_value`.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		Encode,
+		Decode,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(MaxQueryLen, MaxCallbackLen))]
+	#[codec(mel_bound(
+		AccountId: MaxEncodedLen,
+		Balance: MaxEncodedLen,
+		BlockNumber: MaxEncodedLen
+	))]
+	pub struct PendingRequest<
+		AccountId: Clone + PartialEq + Eq + Debug,
+		Balance: Clone + PartialEq + Eq + Debug,
+		BlockNumber: Clone + PartialEq + Eq + Debug,
+		MaxQueryLen: Get<u32>,
+		MaxCallbackLen: Get<u32>,
+	> {
+		/// The account which requested the value.
+		pub requester: AccountId,
+		/// What is requested, substituted for the placeholder of `Config::QueryEndpoint`.
+		pub query: BoundedVec<u8, MaxQueryLen>,
+		/// The fee escrowed from `requester`, paid to the authority which fulfills the request,
+		/// see `Pallet::escrow_account`.
+		pub fee: Balance,
+		/// The block the value was requested in, see `Config::RequestTtl`.
+		pub requested_at: BlockNumber,
+		/// The call dispatched with the answer once the request is fulfilled, see `WithAnswer`.
+		pub callback: Option<BoundedVec<u8, MaxCallbackLen>>,
+	}
+
+	/// The `PendingRequest` of an instance of the pallet.
+	pub type PendingRequestOf<T, I = ()> = PendingRequest<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T, I>,
+		BlockNumberFor<T>,
+		<T as Config<I>>::MaxQueryLen,
+		<T as Config<I>>::MaxCallbackLen,
+	>;
+
+	/// A failure of the offchain worker of an authority, recorded with `report_failure`.
+	#[derive(
+		Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub struct FailureReport<AccountId, Hash, BlockNumber> {
+		/// The authority which reported the failure.
+		pub who: AccountId,
+		/// The code of the failure, defined by the offchain worker of the authority.
+		pub code: u32,
+		/// The hash of the context of the failure, e.g. of the request and the response which
+		/// failed, kept off chain by the authority.
+		pub context_hash: Hash,
+		/// The block the failure was reported in.
+		pub reported_at: BlockNumber,
+	}
+
+	/// The `FailureReport` of an instance of the pallet.
+	pub type FailureReportOf<T> = FailureReport<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::Hash,
+		BlockNumberFor<T>,
+	>;
+
+	/// How a dispute is resolved, see `resolve_dispute`.
+	#[derive(
+		Clone,
+		Copy,
+		Encode,
+		Decode,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum DisputeOutcome {
+		/// The round was finalized with a wrong median, `value` is the right one.
+		Upheld { value: u64 },
+		/// The round was finalized correctly.
+		Rejected,
+	}
+
+	/// A value the offchain worker committed to and has yet to reveal, kept in the offchain
+	/// local storage of a node.
+	#[derive(Encode, Decode, RuntimeDebug)]
+	pub struct PendingReveal<BlockNumber> {
+		pub value: u64,
+		pub salt: [u8; 32],
+		/// The block the commitment was sent at.
+		pub sent_at: BlockNumber,
+	}
+
+	/// Why a pipeline run of the offchain worker failed, see `report_submission_failure`.
+	#[derive(
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		Encode,
+		Decode,
+		RuntimeDebug,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum FailureReason {
+		/// Fetching the price failed.
+		Fetch(http::OffchainError),
+		/// The transaction submitting the value could not be created or submitted.
+		Submit,
+	}
+
+	/// The kinds of transactions the offchain worker can submit a value with.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub enum TransactionType {
+		/// With `submit_data_signed`.
+		Signed,
+		/// With `submit_payload_unsigned_with_signed_payload`.
+		UnsignedWithSignedPayload,
+		/// With `submit_number_unsigned`.
+		Raw,
+	}
 
 	#[pallet::pallet]
-	pub struct Pallet<T>(_);
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config<I: 'static = ()>:
+		CreateSignedTransaction<Call<Self, I>> + frame_system::Config
+	{
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The calls of the runtime, dispatched as the callbacks of the requests, see
+		/// `request_value`.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo;
+		/// The identifier type for an offchain worker.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+		/// A configuration for base priority of unsigned transactions.
+		///
+		/// This is exposed so that it can be tuned for particular runtime, when
+		/// multiple pallets send unsigned transactions.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+		/// The URL the offchain worker fetches the price from.
+		type HttpEndpoint: Get<&'static str>;
+		/// The field of the JSON object returned by the endpoints the price is read from.
+		type PriceField: Get<&'static str>;
+		/// The sources the offchain worker fetches the price from, a tuple of `DataSource`s such
+		/// as `sources::HttpJson`. The worker submits the median of their values.
+		type Sources: DataSources<Self, I>;
+		/// Maximum number of recent prices kept on chain to compute the moving average over.
+		#[pallet::constant]
+		type MaxPrices: Get<u32>;
+		/// The clock the medians of the rounds are timestamped with for the time-weighted
+		/// average, e.g. `pallet_timestamp`.
+		type TimeProvider: UnixTime;
+		/// The window, in milliseconds, the time-weighted average of the medians is computed
+		/// over, see `twap`.
+		#[pallet::constant]
+		type TwapWindow: Get<u64>;
+		/// Maximum number of medians kept on chain to compute the time-weighted average over.
+		///
+		/// This should be more than the number of rounds finalized within `TwapWindow`, or the
+		/// average covers less than the window.
+		#[pallet::constant]
+		type MaxTwapObservations: Get<u32>;
+		/// For how long, in milliseconds, a worker holds the lock on the pipeline at most.
+		///
+		/// This should be longer than a pipeline run takes, including the HTTP timeout.
+		#[pallet::constant]
+		type LockTimeout: Get<u64>;
+		/// For how long, in milliseconds, a run of the offchain worker goes on before it leaves
+		/// its remaining stages to the next run, see `run_stages`. Zero for no limit.
+		///
+		/// A stage is never interrupted, so a run can last longer by the duration of a stage.
+		/// This should be shorter than `LockTimeout`.
+		#[pallet::constant]
+		type WorkerBudgetMs: Get<u64>;
+		/// Number of blocks the offchain worker waits after a submission before it runs its
+		/// pipeline again.
+		///
+		/// Unsigned transactions computed for blocks within this interval are rejected, so
+		/// every node running the worker submits at most once per interval. Overridden by
+		/// `OcwConfig`, if set.
+		#[pallet::constant]
+		type UnsignedInterval: Get<BlockNumberFor<Self>>;
+		/// For how many blocks the signed transactions of the offchain worker are valid, see
+		/// `tx_era`.
+		///
+		/// Rounded up to a power of two, and down to the largest power of two within
+		/// `frame_system::Config::BlockHashCount`.
+		#[pallet::constant]
+		type TxLongevity: Get<BlockNumberFor<Self>>;
+		/// Maximum number of tasks, and thus commitments, queued per block.
+		#[pallet::constant]
+		type MaxIndexedPerBlock: Get<u32>;
+		/// Number of blocks the commitments of indexed data are kept for, before `on_idle`
+		/// removes them.
+		#[pallet::constant]
+		type CommitmentRetention: Get<BlockNumberFor<Self>>;
+		/// Number of blocks the values in `ValuesByBlock` are kept for, before `on_idle` removes
+		/// them.
+		#[pallet::constant]
+		type HistoryDepth: Get<BlockNumberFor<Self>>;
+		/// Maximum number of blocks whose values are removed by a call to `prune_history`.
+		#[pallet::constant]
+		type MaxHistoryPrune: Get<u32>;
+		/// Maximum number of items in `PendingWork`.
+		#[pallet::constant]
+		type MaxPendingWork: Get<u32>;
+		/// Maximum number of endpoints in `OracleEndpoints`.
+		#[pallet::constant]
+		type MaxEndpoints: Get<u32>;
+		/// Maximum length of the URL of an endpoint in `OracleEndpoints`.
+		#[pallet::constant]
+		type MaxEndpointLen: Get<u32>;
+		/// Number of times the offchain worker retries a failed pipeline run, with exponential
+		/// backoff, before it gives up and reports it with `report_retries_exhausted`.
+		/// Overridden by `OcwConfig`, if set.
+		#[pallet::constant]
+		type MaxRetries: Get<u32>;
+		/// Maximum number of accounts in `Authorities`.
+		#[pallet::constant]
+		type MaxAuthorities: Get<u32>;
+		/// The currency the deposits for stored data are reserved in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Maximum length of a payload stored with `submit_data`.
+		#[pallet::constant]
+		type MaxDataLen: Get<u32>;
+		/// The deposit reserved for any payload stored with `submit_data`.
+		#[pallet::constant]
+		type DataDepositBase: Get<BalanceOf<Self, I>>;
+		/// The deposit reserved per byte of a payload stored with `submit_data`.
+		#[pallet::constant]
+		type DataDepositPerByte: Get<BalanceOf<Self, I>>;
+		/// The deposit reserved for each queued task, until the offchain worker confirms it
+		/// processed the tasks of the block with `confirm_tasks`.
+		#[pallet::constant]
+		type IndexingDeposit: Get<BalanceOf<Self, I>>;
+		/// Every how many blocks the offchain workers of the authorities send a heartbeat.
+		///
+		/// An authority which didn't send one within the last period is reported offline at the
+		/// start of the next one.
+		#[pallet::constant]
+		type HeartbeatPeriod: Get<BlockNumberFor<Self>>;
+		/// Maximum number of entries indexed with `write_batch_to_ocs` per block.
+		#[pallet::constant]
+		type MaxBatch: Get<u32>;
+		/// Maximum length of the label of an entry indexed with `write_batch_to_ocs`.
+		#[pallet::constant]
+		type MaxBatchLabelLen: Get<u32>;
+		/// Maximum length of a payload indexed with `index_payload`.
+		#[pallet::constant]
+		type MaxPayloadLen: Get<u32>;
+		/// Maximum size of the chunks a payload indexed with `index_payload` is split into in
+		/// the offchain database, see `blob`.
+		#[pallet::constant]
+		type ChunkSize: Get<u32>;
+		/// Maximum number of calls indexing data into the offchain database an account may
+		/// dispatch per block, enforced by the `CheckOcwRateLimit` signed extension.
+		#[pallet::constant]
+		type MaxCallsPerBlock: Get<u32>;
+		/// Number of blocks the entries indexed into the offchain database are kept for, before
+		/// the offchain worker removes them from its local storage.
+		///
+		/// This should be shorter than `CommitmentRetention`, as the worker finds the entries
+		/// of a block through its on-chain commitments.
+		#[pallet::constant]
+		type RetentionBlocks: Get<BlockNumberFor<Self>>;
+		/// The first block whose tasks were indexed under the keys of the current layout, see
+		/// `keys::KEY_VERSION`.
+		///
+		/// The tasks of the blocks before it are also looked up under their legacy key, and the
+		/// offchain worker moves them to the current one, see `migrate_legacy_keys`. Zero on a
+		/// chain which never indexed tasks under the legacy keys.
+		#[pallet::constant]
+		type LegacyKeysUntil: Get<BlockNumberFor<Self>>;
+		/// Number of blocks after a commitment before its value can be revealed.
+		#[pallet::constant]
+		type RevealDelay: Get<BlockNumberFor<Self>>;
+		/// Number of blocks after `RevealDelay` a value can still be revealed in. Commitments
+		/// which aren't revealed by then expire in `on_initialize`, and their deposit is slashed.
+		/// Zero lets them wait forever.
+		#[pallet::constant]
+		type RevealWindow: Get<BlockNumberFor<Self>>;
+		/// The deposit reserved with a commitment, slashed if the revealed value doesn't match.
+		#[pallet::constant]
+		type CommitDeposit: Get<BalanceOf<Self, I>>;
+		/// Number of blocks in a round. The values submitted by the authorities within a round
+		/// are aggregated into their median at the end of the round.
+		#[pallet::constant]
+		type RoundLength: Get<BlockNumberFor<Self>>;
+		/// The source of the block numbers the rounds and the keys of the indexed entries are
+		/// based on.
+		///
+		/// `frame_system::Pallet<Self>` for the numbers of the local blocks. On a parachain, the
+		/// numbers of the relay chain, which external systems usually track, with e.g.
+		/// `cumulus_pallet_parachain_system::RelaychainBlockNumberProvider<Self>`. The rounds,
+		/// their closing periods and leader turns, and the retention of the indexed entries are
+		/// then counted in relay chain blocks.
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+		/// Number of blocks at the end of a round during which it is `RoundPhase::Closing`.
+		#[pallet::constant]
+		type ClosingPeriod: Get<BlockNumberFor<Self>>;
+		/// Whether the rounds are finalized at their end only, or as soon as enough of the
+		/// authorities agree on a value.
+		#[pallet::constant]
+		type FinalizationMode: Get<FinalizationMode>;
+		/// The bond reserved from an account when it is added to the authorities, slashed if it
+		/// repeatedly submits values far from the median.
+		#[pallet::constant]
+		type AuthorityBond: Get<BalanceOf<Self, I>>;
+		/// The lowest bond of a dispute of a finalized round, see `raise_dispute`.
+		#[pallet::constant]
+		type MinDisputeBond: Get<BalanceOf<Self, I>>;
+		/// How far, relative to the median, the value of an authority may deviate before it
+		/// counts as a strike.
+		#[pallet::constant]
+		type MaxDeviation: Get<Perbill>;
+		/// How far, in basis points of the previous price, the finalized price of an asset must
+		/// move for `Event::SignificantChange`, unless overridden for the asset with
+		/// `set_change_threshold`.
+		#[pallet::constant]
+		type ChangeThresholdBps: Get<u32>;
+		/// Whether block authors may set the value with an inherent, within `MaxDeviation` of
+		/// the median of the latest finalized round, see `inherent`.
+		///
+		/// The inherent data holds a single value, so at most one instance of the pallet in a
+		/// runtime should accept it.
+		#[pallet::constant]
+		type AcceptInherentValues: Get<bool>;
+		/// After how many strikes in a row an authority is slashed and removed.
+		#[pallet::constant]
+		type MaxStrikes: Get<u32>;
+		/// For how long, in milliseconds, the offchain worker reuses an HTTP response instead of
+		/// fetching it again, typically about the duration of a round. Zero disables the cache.
+		#[pallet::constant]
+		type CacheTtl: Get<u64>;
+		/// How long, in milliseconds, the offchain worker waits for an HTTP response before
+		/// giving up on the request.
+		#[pallet::constant]
+		type HttpTimeoutMs: Get<u64>;
+		/// Maximum size, in bytes, of a payload of the offchain worker: of the body of an HTTP
+		/// response, which the worker doesn't read further, and of the data it fetched submitted
+		/// with `submit_attested_data`.
+		#[pallet::constant]
+		type MaxOcwPayload: Get<u32>;
+		/// Minimum time, in milliseconds, between two HTTP requests of the same endpoint by the
+		/// offchain worker of a node, so that a misconfigured runtime can't make the nodes hammer
+		/// an API, and get banned by it. Zero disables the limit.
+		#[pallet::constant]
+		type MinRequestIntervalMs: Get<u64>;
+		/// Maximum delay, in milliseconds, the offchain worker waits before fetching the price,
+		/// so that the workers of all the nodes don't query the sources at once, see `jitter_ms`.
+		#[pallet::constant]
+		type MaxJitterMs: Get<u64>;
+		/// How many authorities fetch the price in each round, see `is_fetcher`. Zero lets the
+		/// workers of all the nodes fetch it.
+		#[pallet::constant]
+		type FetchersPerRound: Get<u32>;
+		/// After how many blocks without a value the next authority takes over from the leader
+		/// of the round, see `leader`. Zero lets the workers of all the nodes submit.
+		#[pallet::constant]
+		type LeaderTimeout: Get<BlockNumberFor<Self>>;
+		/// The lowest value the offchain worker submits, see `check_value`.
+		#[pallet::constant]
+		type MinValue: Get<u64>;
+		/// The highest value the offchain worker submits, see `check_value`.
+		#[pallet::constant]
+		type MaxValue: Get<u64>;
+		/// How much the value the offchain worker submits may differ from the median of the
+		/// latest round, see `check_value`. Zero disables the check.
+		#[pallet::constant]
+		type MaxChangePerRound: Get<Perbill>;
+		/// The URL the offchain worker POSTs a signed report of every finalized round to, see
+		/// `send_report`. Nothing is reported for an empty URL.
+		type ReportEndpoint: Get<&'static str>;
+		/// Every how many rounds the submissions of a finalized round are anchored in
+		/// `Snapshots`, see `anchor_snapshot`. No snapshot is taken if this is zero.
+		#[pallet::constant]
+		type SnapshotPeriod: Get<RoundIndex>;
+		/// The URL the offchain worker POSTs the snapshots anchored in `Snapshots` to, see
+		/// `send_snapshot`. Nothing is archived for an empty URL.
+		type ArchiveEndpoint: Get<&'static str>;
+		/// The URL prefix the offchain worker fetches the subscribed topics from: the value of a
+		/// topic is the field named after it of the JSON object at this URL followed by the topic.
+		type TopicEndpoint: Get<&'static str>;
+		/// The URL of a paginated feed of records the offchain worker follows, see
+		/// `follow_feed`. Nothing is fetched for an empty URL.
+		///
+		/// The feed is fetched with a `since` query parameter, the id of the last record the
+		/// worker submitted, and answers with the records after it, e.g.
+		/// `{"records": [{"id": 13, "value": 1.5}]}`.
+		type FeedEndpoint: Get<&'static str>;
+		/// The URL of the IPFS HTTP gateway the offchain worker fetches the documents of
+		/// `DocumentRequests` from, see `ipfs`. Nothing is fetched for an empty URL.
+		type IpfsGateway: Get<&'static str>;
+		/// Maximum length of the CID of a document.
+		#[pallet::constant]
+		type MaxCidLen: Get<u32>;
+		/// The URL template the offchain worker fetches the answers of the requests from, see
+		/// `request_value`, with the query of a request substituted for its `{QUERY}`
+		/// placeholder. The answer is read from the `PriceField` of the JSON response. Nothing
+		/// is fetched for an empty URL.
+		type QueryEndpoint: Get<&'static str>;
+		/// Maximum length of the query of a request.
+		#[pallet::constant]
+		type MaxQueryLen: Get<u32>;
+		/// Maximum number of requests pending at once.
+		#[pallet::constant]
+		type MaxPendingRequests: Get<u32>;
+		/// The identifier of the account the fees of the requests are escrowed in, see
+		/// `escrow_account`. Each instance of the pallet needs its own.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// After how many blocks a request not fulfilled times out, and its fee is refunded,
+		/// see `RequestsByExpiry`.
+		#[pallet::constant]
+		type RequestTtl: Get<BlockNumberFor<Self>>;
+		/// Maximum number of requests which time out refunded in `on_initialize`, the others are
+		/// refunded in the next blocks, or in `on_idle` if the block has weight left.
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+		/// Maximum length of the callback of a request.
+		#[pallet::constant]
+		type MaxCallbackLen: Get<u32>;
+		/// Maximum weight of the callback of a request, charged to `fulfill_request` on top of
+		/// its own weight.
+		#[pallet::constant]
+		type MaxCallbackWeight: Get<Weight>;
+		/// Maximum number of the latest failures kept in `FailureReports`.
+		#[pallet::constant]
+		type MaxFailureReports: Get<u32>;
+		/// Minimum number of blocks between two failures reported by the same authority, see
+		/// `report_failure`.
+		#[pallet::constant]
+		type FailureReportInterval: Get<BlockNumberFor<Self>>;
+		/// Maximum length of a topic.
+		#[pallet::constant]
+		type MaxTopicLen: Get<u32>;
+		/// Maximum number of distinct topics subscribed to.
+		#[pallet::constant]
+		type MaxTopics: Get<u32>;
+		/// The deposit reserved from an account for each of its subscriptions.
+		#[pallet::constant]
+		type SubscriptionDeposit: Get<BalanceOf<Self, I>>;
+		/// Number of blocks between two updates of the subscribed topics by the offchain worker.
+		#[pallet::constant]
+		type TopicPeriod: Get<BlockNumberFor<Self>>;
+		/// The identifier of an asset whose price is fed by the offchain workers, see
+		/// `register_asset`.
+		type AssetId: Parameter + Member + Copy + MaxEncodedLen;
+		/// Maximum number of registered assets.
+		#[pallet::constant]
+		type MaxAssets: Get<u32>;
+		/// After how many blocks without a new price the price of an asset is stale.
+		/// `ValueStaleWarning` is emitted in the block it becomes stale, unless this is zero.
+		#[pallet::constant]
+		type StalenessThreshold: Get<BlockNumberFor<Self>>;
+		/// Called with the new price of each asset whose round finalized, or whose price was
+		/// forced with `force_set_value`.
+		type OnNewValue: OnNewValue<Self::AssetId, u64>;
+		/// The origin which may override the price of an asset with `force_set_value`.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The origin of the prices sent by the oracles of other chains with
+		/// `receive_remote_value`, typically a sibling parachain over XCM, see `remote`.
+		type RemoteOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The origin `execute_task` is dispatched with, typically root as dispatched by
+		/// `pallet_scheduler` for the periodic tasks scheduled by root.
+		type SchedulerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The origin which manages the authorities, the endpoints and the assets, and the
+		/// storage of the pallet, typically root or a collective.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The origin of the values submitted by the authorities, yielding their account,
+		/// typically `EnsureSigned`.
+		type FeederOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+		/// Creates the identifiers of the assets registered by the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: crate::BenchmarkHelper<Self::AssetId>;
+		/// Maximum number of values stored with `do_something` per block.
+		#[pallet::constant]
+		type MaxValuesPerBlock: Get<u32>;
 		/// Type representing the weight of this pallet
 		type WeightInfo: WeightInfo;
 	}
 
 	// The pallet's runtime storage items.
 	// https://docs.substrate.io/main-docs/build/runtime-storage/
+	/// The values stored with `do_something` in each block, in the order they were stored.
 	#[pallet::storage]
-	#[pallet::getter(fn something)]
+	#[pallet::getter(fn values_by_block)]
 	// Learn more about declaring storage items:
 	// https://docs.substrate.io/main-docs/build/runtime-storage/#declaring-storage-items
-	pub type Something<T> = StorageValue<_, u32>;
+	pub type ValuesByBlock<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<u64, T::MaxValuesPerBlock>,
+		ValueQuery,
+	>;
 
-	// Pallets use events to inform users when important changes are made.
-	// https://docs.substrate.io/main-docs/build/events-errors/
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// Event documentation should end with an array that provides descriptive names for event
-		/// parameters. [something, who]
-		SomethingStored { something: u32, who: T::AccountId },
-	}
+	/// The latest block values were stored in with `do_something`.
+	#[pallet::storage]
+	#[pallet::getter(fn latest_values_at)]
+	pub type LatestValuesAt<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BlockNumberFor<T>, OptionQuery>;
 
-	// Errors inform users that something went wrong.
-	#[pallet::error]
-	pub enum Error<T> {
-		/// Error names should be descriptive.
-		NoneValue,
-		/// Errors should have helpful documentation associated with them.
-		StorageOverflow,
-	}
+	/// For each block in `ValuesByBlock`, the previous block values were stored in, so that the
+	/// history can be walked back without going through the blocks without values.
+	#[pallet::storage]
+	pub type PreviousValuesAt<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, BlockNumberFor<T>, OptionQuery>;
 
-	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
-	// These functions materialize as "extrinsics", which are often compared to transactions.
-	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// An example dispatchable that takes a singles value as a parameter, writes the value to
-		/// storage and emits an event. This function must be dispatched by a signed extrinsic.
-		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::do_something())]
-		pub fn do_something(origin: OriginFor<T>, something: u32) -> DispatchResult {
-			// Check that the extrinsic was signed and get the signer.
-			// This function will return an error if the extrinsic is not signed.
-			// https://docs.substrate.io/main-docs/build/origins/
-			let who = ensure_signed(origin)?;
+	/// The latest value submitted by an offchain worker.
+	#[pallet::storage]
+	#[pallet::getter(fn latest_value)]
+	pub type LatestValue<T, I = ()> = StorageValue<_, u64>;
 
-			// Update storage.
-			<Something<T>>::put(something);
+	/// The id and the value of the latest record of the feed at `Config::FeedEndpoint`, see
+	/// `submit_record_unsigned`.
+	#[pallet::storage]
+	#[pallet::getter(fn latest_record)]
+	pub type LatestRecord<T, I = ()> = StorageValue<_, (u64, u64)>;
 
-			// Emit an event.
-			Self::deposit_event(Event::SomethingStored { something, who });
-			// Return a successful DispatchResultWithPostInfo
-			Ok(())
-		}
+	/// The documents to anchor, by CID, with the block they were requested in, see
+	/// `request_document`.
+	#[pallet::storage]
+	#[pallet::getter(fn document_requests)]
+	pub type DocumentRequests<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, CidOf<T, I>, BlockNumberFor<T>, OptionQuery>;
 
-		/// An example dispatchable that may throw a custom error.
-		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::cause_error())]
-		pub fn cause_error(origin: OriginFor<T>) -> DispatchResult {
-			let _who = ensure_signed(origin)?;
+	/// The hash of the content of the anchored documents, by CID, see `anchor_document`.
+	#[pallet::storage]
+	#[pallet::getter(fn documents)]
+	pub type Documents<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, CidOf<T, I>, T::Hash, OptionQuery>;
 
-			// Read a value from storage.
-			match <Something<T>>::get() {
-				// Return an error if the value has not been set.
-				None => return Err(Error::<T>::NoneValue.into()),
-				Some(old) => {
-					// Increment the value read from storage; will error in the event of overflow.
-					let new = old.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
-					// Update the value in storage with the incremented result.
-					<Something<T>>::put(new);
-					Ok(())
-				},
-			}
+	/// The id of the next request, see `request_value`.
+	#[pallet::storage]
+	#[pallet::getter(fn next_request_id)]
+	pub type NextRequestId<T: Config<I>, I: 'static = ()> = StorageValue<_, RequestId, ValueQuery>;
+
+	/// The requests not fulfilled yet, by id, see `request_value`.
+	#[pallet::storage]
+	#[pallet::getter(fn requests)]
+	pub type Requests<T: Config<I>, I: 'static = ()> =
+		CountedStorageMap<_, Twox64Concat, RequestId, PendingRequestOf<T, I>, OptionQuery>;
+
+	/// The requests which time out at each block, see `Config::RequestTtl`.
+	#[pallet::storage]
+	#[pallet::getter(fn requests_by_expiry)]
+	pub type RequestsByExpiry<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<RequestId, T::MaxPendingRequests>,
+		ValueQuery,
+	>;
+
+	/// The latest failures reported by the authorities, oldest first, see `report_failure`.
+	#[pallet::storage]
+	#[pallet::getter(fn failure_reports)]
+	pub type FailureReports<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<FailureReportOf<T>, T::MaxFailureReports>, ValueQuery>;
+
+	/// The block each authority last reported a failure in, see `Config::FailureReportInterval`.
+	/// The entry of an authority is removed when it leaves the set.
+	#[pallet::storage]
+	pub type LastFailureReports<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// The external signers whose attestations are accepted, see `set_attester`.
+	#[pallet::storage]
+	#[pallet::getter(fn attesters)]
+	pub type Attesters<T, I = ()> = StorageMap<_, Blake2_128Concat, AttesterKey, (), OptionQuery>;
+
+	/// Whether a value was set with an inherent in the current block, see `set_inherent_value`.
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	pub type InherentIncluded<T: Config<I>, I: 'static = ()> = StorageValue<_, bool, ValueQuery>;
+
+	/// Whether the submissions and the offchain workers are stopped, see `pause`.
+	#[pallet::storage]
+	#[pallet::getter(fn is_paused)]
+	pub type Paused<T, I = ()> = StorageValue<_, bool, ValueQuery>;
+
+	/// The latest data attested by each external signer, with the block it was submitted in,
+	/// see `submit_attested_data`.
+	#[pallet::storage]
+	#[pallet::getter(fn attested_data)]
+	pub type AttestedData<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AttesterKey,
+		(BoundedVec<u8, T::MaxDataLen>, BlockNumberFor<T>),
+		OptionQuery,
+	>;
+
+	/// The block the latest value was submitted in.
+	#[pallet::storage]
+	#[pallet::getter(fn latest_value_updated_at)]
+	pub type LatestValueUpdatedAt<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// A ring buffer of the most recent prices submitted by offchain workers, oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn price_history)]
+	pub type PriceHistory<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<u64, T::MaxPrices>, ValueQuery>;
+
+	/// The assets registered with `register_asset`, whose prices the offchain workers fetch.
+	#[pallet::storage]
+	#[pallet::getter(fn assets)]
+	pub type Assets<T: Config<I>, I: 'static = ()> =
+		CountedStorageMap<_, Blake2_128Concat, T::AssetId, AssetInfoOf<T, I>, OptionQuery>;
+
+	/// The URL template the prices of the assets registered without an endpoint are fetched
+	/// from, set with `set_endpoint_template`, e.g. `https://api.example.com/price?symbol={ASSET}`.
+	#[pallet::storage]
+	#[pallet::getter(fn endpoint_template)]
+	pub type EndpointTemplate<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<u8, T::MaxEndpointLen>, ValueQuery>;
+
+	/// The latest finalized price of each registered asset.
+	#[pallet::storage]
+	#[pallet::getter(fn prices)]
+	pub type Prices<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, PriceInfo<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The time in milliseconds of `TimeProvider` the price of each asset in `Prices` was set at.
+	#[pallet::storage]
+	#[pallet::getter(fn price_timestamps)]
+	pub type PriceTimestamps<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, u64, OptionQuery>;
+
+	/// The thresholds in basis points of the assets which override `Config::ChangeThresholdBps`,
+	/// see `set_change_threshold`.
+	#[pallet::storage]
+	#[pallet::getter(fn change_thresholds)]
+	pub type ChangeThresholds<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, u32, OptionQuery>;
+
+	/// The price of each asset submitted by each authority in the current round, until the round
+	/// is finalized.
+	#[pallet::storage]
+	pub type AssetSubmissions<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		u64,
+		OptionQuery,
+	>;
+
+	/// The block from which on the offchain worker runs its pipeline again, and unsigned
+	/// transactions are accepted again.
+	///
+	/// This is set to the block of the latest submission plus the fetch interval, see
+	/// `OcwConfig`.
+	#[pallet::storage]
+	#[pallet::getter(fn next_unsigned_at)]
+	pub type NextUnsignedAt<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// The tasks scheduled with `schedule_task` or `execute_task` for the offchain worker of each
+	/// block, in order. Removed at the start of the next block.
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_tasks)]
+	pub type ScheduledTasks<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<Task, T::MaxIndexedPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Hashes of the tasks indexed into the offchain database, per block and in queue order.
+	///
+	/// The offchain worker only runs the tasks matching their commitment, so light clients can
+	/// verify that the worker ran what was committed. A task can be queued once per block.
+	#[pallet::storage]
+	#[pallet::getter(fn index_commitments)]
+	pub type IndexCommitments<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<T::Hash, T::MaxIndexedPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The kinds of the tasks indexed into the offchain database, per block and in queue order.
+	///
+	/// Along with the block number and the queue index, the kind is all the offchain database key
+	/// of a task is derived from, so that tooling can enumerate the keys of a range of blocks, see
+	/// `indexed_keys`. Pruned along with `IndexCommitments`.
+	#[pallet::storage]
+	#[pallet::getter(fn indexed_keys_of)]
+	pub type IndexedKeys<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<KeyKind, T::MaxIndexedPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Hashes of the entries indexed into the offchain database with `write_batch_to_ocs`, per
+	/// block and in order. Pruned along with `IndexCommitments`.
+	#[pallet::storage]
+	#[pallet::getter(fn batch_commitments)]
+	pub type BatchCommitments<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<T::Hash, T::MaxBatch>,
+		ValueQuery,
+	>;
+
+	/// The number of calls indexing data into the offchain database each account dispatched,
+	/// along with the block it last dispatched one in, see `ocw_calls`.
+	#[pallet::storage]
+	pub type OcwCalls<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, u32), ValueQuery>;
+
+	/// The Merkle roots of the payloads indexed into the offchain database with
+	/// `index_payload`, along with the block they were indexed in and their number of chunks.
+	#[pallet::storage]
+	#[pallet::getter(fn payload_roots)]
+	pub type PayloadRoots<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, T::Hash, (BlockNumberFor<T>, u32), OptionQuery>;
+
+	/// The URLs the offchain worker fetches the price from, set with `set_oracle_endpoints`.
+	///
+	/// The worker submits the median of the prices of all the endpoints that answered. While
+	/// this is empty it fetches the price from `Config::HttpEndpoint` alone.
+	#[pallet::storage]
+	#[pallet::getter(fn oracle_endpoints)]
+	pub type OracleEndpoints<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<BoundedVec<u8, T::MaxEndpointLen>, T::MaxEndpoints>, ValueQuery>;
+
+	/// The behavior of the offchain worker set with `set_ocw_config`, which overrides
+	/// `Pallet::runtime_ocw_config`.
+	#[pallet::storage]
+	#[pallet::getter(fn ocw_config)]
+	pub type OcwConfig<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, OcwConfigOf<T, I>, OptionQuery>;
+
+	/// The oldest block whose values may still be in `ValuesByBlock`.
+	#[pallet::storage]
+	pub type HistoryCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// The work deferred to `on_idle`, oldest first. An item `on_idle` only processed part of
+	/// stays at the front of the queue until the following blocks complete it.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_work)]
+	pub type PendingWork<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<WorkItem<BlockNumberFor<T>>, T::MaxPendingWork>, ValueQuery>;
+
+	/// The oldest block whose commitments may still be in `IndexCommitments`.
+	#[pallet::storage]
+	pub type CommitmentsCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// The accounts of the offchain worker keys allowed to submit data, managed by root.
+	///
+	/// While this is empty, any account can submit data. Once it is set, only its members can,
+	/// and raw unsigned submissions, which carry no key, are rejected.
+	#[pallet::storage]
+	#[pallet::getter(fn authorities)]
+	pub type Authorities<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxAuthorities>, ValueQuery>;
+
+	/// The payload stored by each account with `submit_data`.
+	#[pallet::storage]
+	#[pallet::getter(fn submitted_data)]
+	pub type SubmittedData<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, DataEntryOf<T, I>, OptionQuery>;
+
+	/// The deposits reserved for the tasks queued in a block, in queue order, until the
+	/// offchain worker confirms it processed them.
+	#[pallet::storage]
+	#[pallet::getter(fn index_deposits)]
+	pub type IndexDeposits<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(T::AccountId, BalanceOf<T, I>), T::MaxIndexedPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The deposits reserved from each account, but the bonds of the authorities, see `Bonds`.
+	#[pallet::storage]
+	#[pallet::getter(fn deposits)]
+	pub type Deposits<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, DepositInfo<BalanceOf<T, I>>, ValueQuery>;
+
+	/// The block of the latest heartbeat of each authority.
+	#[pallet::storage]
+	#[pallet::getter(fn last_seen)]
+	pub type LastSeen<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// The current round, advanced by `on_initialize` every `Config::RoundLength` blocks.
+	#[pallet::storage]
+	#[pallet::getter(fn current_round)]
+	pub type CurrentRound<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, RoundInfo<BlockNumberFor<T>>, ValueQuery>;
+
+	/// The value submitted by each authority in a round, until the round is finalized.
+	#[pallet::storage]
+	pub type Submissions<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		RoundIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		u64,
+		OptionQuery,
+	>;
+
+	/// The latest finalized round and the median of its submissions.
+	#[pallet::storage]
+	#[pallet::getter(fn latest_round)]
+	pub type LatestRound<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (RoundIndex, u64), OptionQuery>;
+
+	/// The submissions the latest finalized round was finalized with, kept for its disputes.
+	#[pallet::storage]
+	#[pallet::getter(fn latest_submissions)]
+	pub type LatestSubmissions<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<(T::AccountId, u64), T::MaxAuthorities>, ValueQuery>;
+
+	/// The pending dispute of each disputed round, see `raise_dispute`.
+	#[pallet::storage]
+	#[pallet::getter(fn disputes)]
+	pub type Disputes<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, RoundIndex, DisputeInfoOf<T, I>, OptionQuery>;
+
+	/// The hash of the snapshot of the submissions of every `Config::SnapshotPeriod`-th round,
+	/// see `anchor_snapshot`.
+	#[pallet::storage]
+	#[pallet::getter(fn snapshots)]
+	pub type Snapshots<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, RoundIndex, T::Hash, OptionQuery>;
+
+	/// The medians of the recent rounds, oldest first, along with the time in milliseconds of
+	/// `TimeProvider` they were finalized at, see `twap`.
+	#[pallet::storage]
+	#[pallet::getter(fn twap_observations)]
+	pub type TwapObservations<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<(u64, u64), T::MaxTwapObservations>, ValueQuery>;
+
+	/// The bond reserved from each authority, see `Config::AuthorityBond`.
+	#[pallet::storage]
+	#[pallet::getter(fn bonds)]
+	pub type Bonds<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T, I>, ValueQuery>;
+
+	/// The reputation of each authority which submitted values in finalized rounds.
+	#[pallet::storage]
+	#[pallet::getter(fn reputation)]
+	pub type Reputation<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ReputationInfo, ValueQuery>;
+
+	/// The commitment of each authority to a value it has yet to reveal.
+	#[pallet::storage]
+	#[pallet::getter(fn commitments)]
+	pub type Commitments<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		ValueCommitment<T::Hash, BlockNumberFor<T>, BalanceOf<T, I>>,
+		OptionQuery,
+	>;
+
+	/// The authorities whose commitment expires at each block, see `Config::RevealWindow`.
+	#[pallet::storage]
+	pub type CommitmentExpiries<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<T::AccountId, T::MaxAuthorities>,
+		ValueQuery,
+	>;
+
+	/// The deposit reserved for each subscription of an account to a topic.
+	#[pallet::storage]
+	#[pallet::getter(fn subscriptions)]
+	pub type Subscriptions<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Topic<T, I>,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T, I>,
+		OptionQuery,
+	>;
+
+	/// The number of subscribers of each topic subscribed to, whose values the offchain worker
+	/// fetches.
+	#[pallet::storage]
+	pub type Topics<T: Config<I>, I: 'static = ()> =
+		CountedStorageMap<_, Blake2_128Concat, Topic<T, I>, u32, ValueQuery>;
+
+	/// The initial state of the oracle, so that a chain can start with its offchain workers
+	/// running instead of bootstrapping them with root extrinsics.
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+		/// The initial `Authorities`, their `Config::AuthorityBond` is reserved as if they were
+		/// added with `add_authority`.
+		pub authorities: Vec<T::AccountId>,
+		/// The initial `OracleEndpoints`.
+		pub endpoints: Vec<Vec<u8>>,
+		/// The index of the round the chain starts in.
+		pub round: RoundIndex,
+		#[serde(skip)]
+		pub phantom: PhantomData<I>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config<I>, I: 'static> BuildGenesisConfig for GenesisConfig<T, I> {
+		fn build(&self) {
+			let authorities =
+				BoundedVec::<_, T::MaxAuthorities>::try_from(self.authorities.clone())
+					.expect("more than `MaxAuthorities` authorities in the genesis config");
+			for (i, who) in authorities.iter().enumerate() {
+				assert!(
+					!authorities[..i].contains(who),
+					"duplicate authority in the genesis config"
+				);
+				let bond = T::AuthorityBond::get();
+				T::Currency::reserve(who, bond)
+					.expect("authority in the genesis config can't afford its bond");
+				<Bonds<T, I>>::insert(who, bond);
+			}
+			<Authorities<T, I>>::put(authorities);
+
+			let endpoints = self
+				.endpoints
+				.iter()
+				.map(|url| {
+					assert!(
+						sp_std::str::from_utf8(url).is_ok(),
+						"invalid endpoint in the genesis config"
+					);
+					BoundedVec::try_from(url.clone())
+						.expect("endpoint longer than `MaxEndpointLen` in the genesis config")
+				})
+				.collect::<Vec<_>>();
+			let endpoints = BoundedVec::<_, T::MaxEndpoints>::try_from(endpoints)
+				.expect("more than `MaxEndpoints` endpoints in the genesis config");
+			<OracleEndpoints<T, I>>::put(endpoints);
+
+			<CurrentRound<T, I>>::put(RoundInfo {
+				index: self.round,
+				started_at: Zero::zero(),
+				phase: RoundPhase::Open,
+				seed: Pallet::<T, I>::round_seed(self.round),
+			});
+		}
+	}
+
+	// Pallets use events to inform users when important changes are made.
+	// https://docs.substrate.io/main-docs/build/events-errors/
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// Event documentation should end with an array that provides descriptive names for event
+		/// parameters. [something, who]
+		SomethingStored { something: u32, who: T::AccountId },
+		/// An offchain worker submitted a new value. [value, who]
+		DataSubmitted { value: u64, who: T::AccountId },
+		/// An offchain worker submitted a new value with an unsigned transaction.
+		/// [value, block_number]
+		DataSubmittedUnsigned { value: u64, block_number: BlockNumberFor<T> },
+		/// The endpoints of the oracle were replaced. [count]
+		OracleEndpointsSet { count: u32 },
+		/// The offchain worker of `who` gave up after `attempts` failed pipeline runs.
+		/// [who, attempts]
+		RetriesExhausted { who: T::AccountId, attempts: u32 },
+		/// A task was indexed into the offchain database, under the key of hash `key_hash`.
+		/// [block, key_hash]
+		OffchainDataIndexed { block: BlockNumberFor<T>, key_hash: T::Hash },
+		/// A pipeline run of the offchain worker of `who` failed. [who, reason]
+		OffchainSubmissionFailed { who: T::AccountId, reason: FailureReason },
+		/// An account was added to the authorities. [who]
+		AuthorityAdded { who: T::AccountId },
+		/// An account was removed from the authorities. [who]
+		AuthorityRemoved { who: T::AccountId },
+		/// A payload of `len` bytes was stored, with `deposit` reserved for it.
+		/// [who, len, deposit]
+		DataStored { who: T::AccountId, len: u32, deposit: BalanceOf<T, I> },
+		/// A payload was removed and its deposit unreserved. [who, deposit]
+		DataCleared { who: T::AccountId, deposit: BalanceOf<T, I> },
+		/// The offchain worker of `who` processed the `count` tasks queued in `block_number`,
+		/// and their deposits were unreserved. [who, block_number, count]
+		TasksConfirmed { who: T::AccountId, block_number: BlockNumberFor<T>, count: u32 },
+		/// The offchain worker of an authority sent a heartbeat. [who, block_number]
+		HeartbeatReceived { who: T::AccountId, block_number: BlockNumberFor<T> },
+		/// An authority sent no heartbeat within the last `HeartbeatPeriod` blocks. [who]
+		OffchainWorkerOffline { who: T::AccountId },
+		/// `count` entries were indexed into the offchain database in one batch.
+		/// [who, block, count]
+		BatchIndexed { who: T::AccountId, block: BlockNumberFor<T>, count: u32 },
+		/// The `count` entries indexed in `block_number` were removed from the offchain
+		/// database. [block_number, count]
+		OffchainEntriesCleared { block_number: BlockNumberFor<T>, count: u32 },
+		/// An authority committed to a value with `hash`. [who, hash]
+		ValueCommitted { who: T::AccountId, hash: T::Hash },
+		/// An authority revealed the value it committed to. [who, value]
+		ValueRevealed { who: T::AccountId, value: u64 },
+		/// An authority revealed a value which doesn't match its commitment, and its deposit was
+		/// slashed. [who, slashed]
+		RevealMismatched { who: T::AccountId, slashed: BalanceOf<T, I> },
+		/// An authority didn't reveal its value within `RevealWindow`, its commitment was
+		/// removed and its deposit slashed. [who, slashed]
+		CommitmentExpired { who: T::AccountId, slashed: BalanceOf<T, I> },
+		/// The leader of `round` didn't submit a value during its turn, the next authority
+		/// leads the round. [round, who]
+		LeaderTimedOut { round: RoundIndex, who: T::AccountId },
+		/// `agreeing` of the values submitted in a round are within the tolerance of
+		/// `FinalizationMode::Threshold`, the round is finalized before its end. [round,
+		/// agreeing]
+		RoundClosedEarly { round: RoundIndex, agreeing: u32 },
+		/// A round ended with `num_submissions` values submitted by the authorities, whose
+		/// median is `median`. [round, median, num_submissions]
+		RoundFinalized { round: RoundIndex, median: u64, num_submissions: u32 },
+		/// The value of an authority deviated beyond `MaxDeviation` from the median of `round`.
+		/// [who, round, deviation]
+		SubmissionDeviated { who: T::AccountId, round: RoundIndex, deviation: Perbill },
+		/// An authority deviated `MaxStrikes` rounds in a row, `amount` of its bond was
+		/// slashed and it was removed from the authorities. [who, amount]
+		AuthoritySlashed { who: T::AccountId, amount: BalanceOf<T, I> },
+		/// An account subscribed to a topic. [who, topic]
+		Subscribed { who: T::AccountId, topic: Topic<T, I> },
+		/// An account unsubscribed from a topic. [who, topic]
+		Unsubscribed { who: T::AccountId, topic: Topic<T, I> },
+		/// The offchain worker of an authority fetched a new value of a topic. [topic, value]
+		TopicUpdated { topic: Topic<T, I>, value: u64 },
+		/// The configuration of the offchain worker was set, or reset to the defaults of the
+		/// runtime for `None`. [config]
+		OcwConfigSet { config: Option<OcwConfigOf<T, I>> },
+		/// An asset was registered, its price is fetched from `endpoint`. [asset, endpoint]
+		AssetRegistered { asset: T::AssetId, endpoint: BoundedVec<u8, T::MaxEndpointLen> },
+		/// An asset was deregistered, along with its price. [asset]
+		AssetDeregistered { asset: T::AssetId },
+		/// An authority submitted the prices of `count` assets. [who, count]
+		AssetPricesSubmitted { who: T::AccountId, count: u32 },
+		/// The round of an asset ended with `num_submissions` prices submitted by the authorities,
+		/// whose median is `median`. [asset, round, median, num_submissions]
+		AssetRoundFinalized {
+			asset: T::AssetId,
+			round: RoundIndex,
+			median: u64,
+			num_submissions: u32,
+		},
+		/// A payload of `chunks` chunks was indexed into the offchain database, with `root` as
+		/// the Merkle root of its chunks. [who, root, chunks]
+		PayloadIndexed { who: T::AccountId, root: T::Hash, chunks: u32 },
+		/// The chunk at `index` was proven to be part of the payload of `root`. [root, index]
+		ChunkVerified { root: T::Hash, index: u32 },
+		/// The URL template of the assets registered without an endpoint was set. [template]
+		EndpointTemplateSet { template: BoundedVec<u8, T::MaxEndpointLen> },
+		/// The values stored before block `up_to` were removed. [up_to]
+		HistoryPruned { up_to: BlockNumberFor<T> },
+		/// The price of an asset was set by `ForceOrigin` rather than by the authorities.
+		/// [asset, value]
+		ValueForced { asset: T::AssetId, value: u64 },
+		/// A record of the feed at `FeedEndpoint` was submitted. [id, value]
+		RecordSubmitted { id: u64, value: u64 },
+		/// The anchoring of the document of a CID was requested. [cid]
+		DocumentRequested { cid: CidOf<T, I> },
+		/// The content of the document of a CID was verified and anchored. [cid, hash, who]
+		DocumentAnchored { cid: CidOf<T, I>, hash: T::Hash, who: T::AccountId },
+		/// The attestations of an external signer are accepted, or no longer. [signer, trusted]
+		AttesterSet { signer: AttesterKey, trusted: bool },
+		/// Data attested by an external signer was submitted. [signer, who, len]
+		DataAttested { signer: AttesterKey, who: T::AccountId, len: u32 },
+		/// The submissions and the offchain workers were stopped.
+		Paused,
+		/// The submissions and the offchain workers were resumed.
+		Unpaused,
+		/// The price of an asset was received from the oracle of another chain. [asset, value]
+		RemoteValueReceived { asset: T::AssetId, value: u64 },
+		/// The price of an asset wasn't updated for `StalenessThreshold` blocks.
+		/// [asset, updated_at]
+		ValueStaleWarning { asset: T::AssetId, updated_at: BlockNumberFor<T> },
+		/// A task was scheduled for the offchain worker of a later block. [at, task]
+		TaskScheduled { at: BlockNumberFor<T>, task: Task },
+		/// The deposits of tasks whose commitments were pruned were unreserved. [who, amount]
+		DepositReclaimed { who: T::AccountId, amount: BalanceOf<T, I> },
+		/// The snapshot of the submissions of a round was anchored. [round, hash]
+		SnapshotAnchored { round: RoundIndex, hash: T::Hash },
+		/// A finalized round was disputed. [who, round, bond]
+		DisputeRaised { who: T::AccountId, round: RoundIndex, bond: BalanceOf<T, I> },
+		/// The dispute of a round was rejected and its bond slashed. [round, slashed]
+		DisputeRejected { round: RoundIndex, slashed: BalanceOf<T, I> },
+		/// The dispute of a round was upheld, `value` is the right median. [round, value]
+		RoundInvalidated { round: RoundIndex, value: u64 },
+		/// The author of the block set the value with an inherent. [value]
+		InherentValueSet { value: u64 },
+		/// A value was requested, and `fee` escrowed for its answer. [id, who, fee]
+		ValueRequested { id: RequestId, who: T::AccountId, fee: BalanceOf<T, I> },
+		/// A request was answered by an authority. [id, answer]
+		RequestFulfilled { id: RequestId, answer: u64 },
+		/// A request timed out and its fee was refunded. [id, who, fee]
+		RequestRefunded { id: RequestId, who: T::AccountId, fee: BalanceOf<T, I> },
+		/// A request timed out and its fee was refunded by the pallet, see `RequestsByExpiry`.
+		/// [id, who, fee]
+		RequestExpired { id: RequestId, who: T::AccountId, fee: BalanceOf<T, I> },
+		/// The callback of a fulfilled request was dispatched. [id]
+		CallbackDispatched { id: RequestId },
+		/// The callback of a fulfilled request failed, the request stays fulfilled. [id, error]
+		CallbackFailed { id: RequestId, error: DispatchError },
+		/// The finalized price of an asset moved from `old` to `new`, by `delta_bps` basis points
+		/// of `old`, more than its threshold, see `change_threshold`. [asset, old, new, delta_bps]
+		SignificantChange { asset: T::AssetId, old: u64, new: u64, delta_bps: u32 },
+		/// The threshold of an asset was set, or reset to `Config::ChangeThresholdBps` for
+		/// `None`. [asset, threshold]
+		ChangeThresholdSet { asset: T::AssetId, threshold: Option<u32> },
+		/// The offchain worker of an authority failed with `code`, in the context with the hash
+		/// `context_hash`. [who, code, context_hash]
+		FailureReported { who: T::AccountId, code: u32, context_hash: T::Hash },
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// Error names should be descriptive.
+		NoneValue,
+		/// Errors should have helpful documentation associated with them.
+		StorageOverflow,
+		/// `MaxValuesPerBlock` values were already stored in this block.
+		TooManyValues,
+		/// More than `MaxIndexedPerBlock` tasks were queued in this block.
+		TooManyIndexedEntries,
+		/// The task is already queued in this block.
+		DuplicateTask,
+		/// The URL of an endpoint is not valid UTF-8.
+		InvalidEndpoint,
+		/// The account is not one of the authorities.
+		NotAuthority,
+		/// The account is already one of the authorities.
+		AlreadyAuthority,
+		/// There are already `MaxAuthorities` authorities.
+		TooManyAuthorities,
+		/// The account has no payload stored.
+		NoData,
+		/// No deposits of tasks queued in the block are left to refund.
+		NothingToConfirm,
+		/// The tasks of the current block can't be processed yet.
+		TooEarly,
+		/// More than `MaxBatch` entries were indexed in this block.
+		TooManyBatchEntries,
+		/// No entries are indexed in the block.
+		NothingToClear,
+		/// The account already committed to a value it didn't reveal yet.
+		AlreadyCommitted,
+		/// The account has no commitment to reveal.
+		NoCommitment,
+		/// The value can't be revealed before `RevealDelay` blocks passed since the commitment.
+		RevealTooEarly,
+		/// The topic is not valid UTF-8.
+		InvalidTopic,
+		/// The account is already subscribed to the topic.
+		AlreadySubscribed,
+		/// The account is not subscribed to the topic.
+		NotSubscribed,
+		/// `MaxTopics` topics are already subscribed to.
+		TooManyTopics,
+		/// Nobody is subscribed to the topic.
+		UnknownTopic,
+		/// The asset is already registered.
+		AssetAlreadyRegistered,
+		/// The asset is not registered.
+		UnknownAsset,
+		/// `MaxAssets` assets are already registered.
+		TooManyAssets,
+		/// The JSON field of an asset is not valid UTF-8.
+		InvalidField,
+		/// The current round is finalized, no more prices are accepted.
+		RoundAlreadyFinalized,
+		/// The payload is empty.
+		EmptyPayload,
+		/// A payload with the same chunks is already indexed.
+		PayloadAlreadyIndexed,
+		/// The proof doesn't match the chunk or the root of any indexed payload.
+		InvalidProof,
+		/// The values of the current block and of the blocks after it can't be pruned.
+		PruneTooFar,
+		/// `MaxPendingWork` items are already deferred to `on_idle`.
+		TooMuchPendingWork,
+		/// The record doesn't follow the latest record of the feed.
+		OutOfOrderRecord,
+		/// The CID is not a supported CID, see `ipfs`.
+		InvalidCid,
+		/// The document is already anchored, or its anchoring already requested.
+		DocumentAlreadyRequested,
+		/// The anchoring of the document was not requested.
+		UnknownDocument,
+		/// The signer of the attestation is not in `Attesters`.
+		UntrustedAttester,
+		/// The attestation is not a valid signature of the data by its signer.
+		BadAttestation,
+		/// The pallet is paused, no values are accepted.
+		FeedPaused,
+		/// The asset has no price, or it is older than the consumer accepts.
+		StalePrice,
+		/// The block is not after the current one.
+		BlockInThePast,
+		/// The account has no deposits of pruned tasks to reclaim.
+		NothingToReclaim,
+		/// The bond is below `Config::MinDisputeBond`.
+		DisputeBondTooLow,
+		/// Only the latest finalized round can be disputed.
+		NotDisputable,
+		/// The round is already disputed.
+		AlreadyDisputed,
+		/// The round is not disputed.
+		NoDispute,
+		/// A value was already set with an inherent in this block.
+		InherentValueAlreadySet,
+		/// The value set with an inherent failed its check, see `check_inherent_value`.
+		InherentValueRejected,
+		/// The payload is larger than `Config::MaxOcwPayload`.
+		PayloadTooLarge,
+		/// There are already `Config::MaxPendingRequests` pending requests.
+		TooManyRequests,
+		/// No request is pending with the id.
+		UnknownRequest,
+		/// The fee of a request is below the existential deposit.
+		FeeTooLow,
+		/// The request timed out, see `Config::RequestTtl`.
+		RequestExpired,
+		/// The request didn't time out yet, see `Config::RequestTtl`.
+		RequestNotExpired,
+		/// The callback of a request decodes to no call, see `WithAnswer`.
+		InvalidCallback,
+		/// The callback of a request is heavier than `Config::MaxCallbackWeight`.
+		CallbackTooHeavy,
+		/// The authority reported a failure less than `Config::FailureReportInterval` blocks ago.
+		FailureReportTooSoon,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		/// Report the authorities which sent no heartbeat in the last period, at the start of
+		/// every `T::HeartbeatPeriod` blocks.
+		///
+		/// Then advance the current round, finalize it if enough of the authorities agree on a
+		/// value, see `FinalizationMode::Threshold`, expire the commitments whose reveal window
+		/// passed, time out the leader of the round if it didn't submit during its turn, warn
+		/// about the prices which became stale, refund the requests which timed out, remove the
+		/// tasks scheduled for the previous block, and account for the weight of `on_finalize`.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let block = Self::current_block();
+			let mut weight = Self::report_offline(now);
+			<ScheduledTasks<T, I>>::remove(now.saturating_sub(One::one()));
+			weight = weight.saturating_add(T::DbWeight::get().writes(1));
+			weight = weight.saturating_add(Self::advance_round(block));
+			weight = weight.saturating_add(Self::close_round_early());
+			weight = weight.saturating_add(Self::expire_commitments(now));
+			weight = weight.saturating_add(Self::time_out_leader(block));
+			weight = weight.saturating_add(Self::warn_stale_prices(now));
+			let expired = Self::expire_requests(now, now, T::MaxExpiriesPerBlock::get());
+			weight = weight.saturating_add(T::WeightInfo::expire_requests(expired));
+			// `ends_round` reads the current round.
+			weight = weight.saturating_add(T::DbWeight::get().reads(1));
+			if Self::ends_round(block) {
+				weight = weight.saturating_add(Self::finalize_round_weight());
+			}
+			weight
+		}
+
+		/// Reset `InherentIncluded`, then finalize the current round if it ends with this block.
+		fn on_finalize(_now: BlockNumberFor<T>) {
+			<InherentIncluded<T, I>>::kill();
+			if Self::ends_round(Self::current_block()) {
+				Self::finalize_round();
+			}
+		}
+
+		/// Process the `PendingWork`, refund the requests which timed out but were left over by
+		/// `on_initialize`, then remove the commitments which fell out of
+		/// `T::CommitmentRetention` and the values which fell out of `T::HistoryDepth`, as far
+		/// as `remaining_weight` allows.
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut consumed = Self::process_pending_work(remaining_weight);
+			consumed = consumed.saturating_add(Self::expire_left_over_requests(
+				now,
+				remaining_weight.saturating_sub(consumed),
+			));
+			consumed = consumed.saturating_add(Self::prune_commitments(
+				Self::current_block(),
+				remaining_weight.saturating_sub(consumed),
+			));
+			consumed.saturating_add(Self::prune_expired_history(
+				now,
+				remaining_weight.saturating_sub(consumed),
+			))
+		}
+
+		/// Check the invariants of the pallet, see `do_try_state`.
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_now: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state(Self::current_block())
+		}
+
+		/// Offchain worker entry point.
+		///
+		/// Runs the pipeline of `run_pipeline` while holding a lock in the offchain local
+		/// storage, so that workers of blocks imported in quick succession don't race each
+		/// other. A worker which can't take the lock skips its run.
+		fn offchain_worker(block_number: BlockNumberFor<T>) {
+			if <Paused<T, I>>::get() {
+				log::info!(
+					target: logging::SUBMIT,
+					"{} skipping worker, the pallet is paused",
+					Self::log_fields(None)
+				);
+				return
+			}
+			if Self::is_disabled() {
+				Self::log_dry_run(block_number);
+				return
+			}
+
+			let key = Self::local_key(LOCK_KEY);
+			let mut lock =
+				StorageLock::<BlockAndTime<frame_system::Pallet<T>>>::with_block_and_time_deadline(
+					&key,
+					LOCK_BLOCK_EXPIRATION,
+					Duration::from_millis(T::LockTimeout::get()),
+				);
+
+			match lock.try_lock() {
+				Ok(_guard) => {
+					Self::update_metrics(|metrics| {
+						metrics.last_processed_block = block_number.saturated_into();
+					});
+					Self::migrate_legacy_keys();
+					Self::run_stages(block_number)
+				},
+				Err(_) => log::debug!(
+					target: logging::STORAGE,
+					"{} skipping worker, the pipeline is locked by another worker",
+					Self::log_fields(Some(&key))
+				),
+			}
+		}
+	}
+
+	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
+	// These functions materialize as "extrinsics", which are often compared to transactions.
+	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// An example dispatchable that takes a singles value as a parameter, writes the value to
+		/// storage and emits an event. This function must be dispatched by a signed extrinsic.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::do_something())]
+		pub fn do_something(origin: OriginFor<T>, something: u32) -> DispatchResult {
+			// Check that the extrinsic was signed and get the signer.
+			// This function will return an error if the extrinsic is not signed.
+			// https://docs.substrate.io/main-docs/build/origins/
+			let who = ensure_signed(origin)?;
+
+			// Update storage.
+			Self::store_value(something.into())?;
+
+			// Emit an event.
+			Self::deposit_event(Event::SomethingStored { something, who });
+			// Return a successful DispatchResultWithPostInfo
+			Ok(())
+		}
+
+		/// An example dispatchable that may throw a custom error.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::cause_error())]
+		pub fn cause_error(origin: OriginFor<T>) -> DispatchResult {
+			let _who = ensure_signed(origin)?;
+
+			// Read the latest value from storage.
+			let latest = <LatestValuesAt<T, I>>::get()
+				.and_then(|block_number| <ValuesByBlock<T, I>>::get(block_number).last().copied());
+			match latest {
+				// Return an error if no value has been set.
+				None => return Err(Error::<T, I>::NoneValue.into()),
+				Some(old) => {
+					// Increment the value read from storage; will error in the event of overflow.
+					let value = old.checked_add(1).ok_or(Error::<T, I>::StorageOverflow)?;
+					// Store the incremented result as the latest value.
+					Self::store_value(value)
+				},
+			}
+		}
+
+		/// Queue a `Task::SubmitValue` of `number` for the offchain worker of this block, which
+		/// submits it on chain instead of the fetched price.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::write_key_to_ocs())]
+		pub fn write_key_to_ocs(origin: OriginFor<T>, number: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::queue(&who, Task::SubmitValue(number))
+		}
+
+		/// Submit a value computed by the offchain worker.
+		///
+		/// This function must be dispatched by `T::FeederOrigin`, for one of the authorities.
+		/// The offchain worker creates it with one of the keys of type `KEY_TYPE` found in the
+		/// local keystore.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::submit_data_signed())]
+		pub fn submit_data_signed(origin: OriginFor<T>, value: u64) -> DispatchResult {
+			let who = T::FeederOrigin::ensure_origin(origin)?;
+			Self::ensure_not_paused()?;
+			ensure!(Self::is_authority(&who), Error::<T, I>::NotAuthority);
+
+			Self::add_price(value);
+			Self::add_submission(&who, value);
+
+			Self::deposit_event(Event::DataSubmitted { value, who });
+			Ok(())
+		}
+
+		/// Submit a value computed by the offchain worker of `block_number` without a signature.
+		///
+		/// This function must be dispatched by an unsigned extrinsic, which is only accepted
+		/// into the transaction pool if it passes `validate_unsigned`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::submit_number_unsigned())]
+		pub fn submit_number_unsigned(
+			origin: OriginFor<T>,
+			block_number: BlockNumberFor<T>,
+			number: u64,
+		) -> DispatchResult {
+			// This ensures that the function can only be called via unsigned transaction.
+			ensure_none(origin)?;
+			Self::ensure_not_paused()?;
+
+			Self::add_price(number);
+
+			Self::deposit_event(Event::DataSubmittedUnsigned { value: number, block_number });
+			Ok(())
+		}
+
+		/// Submit a value computed by the offchain worker without a signed transaction, but with
+		/// a payload signed by the offchain worker key.
+		///
+		/// This way the submitter pays no fees, while the signature still proves which
+		/// authority produced the value. The signature is checked in `validate_unsigned`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::submit_payload_unsigned_with_signed_payload()
+			.saturating_add(T::WeightInfo::validate_signed_payload(T::MaxAuthorities::get())))]
+		pub fn submit_payload_unsigned_with_signed_payload(
+			origin: OriginFor<T>,
+			payload: DataPayload<T::Public, BlockNumberFor<T>>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			// This ensures that the function can only be called via unsigned transaction.
+			ensure_none(origin)?;
+			Self::ensure_not_paused()?;
+
+			Self::add_price(payload.value);
+
+			let who = payload.public.into_account();
+			Self::add_submission(&who, payload.value);
+			Self::deposit_event(Event::DataSubmitted { value: payload.value, who });
+			Ok(())
+		}
+
+		/// Queue `task` for the offchain worker of this block.
+		///
+		/// Note that the node has to run with `--enable-offchain-indexing true` for the task to
+		/// reach the offchain worker.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::queue_task())]
+		pub fn queue_task(origin: OriginFor<T>, task: Task) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::queue(&who, task)
+		}
+
+		/// Replace the endpoints the offchain worker fetches the price from.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::set_oracle_endpoints())]
+		pub fn set_oracle_endpoints(
+			origin: OriginFor<T>,
+			endpoints: BoundedVec<BoundedVec<u8, T::MaxEndpointLen>, T::MaxEndpoints>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(
+				endpoints.iter().all(|url| sp_std::str::from_utf8(url).is_ok()),
+				Error::<T, I>::InvalidEndpoint
+			);
+
+			let count = endpoints.len() as u32;
+			<OracleEndpoints<T, I>>::put(endpoints);
+
+			Self::deposit_event(Event::OracleEndpointsSet { count });
+			Ok(())
+		}
+
+		/// Report that the offchain worker gave up after `attempts` failed pipeline runs.
+		///
+		/// This function must be dispatched by a signed extrinsic, which the offchain worker
+		/// creates with one of the keys of type `KEY_TYPE` found in the local keystore.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::report_retries_exhausted())]
+		pub fn report_retries_exhausted(origin: OriginFor<T>, attempts: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_authority(&who), Error::<T, I>::NotAuthority);
+
+			Self::deposit_event(Event::RetriesExhausted { who, attempts });
+			Ok(())
+		}
+
+		/// Report that a pipeline run of the offchain worker failed.
+		///
+		/// This function must be dispatched by a signed extrinsic, which the offchain worker
+		/// creates with one of the keys of type `KEY_TYPE` found in the local keystore.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::report_submission_failure())]
+		pub fn report_submission_failure(
+			origin: OriginFor<T>,
+			reason: FailureReason,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_authority(&who), Error::<T, I>::NotAuthority);
+
+			Self::deposit_event(Event::OffchainSubmissionFailed { who, reason });
+			Ok(())
+		}
+
+		/// Allow the offchain worker key of `who` to submit data, reserving `T::AuthorityBond`
+		/// from `who`.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::add_authority())]
+		pub fn add_authority(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			<Authorities<T, I>>::try_mutate(|authorities| {
+				ensure!(!authorities.contains(&who), Error::<T, I>::AlreadyAuthority);
+				authorities.try_push(who.clone()).map_err(|_| Error::<T, I>::TooManyAuthorities)
+			})?;
+			let bond = T::AuthorityBond::get();
+			T::Currency::reserve(&who, bond)?;
+			<Bonds<T, I>>::insert(&who, bond);
+
+			Self::deposit_event(Event::AuthorityAdded { who });
+			Ok(())
+		}
+
+		/// Stop the offchain worker key of `who` from submitting data, unreserving its bond.
+		///
+		/// Its submissions to the current round, if any, are dropped.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::remove_authority()
+			.saturating_add(Pallet::<T, I>::drop_asset_submissions_weight()))]
+		pub fn remove_authority(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			<Authorities<T, I>>::try_mutate(|authorities| {
+				let index = authorities
+					.iter()
+					.position(|authority| authority == &who)
+					.ok_or(Error::<T, I>::NotAuthority)?;
+				authorities.remove(index);
+				Ok::<_, Error<T, I>>(())
+			})?;
+			T::Currency::unreserve(&who, <Bonds<T, I>>::take(&who));
+			<Submissions<T, I>>::remove(<CurrentRound<T, I>>::get().index, &who);
+			Self::drop_asset_submissions(&who);
+			<LastFailureReports<T, I>>::remove(&who);
+
+			Self::deposit_event(Event::AuthorityRemoved { who });
+			Ok(())
+		}
+
+		/// Store `data`, replacing the payload previously stored by the caller, if any.
+		///
+		/// A deposit of `DataDepositBase` plus `DataDepositPerByte` for each byte of `data` is
+		/// reserved from the caller, and adjusted when the payload is replaced.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::submit_data())]
+		pub fn submit_data(
+			origin: OriginFor<T>,
+			data: BoundedVec<u8, T::MaxDataLen>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let len = data.len() as u32;
+			let deposit = T::DataDepositBase::get()
+				.saturating_add(T::DataDepositPerByte::get().saturating_mul(len.into()));
+			let old_deposit =
+				<SubmittedData<T, I>>::get(&who).map_or_else(Zero::zero, |entry| entry.deposit);
+			if deposit > old_deposit {
+				Self::reserve_deposit(&who, deposit - old_deposit)?;
+			} else if old_deposit > deposit {
+				Self::unreserve_deposit(&who, old_deposit - deposit);
+			}
+			<SubmittedData<T, I>>::insert(&who, DataEntry { data, deposit });
+
+			Self::deposit_event(Event::DataStored { who, len, deposit });
+			Ok(())
+		}
+
+		/// Remove the payload stored by the caller and unreserve its deposit.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::clear_data())]
+		pub fn clear_data(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let entry = <SubmittedData<T, I>>::take(&who).ok_or(Error::<T, I>::NoData)?;
+			Self::unreserve_deposit(&who, entry.deposit);
+
+			Self::deposit_event(Event::DataCleared { who, deposit: entry.deposit });
+			Ok(())
+		}
+
+		/// Confirm that the offchain worker processed the tasks queued in `block_number`, which
+		/// unreserves their deposits.
+		///
+		/// This function must be dispatched by a signed extrinsic, which the offchain worker
+		/// creates with one of the keys of type `KEY_TYPE` found in the local keystore. It is
+		/// charged for `MaxIndexedPerBlock` tasks, the weight of the missing ones is refunded.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::confirm_tasks(T::MaxIndexedPerBlock::get()))]
+		pub fn confirm_tasks(
+			origin: OriginFor<T>,
+			block_number: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_authority(&who), Error::<T, I>::NotAuthority);
+			ensure!(block_number < Self::current_block(), Error::<T, I>::TooEarly);
+
+			let deposits = <IndexDeposits<T, I>>::take(block_number);
+			ensure!(!deposits.is_empty(), Error::<T, I>::NothingToConfirm);
+			for (depositor, deposit) in &deposits {
+				Self::unreserve_deposit(depositor, *deposit);
+			}
+
+			let count = deposits.len() as u32;
+			Self::deposit_event(Event::TasksConfirmed { who, block_number, count });
+			Ok(Some(T::WeightInfo::confirm_tasks(count)).into())
+		}
+
+		/// Record a heartbeat of the offchain worker of an authority.
+		///
+		/// This function must be dispatched by an unsigned extrinsic carrying a payload signed
+		/// by the offchain worker key of the authority, which is checked in `validate_unsigned`.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::heartbeat())]
+		pub fn heartbeat(
+			origin: OriginFor<T>,
+			heartbeat: HeartbeatPayload<T::Public, BlockNumberFor<T>>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let who = heartbeat.public.into_account();
+			<LastSeen<T, I>>::insert(&who, heartbeat.block_number);
+
+			Self::deposit_event(Event::HeartbeatReceived {
+				who,
+				block_number: heartbeat.block_number,
+			});
+			Ok(())
+		}
+
+		/// Index all `entries` into the offchain database at once, for the offchain worker of
+		/// this block to read back.
+		///
+		/// Each entry is stored under its own key and committed on chain in
+		/// `BatchCommitments`. Unlike tasks, the whole batch is charged a single weight and no
+		/// deposit. Note that the node has to run with `--enable-offchain-indexing true` for the
+		/// entries to reach the offchain worker.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::write_batch_to_ocs(entries.len() as u32))]
+		pub fn write_batch_to_ocs(
+			origin: OriginFor<T>,
+			entries: BoundedVec<BatchEntry<T, I>, T::MaxBatch>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let block = Self::current_block();
+			let first = <BatchCommitments<T, I>>::try_mutate(block, |commitments| {
+				let first = commitments.len() as u32;
+				for entry in &entries {
+					commitments
+						.try_push(T::Hashing::hash_of(entry))
+						.map_err(|_| Error::<T, I>::TooManyBatchEntries)?;
+				}
+				Ok::<_, DispatchError>(first)
+			})?;
+			for (i, entry) in entries.iter().enumerate() {
+				let key = Self::task_key(KeyKind::BatchEntry, block, first + i as u32);
+				sp_io::offchain_index::set(&key, &entry.encode());
+			}
+
+			let count = entries.len() as u32;
+			Self::deposit_event(Event::BatchIndexed { who, block, count });
+			Ok(())
+		}
+
+		/// Remove the entries indexed in `block_number`, tasks and batches alike, from the
+		/// offchain database.
+		///
+		/// Their commitments are kept, but they are no longer listed by `indexed_keys`. The
+		/// deposits of the tasks are still refunded by `confirm_tasks`.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::clear_ocs_key())]
+		pub fn clear_ocs_key(
+			origin: OriginFor<T>,
+			block_number: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let keys = Self::indexed_keys(block_number, block_number);
+			ensure!(!keys.is_empty(), Error::<T, I>::NothingToClear);
+			for key in &keys {
+				sp_io::offchain_index::clear(key);
+			}
+			<IndexedKeys<T, I>>::remove(block_number);
+			<BatchCommitments<T, I>>::remove(block_number);
+
+			let count = keys.len() as u32;
+			Self::deposit_event(Event::OffchainEntriesCleared { block_number, count });
+			Ok(())
+		}
+
+		/// Commit to a value by its `hash`, see `Pallet::reveal_hash`, reserving
+		/// `T::CommitDeposit` until it is revealed with `reveal_value`.
+		///
+		/// This function must be dispatched by `T::FeederOrigin`, for one of the authorities.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::commit_value())]
+		pub fn commit_value(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			let who = T::FeederOrigin::ensure_origin(origin)?;
+			Self::ensure_not_paused()?;
+			ensure!(<Authorities<T, I>>::get().contains(&who), Error::<T, I>::NotAuthority);
+			ensure!(!<Commitments<T, I>>::contains_key(&who), Error::<T, I>::AlreadyCommitted);
+
+			let deposit = T::CommitDeposit::get();
+			Self::reserve_deposit(&who, deposit)?;
+			let committed_at = <frame_system::Pallet<T>>::block_number();
+			if let Some(expires_at) = Self::commitment_expiry(committed_at) {
+				<CommitmentExpiries<T, I>>::try_append(expires_at, &who)
+					.map_err(|_| Error::<T, I>::TooManyAuthorities)?;
+			}
+			<Commitments<T, I>>::insert(&who, ValueCommitment { hash, committed_at, deposit });
+
+			Self::deposit_event(Event::ValueCommitted { who, hash });
+			Ok(())
+		}
+
+		/// Reveal the value committed to with `commit_value`, at least `T::RevealDelay` blocks
+		/// after the commitment and, unless it is zero, within `T::RevealWindow` blocks after
+		/// that.
+		///
+		/// A matching value is submitted like any other and the deposit is unreserved. If the
+		/// value doesn't match the commitment, the deposit is slashed instead.
+		///
+		/// This function must be dispatched by `T::FeederOrigin`.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::reveal_value())]
+		pub fn reveal_value(origin: OriginFor<T>, value: u64, salt: [u8; 32]) -> DispatchResult {
+			let who = T::FeederOrigin::ensure_origin(origin)?;
+			Self::ensure_not_paused()?;
+			let commitment = <Commitments<T, I>>::get(&who).ok_or(Error::<T, I>::NoCommitment)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				commitment.committed_at.saturating_add(T::RevealDelay::get()) <= now,
+				Error::<T, I>::RevealTooEarly
+			);
+
+			<Commitments<T, I>>::remove(&who);
+			if Self::reveal_hash(value, &salt) != commitment.hash {
+				let slashed = Self::slash_deposit(&who, commitment.deposit);
+				Self::deposit_event(Event::RevealMismatched { who, slashed });
+				return Ok(())
+			}
+
+			Self::unreserve_deposit(&who, commitment.deposit);
+			Self::add_price(value);
+			Self::add_submission(&who, value);
+			Self::deposit_event(Event::ValueRevealed { who, value });
+			Ok(())
+		}
+
+		/// Subscribe to `topic`, reserving `T::SubscriptionDeposit` until `unsubscribe`.
+		///
+		/// The offchain workers of the authorities fetch the values of the subscribed topics every
+		/// `T::TopicPeriod` blocks, see `Event::TopicUpdated`.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::subscribe())]
+		pub fn subscribe(origin: OriginFor<T>, topic: Topic<T, I>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(sp_std::str::from_utf8(&topic).is_ok(), Error::<T, I>::InvalidTopic);
+			ensure!(
+				!<Subscriptions<T, I>>::contains_key(&topic, &who),
+				Error::<T, I>::AlreadySubscribed
+			);
+			ensure!(
+				<Topics<T, I>>::contains_key(&topic) ||
+					<Topics<T, I>>::count() < T::MaxTopics::get(),
+				Error::<T, I>::TooManyTopics
+			);
+
+			let deposit = T::SubscriptionDeposit::get();
+			Self::reserve_deposit(&who, deposit)?;
+			<Subscriptions<T, I>>::insert(&topic, &who, deposit);
+			<Topics<T, I>>::mutate(&topic, |subscribers| {
+				*subscribers = subscribers.saturating_add(1)
+			});
+
+			Self::deposit_event(Event::Subscribed { who, topic });
+			Ok(())
+		}
+
+		/// Unsubscribe from `topic` and unreserve the deposit of the subscription.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::unsubscribe())]
+		pub fn unsubscribe(origin: OriginFor<T>, topic: Topic<T, I>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let deposit =
+				<Subscriptions<T, I>>::take(&topic, &who).ok_or(Error::<T, I>::NotSubscribed)?;
+
+			Self::unreserve_deposit(&who, deposit);
+			<Topics<T, I>>::mutate_exists(&topic, |subscribers| {
+				*subscribers = subscribers.map(|n| n.saturating_sub(1)).filter(|n| *n > 0);
+			});
+
+			Self::deposit_event(Event::Unsubscribed { who, topic });
+			Ok(())
+		}
+
+		/// Publish the values of subscribed topics fetched by the offchain worker.
+		///
+		/// This function must be dispatched by `T::FeederOrigin`, for one of the authorities.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::update_topics(updates.len() as u32))]
+		pub fn update_topics(
+			origin: OriginFor<T>,
+			updates: BoundedVec<(Topic<T, I>, u64), T::MaxTopics>,
+		) -> DispatchResult {
+			let who = T::FeederOrigin::ensure_origin(origin)?;
+			Self::ensure_not_paused()?;
+			ensure!(<Authorities<T, I>>::get().contains(&who), Error::<T, I>::NotAuthority);
+			for (topic, _) in &updates {
+				ensure!(<Topics<T, I>>::contains_key(topic), Error::<T, I>::UnknownTopic);
+			}
+
+			for (topic, value) in updates {
+				Self::deposit_event(Event::TopicUpdated { topic, value });
+			}
+			Ok(())
+		}
+
+		/// Set the configuration of the offchain worker, which it reads on each run, or reset it
+		/// to the defaults of the runtime with `None`.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::set_ocw_config())]
+		pub fn set_ocw_config(
+			origin: OriginFor<T>,
+			config: Option<OcwConfigOf<T, I>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			if let Some(config) = &config {
+				ensure!(
+					sp_std::str::from_utf8(&config.endpoint).is_ok(),
+					Error::<T, I>::InvalidEndpoint
+				);
+			}
+
+			<OcwConfig<T, I>>::set(config.clone());
+
+			Self::deposit_event(Event::OcwConfigSet { config });
+			Ok(())
+		}
+
+		/// Register `asset`, whose price the offchain workers fetch from the `field` of the
+		/// JSON object at `endpoint`, and submit with `submit_asset_prices`.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::register_asset())]
+		pub fn register_asset(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			endpoint: BoundedVec<u8, T::MaxEndpointLen>,
+			field: BoundedVec<u8, T::MaxEndpointLen>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(sp_std::str::from_utf8(&endpoint).is_ok(), Error::<T, I>::InvalidEndpoint);
+			ensure!(sp_std::str::from_utf8(&field).is_ok(), Error::<T, I>::InvalidField);
+			ensure!(
+				url::substitute(&endpoint, &[(url::ASSET, &field[..])]).is_ok(),
+				Error::<T, I>::InvalidEndpoint
+			);
+			ensure!(!<Assets<T, I>>::contains_key(asset), Error::<T, I>::AssetAlreadyRegistered);
+			ensure!(<Assets<T, I>>::count() < T::MaxAssets::get(), Error::<T, I>::TooManyAssets);
+
+			<Assets<T, I>>::insert(asset, AssetInfo { endpoint: endpoint.clone(), field });
+
+			Self::deposit_event(Event::AssetRegistered { asset, endpoint });
+			Ok(())
+		}
+
+		/// Deregister `asset`, removing its price and the submissions of the current round.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::deregister_asset())]
+		pub fn deregister_asset(origin: OriginFor<T>, asset: T::AssetId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+
+			<Assets<T, I>>::remove(asset);
+			<Prices<T, I>>::remove(asset);
+			<PriceTimestamps<T, I>>::remove(asset);
+			<ChangeThresholds<T, I>>::remove(asset);
+			let _ = <AssetSubmissions<T, I>>::clear_prefix(asset, T::MaxAuthorities::get(), None);
+
+			Self::deposit_event(Event::AssetDeregistered { asset });
+			Ok(())
+		}
+
+		/// Submit the prices of registered assets fetched by the offchain worker, for the
+		/// current round. A later submission in the same round replaces the earlier one.
+		///
+		/// This function must be dispatched by `T::FeederOrigin`, for one of the authorities.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::submit_asset_prices(prices.len() as u32))]
+		pub fn submit_asset_prices(
+			origin: OriginFor<T>,
+			prices: BoundedVec<(T::AssetId, u64), T::MaxAssets>,
+		) -> DispatchResult {
+			let who = T::FeederOrigin::ensure_origin(origin)?;
+			Self::ensure_not_paused()?;
+			ensure!(<Authorities<T, I>>::get().contains(&who), Error::<T, I>::NotAuthority);
+			ensure!(
+				<CurrentRound<T, I>>::get().phase != RoundPhase::Finalized,
+				Error::<T, I>::RoundAlreadyFinalized
+			);
+			for (asset, _) in &prices {
+				ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+			}
+
+			for (asset, price) in &prices {
+				<AssetSubmissions<T, I>>::insert(asset, &who, price);
+			}
+
+			let count = prices.len() as u32;
+			Self::deposit_event(Event::AssetPricesSubmitted { who, count });
+			Ok(())
+		}
+
+		/// Index `payload` into the offchain database, committing only the Merkle root of its
+		/// chunks of `PAYLOAD_CHUNK_LEN` bytes on chain, in `PayloadRoots`.
+		///
+		/// The payload is stored in chunks of `T::ChunkSize` bytes, compressed with the
+		/// `compression` feature, which the offchain worker reassembles with `read_payload`. The
+		/// inclusion of a chunk can then be proven with `verify_chunk`, without the whole
+		/// payload. Note that the node has to run with `--enable-offchain-indexing true`
+		/// for the payload to reach the offchain database.
+		///
+		/// This function must be dispatched by a signed extrinsic.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::index_payload(payload.len() as u32))]
+		pub fn index_payload(
+			origin: OriginFor<T>,
+			payload: BoundedVec<u8, T::MaxPayloadLen>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let leaves = merkle::leaves::<T::Hashing>(&payload, PAYLOAD_CHUNK_LEN as usize);
+			let root = merkle::root::<T::Hashing>(&leaves).ok_or(Error::<T, I>::EmptyPayload)?;
+			ensure!(
+				!<PayloadRoots<T, I>>::contains_key(root),
+				Error::<T, I>::PayloadAlreadyIndexed
+			);
+
+			let chunks = leaves.len() as u32;
+			let block = <frame_system::Pallet<T>>::block_number();
+			<PayloadRoots<T, I>>::insert(root, (block, chunks));
+			let key = keys::payload_key(&Self::key_scope(), &root);
+			blob::index::<T::Hashing>(&key, &compression::encode(&payload), T::ChunkSize::get());
+
+			Self::deposit_event(Event::PayloadIndexed { who, root, chunks });
+			Ok(())
+		}
+
+		/// Prove that `chunk` is the chunk at the index of `proof` of a payload indexed with
+		/// `index_payload`.
+		///
+		/// This function must be dispatched by a signed extrinsic.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::verify_chunk())]
+		pub fn verify_chunk(
+			origin: OriginFor<T>,
+			proof: merkle::MerkleProof<T::Hash>,
+			chunk: BoundedVec<u8, ConstU32<PAYLOAD_CHUNK_LEN>>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let root = merkle::root_from_proof::<T::Hashing>(
+				&proof,
+				merkle::leaf_hash::<T::Hashing>(&chunk),
+			)
+			.ok_or(Error::<T, I>::InvalidProof)?;
+			let (_, chunks) = <PayloadRoots<T, I>>::get(root).ok_or(Error::<T, I>::InvalidProof)?;
+			ensure!(proof.leaf_count == chunks, Error::<T, I>::InvalidProof);
+
+			Self::deposit_event(Event::ChunkVerified { root, index: proof.index });
+			Ok(())
+		}
+
+		/// Set the URL template the prices of the assets registered without an endpoint are
+		/// fetched from, in which `{ASSET}` stands for the field of each asset.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::set_endpoint_template())]
+		pub fn set_endpoint_template(
+			origin: OriginFor<T>,
+			template: BoundedVec<u8, T::MaxEndpointLen>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(sp_std::str::from_utf8(&template).is_ok(), Error::<T, I>::InvalidEndpoint);
+			ensure!(
+				url::substitute(&template, &[(url::ASSET, &[][..])]).is_ok(),
+				Error::<T, I>::InvalidEndpoint
+			);
+
+			<EndpointTemplate<T, I>>::put(&template);
+
+			Self::deposit_event(Event::EndpointTemplateSet { template });
+			Ok(())
+		}
+
+		/// Remove the values stored before block `up_to`, even if they are within
+		/// `HistoryDepth`, as far as `MaxHistoryPrune` blocks past the oldest block left. The
+		/// values of the blocks past those are removed later by `on_idle`, see `PendingWork`.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`. It is charged for
+		/// `MaxHistoryPrune` blocks, the weight of the blocks it didn't prune is refunded.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::prune_history(T::MaxHistoryPrune::get()))]
+		pub fn prune_history(
+			origin: OriginFor<T>,
+			up_to: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(up_to <= <frame_system::Pallet<T>>::block_number(), Error::<T, I>::PruneTooFar);
+
+			let from = <HistoryCursor<T, I>>::get();
+			let pruned_up_to = Self::prune_values(up_to, T::MaxHistoryPrune::get());
+			if pruned_up_to < up_to {
+				Self::defer(WorkItem::PruneHistory { up_to })?;
+			}
+
+			Self::deposit_event(Event::HistoryPruned { up_to: pruned_up_to });
+			let pruned = pruned_up_to.saturating_sub(from).saturated_into::<u32>();
+			Ok(Some(T::WeightInfo::prune_history(pruned)).into())
+		}
+
+		/// Override the price of `asset` with `value`, when the feeds of the offchain workers are
+		/// broken. The round of the price is left as is, the submissions of the current round
+		/// are aggregated as usual once it ends.
+		///
+		/// This function must be dispatched by `T::ForceOrigin`.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::force_set_value())]
+		pub fn force_set_value(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			value: u64,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+
+			let round = <Prices<T, I>>::get(asset).map_or(0, |info| info.round);
+			Self::set_price(asset, value, round);
+			T::OnNewValue::on_new_value(&asset, &value);
+
+			Self::deposit_event(Event::ValueForced { asset, value });
+			Ok(())
+		}
+
+		/// Submit a record of the feed at `T::FeedEndpoint`, fetched by the offchain worker,
+		/// without a signature.
+		///
+		/// This function must be dispatched by an unsigned extrinsic, which is only accepted
+		/// into the transaction pool if it passes `validate_unsigned`. The records are accepted
+		/// in the order of the feed: `record.since` must be the `feed_cursor`.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::submit_record_unsigned())]
+		pub fn submit_record_unsigned(origin: OriginFor<T>, record: FeedRecord) -> DispatchResult {
+			ensure_none(origin)?;
+			Self::add_record(record)
+		}
+
+		/// Request the anchoring of the document of `cid`: the offchain workers fetch it from
+		/// `T::IpfsGateway`, verify it against the CID and anchor the hash of its content with
+		/// `anchor_document`.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::request_document())]
+		pub fn request_document(origin: OriginFor<T>, cid: CidOf<T, I>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(ipfs::cid_digest(&cid).is_ok(), Error::<T, I>::InvalidCid);
+			ensure!(
+				!<DocumentRequests<T, I>>::contains_key(&cid) &&
+					!<Documents<T, I>>::contains_key(&cid),
+				Error::<T, I>::DocumentAlreadyRequested
+			);
+
+			<DocumentRequests<T, I>>::insert(&cid, <frame_system::Pallet<T>>::block_number());
+
+			Self::deposit_event(Event::DocumentRequested { cid });
+			Ok(())
+		}
+
+		/// Anchor `hash`, the hash of the content of the document of `cid` fetched and verified
+		/// by the offchain worker, see `request_document`.
+		///
+		/// This function must be dispatched by `T::FeederOrigin`, for one of the authorities.
+		/// The first anchor of a document is kept.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::anchor_document())]
+		pub fn anchor_document(
+			origin: OriginFor<T>,
+			cid: CidOf<T, I>,
+			hash: T::Hash,
+		) -> DispatchResult {
+			let who = T::FeederOrigin::ensure_origin(origin)?;
+			Self::ensure_not_paused()?;
+			ensure!(<Authorities<T, I>>::get().contains(&who), Error::<T, I>::NotAuthority);
+			ensure!(<DocumentRequests<T, I>>::take(&cid).is_some(), Error::<T, I>::UnknownDocument);
+
+			<Documents<T, I>>::insert(&cid, hash);
+
+			Self::deposit_event(Event::DocumentAnchored { cid, hash, who });
+			Ok(())
+		}
+
+		/// Accept the attestations of `signer` if `trusted`, or stop accepting them, see
+		/// `submit_attested_data`.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`. The data it already attested is
+		/// kept.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::set_attester())]
+		pub fn set_attester(
+			origin: OriginFor<T>,
+			signer: AttesterKey,
+			trusted: bool,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			if trusted {
+				<Attesters<T, I>>::insert(&signer, ());
+			} else {
+				<Attesters<T, I>>::remove(&signer);
+			}
+
+			Self::deposit_event(Event::AttesterSet { signer, trusted });
+			Ok(())
+		}
+
+		/// Store `data`, as fetched by the offchain worker from an API which signs its
+		/// responses, with `signature`, the signature of `data` by `signer`.
+		///
+		/// The signature is verified on chain, so the data is known to come from `signer`
+		/// rather than only from the authority submitting it. This function must be dispatched
+		/// by `T::FeederOrigin`, for one of the authorities, and `signer` must be one of the
+		/// `Attesters`. The data replaces the data previously attested by `signer`.
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::submit_attested_data(data.len() as u32))]
+		pub fn submit_attested_data(
+			origin: OriginFor<T>,
+			data: BoundedVec<u8, T::MaxDataLen>,
+			signature: Attestation,
+			signer: AttesterKey,
+		) -> DispatchResult {
+			let who = T::FeederOrigin::ensure_origin(origin)?;
+			Self::ensure_not_paused()?;
+			ensure!(<Authorities<T, I>>::get().contains(&who), Error::<T, I>::NotAuthority);
+			ensure!(<Attesters<T, I>>::contains_key(&signer), Error::<T, I>::UntrustedAttester);
+			ensure!(data.len() as u32 <= T::MaxOcwPayload::get(), Error::<T, I>::PayloadTooLarge);
+			ensure!(signer.verify(&signature, &data), Error::<T, I>::BadAttestation);
+
+			let len = data.len() as u32;
+			<AttestedData<T, I>>::insert(
+				&signer,
+				(data, <frame_system::Pallet<T>>::block_number()),
+			);
+
+			Self::deposit_event(Event::DataAttested { signer, who, len });
+			Ok(())
+		}
+
+		/// Stop accepting values and running the offchain workers, e.g. when an upstream API
+		/// is compromised, until `unpause`.
+		///
+		/// The submissions are rejected, both in the pool and on dispatch, and the offchain
+		/// workers return as soon as they start. The commitments expiring meanwhile are
+		/// refunded rather than slashed, as they can't be revealed.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`.
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			<Paused<T, I>>::put(true);
+
+			Self::deposit_event(Event::Paused);
+			Ok(())
+		}
+
+		/// Resume the submissions and the offchain workers stopped with `pause`.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`.
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::unpause())]
+		pub fn unpause(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			<Paused<T, I>>::kill();
+
+			Self::deposit_event(Event::Unpaused);
+			Ok(())
+		}
+
+		/// Set the price of `asset` to `value`, sent by the oracle of another chain, e.g. with
+		/// `remote::SendToSiblings`. The round of the price is left as is, and `OnNewValue` isn't
+		/// called, so that the price isn't sent on to other chains.
+		///
+		/// This function must be dispatched by `T::RemoteOrigin`.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::receive_remote_value())]
+		pub fn receive_remote_value(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			value: u64,
+		) -> DispatchResult {
+			T::RemoteOrigin::ensure_origin(origin)?;
+			Self::ensure_not_paused()?;
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+
+			let round = <Prices<T, I>>::get(asset).map_or(0, |info| info.round);
+			Self::set_price(asset, value, round);
+
+			Self::deposit_event(Event::RemoteValueReceived { asset, value });
+			Ok(())
+		}
+
+		/// Schedule `task` for the offchain worker of block `at`, e.g. to fetch the price then.
+		///
+		/// Unlike the tasks queued with `queue_task`, the task is stored on chain until `at`, so
+		/// it doesn't need offchain indexing. It is only run by the workers of block `at`.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::schedule_task())]
+		pub fn schedule_task(
+			origin: OriginFor<T>,
+			at: BlockNumberFor<T>,
+			task: Task,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(at > <frame_system::Pallet<T>>::block_number(), Error::<T, I>::BlockInThePast);
+
+			Self::schedule(at, task)
+		}
+
+		/// Schedule `task` for the offchain worker of this block.
+		///
+		/// This is meant to be dispatched by `pallet_scheduler`, so that the recurring offchain
+		/// work is scheduled on chain with its periodic tasks, rather than by the offchain
+		/// worker itself.
+		///
+		/// This function must be dispatched by `T::SchedulerOrigin`.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::execute_task())]
+		pub fn execute_task(origin: OriginFor<T>, task: Task) -> DispatchResult {
+			T::SchedulerOrigin::ensure_origin(origin)?;
+
+			Self::schedule(<frame_system::Pallet<T>>::block_number(), task)
+		}
+
+		/// Unreserve the deposits of the caller for the tasks whose commitments were pruned
+		/// before the offchain worker confirmed them, see `DepositInfo::reclaimable`.
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::reclaim_deposit())]
+		pub fn reclaim_deposit(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let amount = <Deposits<T, I>>::get(&who).reclaimable;
+			ensure!(!amount.is_zero(), Error::<T, I>::NothingToReclaim);
+
+			Self::unreserve_deposit(&who, amount);
+
+			Self::deposit_event(Event::DepositReclaimed { who, amount });
+			Ok(())
+		}
+
+		/// Dispute `round`, the latest finalized round, reserving `bond` from the caller until
+		/// the dispute is resolved with `resolve_dispute`.
+		///
+		/// The bond must be at least `T::MinDisputeBond`. It is refunded if the dispute is
+		/// upheld, and slashed if it is rejected.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::raise_dispute())]
+		pub fn raise_dispute(
+			origin: OriginFor<T>,
+			round: RoundIndex,
+			bond: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(bond >= T::MinDisputeBond::get(), Error::<T, I>::DisputeBondTooLow);
+			ensure!(
+				<LatestRound<T, I>>::get().map(|(latest, _)| latest) == Some(round),
+				Error::<T, I>::NotDisputable
+			);
+			ensure!(!<Disputes<T, I>>::contains_key(round), Error::<T, I>::AlreadyDisputed);
+
+			Self::reserve_deposit(&who, bond)?;
+			let submissions = <LatestSubmissions<T, I>>::get();
+			<Disputes<T, I>>::insert(round, DisputeInfo { who: who.clone(), bond, submissions });
+
+			Self::deposit_event(Event::DisputeRaised { who, round, bond });
+			Ok(())
+		}
+
+		/// Resolve the dispute of `round` with `outcome`.
+		///
+		/// An upheld dispute invalidates the round, which is no longer the `LatestRound`, and
+		/// slashes and removes the authorities whose submission deviated from the right value
+		/// by more than `T::MaxDeviation`. Its bond is refunded. A rejected dispute has its bond
+		/// slashed.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`. It is charged for a full set of
+		/// authorities slashed, the weight of the others is refunded.
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::resolve_dispute(T::MaxAuthorities::get()))]
+		pub fn resolve_dispute(
+			origin: OriginFor<T>,
+			round: RoundIndex,
+			outcome: DisputeOutcome,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let dispute = <Disputes<T, I>>::take(round).ok_or(Error::<T, I>::NoDispute)?;
+
+			let DisputeOutcome::Upheld { value } = outcome else {
+				let slashed = Self::slash_deposit(&dispute.who, dispute.bond);
+				Self::deposit_event(Event::DisputeRejected { round, slashed });
+				return Ok(Some(T::WeightInfo::resolve_dispute(0)).into())
+			};
+			Self::unreserve_deposit(&dispute.who, dispute.bond);
+			if <LatestRound<T, I>>::get().map(|(latest, _)| latest) == Some(round) {
+				<LatestRound<T, I>>::kill();
+			}
+			let authorities = <Authorities<T, I>>::get();
+			let mut slashed = 0;
+			for (who, submitted) in dispute.submissions {
+				let deviation = Perbill::from_rational(submitted.abs_diff(value), value.max(1));
+				if deviation > T::MaxDeviation::get() && authorities.contains(&who) {
+					Self::slash_authority(who);
+					slashed += 1;
+				}
+			}
+
+			Self::deposit_event(Event::RoundInvalidated { round, value });
+			Ok(Some(T::WeightInfo::resolve_dispute(slashed)).into())
+		}
+
+		/// Set the value of the block to `value`, the latest value known to its author.
+		///
+		/// This function must be dispatched by an inherent, created by `create_inherent` from
+		/// the inherent data of the author, at most once per block. The value must be within
+		/// `T::MaxDeviation` of the median of the latest finalized round.
+		#[pallet::call_index(45)]
+		#[pallet::weight((T::WeightInfo::set_inherent_value(), DispatchClass::Mandatory))]
+		pub fn set_inherent_value(origin: OriginFor<T>, value: u64) -> DispatchResult {
+			ensure_none(origin)?;
+			Self::ensure_not_paused()?;
+			ensure!(!<InherentIncluded<T, I>>::get(), Error::<T, I>::InherentValueAlreadySet);
+			Self::check_inherent_value(value).map_err(|_| Error::<T, I>::InherentValueRejected)?;
+			<InherentIncluded<T, I>>::put(true);
+
+			Self::add_price(value);
+
+			Self::deposit_event(Event::InherentValueSet { value });
+			Ok(())
+		}
+
+		/// Request the value of `query`, escrowing `fee` from the caller in `escrow_account`:
+		/// the offchain workers fetch it from `T::QueryEndpoint` and answer it with
+		/// `fulfill_request`.
+		///
+		/// The fee is paid to the authority which fulfills the request, see `RequestFulfilled`
+		/// for the answer, or refunded with `refund_request` if the request times out. It must
+		/// be at least the existential deposit. At most `T::MaxPendingRequests` requests are
+		/// pending at once.
+		///
+		/// The `callback`, if any, is dispatched as the caller with the id and the answer of the
+		/// request appended once it is fulfilled, see `WithAnswer`. It must be at most as heavy
+		/// as `T::MaxCallbackWeight`.
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::request_value())]
+		pub fn request_value(
+			origin: OriginFor<T>,
+			query: BoundedVec<u8, T::MaxQueryLen>,
+			fee: BalanceOf<T, I>,
+			callback: Option<BoundedVec<u8, T::MaxCallbackLen>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_paused()?;
+			ensure!(
+				<Requests<T, I>>::count() < T::MaxPendingRequests::get(),
+				Error::<T, I>::TooManyRequests
+			);
+			ensure!(fee >= T::Currency::minimum_balance(), Error::<T, I>::FeeTooLow);
+
+			T::Currency::transfer(
+				&who,
+				&Self::escrow_account(),
+				fee,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			let id = <NextRequestId<T, I>>::mutate(|next| {
+				let id = *next;
+				*next = next.wrapping_add(1);
+				id
+			});
+			if let Some(callback) = &callback {
+				let call = <T as Config<I>>::RuntimeCall::with_answer(callback, id, 0)
+					.ok_or(Error::<T, I>::InvalidCallback)?;
+				ensure!(
+					call.get_dispatch_info().weight.all_lte(T::MaxCallbackWeight::get()),
+					Error::<T, I>::CallbackTooHeavy
+				);
+			}
+			let requested_at = <frame_system::Pallet<T>>::block_number();
+			<RequestsByExpiry<T, I>>::try_append(
+				requested_at.saturating_add(T::RequestTtl::get()),
+				id,
+			)
+			.map_err(|_| Error::<T, I>::TooManyRequests)?;
+			<Requests<T, I>>::insert(
+				id,
+				PendingRequest { requester: who.clone(), query, fee, requested_at, callback },
+			);
+
+			Self::deposit_event(Event::ValueRequested { id, who, fee });
+			Ok(())
+		}
+
+		/// Answer the request `id` with `answer`, fetched by the offchain worker, see
+		/// `request_value`. The fee of the request is paid to the caller, then its callback is
+		/// dispatched, if any.
+		///
+		/// This function must be dispatched by `T::FeederOrigin`, for one of the authorities.
+		/// The first answer of a request is kept, unless the request timed out. It is charged
+		/// for a callback of `T::MaxCallbackWeight`, the weight the callback didn't use is
+		/// refunded.
+		#[pallet::call_index(47)]
+		#[pallet::weight(
+			T::WeightInfo::fulfill_request().saturating_add(T::MaxCallbackWeight::get())
+		)]
+		pub fn fulfill_request(
+			origin: OriginFor<T>,
+			id: RequestId,
+			answer: u64,
+		) -> DispatchResultWithPostInfo {
+			let who = T::FeederOrigin::ensure_origin(origin)?;
+			Self::ensure_not_paused()?;
+			ensure!(<Authorities<T, I>>::get().contains(&who), Error::<T, I>::NotAuthority);
+			let request = <Requests<T, I>>::take(id).ok_or(Error::<T, I>::UnknownRequest)?;
+			ensure!(!Self::is_expired(&request), Error::<T, I>::RequestExpired);
+			<RequestsByExpiry<T, I>>::mutate(Self::request_expiry(&request), |ids| {
+				ids.retain(|pending| *pending != id)
+			});
+
+			T::Currency::transfer(
+				&Self::escrow_account(),
+				&who,
+				request.fee,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Self::deposit_event(Event::RequestFulfilled { id, answer });
+			let callback_weight = match request.callback {
+				Some(callback) => Self::dispatch_callback(id, request.requester, &callback, answer),
+				None => Weight::zero(),
+			};
+			Ok(Some(T::WeightInfo::fulfill_request().saturating_add(callback_weight)).into())
+		}
+
+		/// Refund the fee of the request `id` to its requester, once it timed out unfulfilled
+		/// after `T::RequestTtl` blocks, see `request_value`.
+		///
+		/// The pallet refunds the requests which timed out on its own, see `RequestsByExpiry`,
+		/// so this is only needed while it is behind. This function may be dispatched by any
+		/// signed origin.
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::refund_request())]
+		pub fn refund_request(origin: OriginFor<T>, id: RequestId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let request = <Requests<T, I>>::take(id).ok_or(Error::<T, I>::UnknownRequest)?;
+			ensure!(Self::is_expired(&request), Error::<T, I>::RequestNotExpired);
+
+			T::Currency::transfer(
+				&Self::escrow_account(),
+				&request.requester,
+				request.fee,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Self::deposit_event(Event::RequestRefunded {
+				id,
+				who: request.requester,
+				fee: request.fee,
+			});
+			Ok(())
+		}
+
+		/// Set the threshold in basis points `asset` must move by for `Event::SignificantChange`,
+		/// or reset it to `T::ChangeThresholdBps` for `None`.
+		///
+		/// This function must be dispatched by `T::AdminOrigin`.
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::set_change_threshold())]
+		pub fn set_change_threshold(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			threshold: Option<u32>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+
+			<ChangeThresholds<T, I>>::set(asset, threshold);
+
+			Self::deposit_event(Event::ChangeThresholdSet { asset, threshold });
+			Ok(())
+		}
+
+		/// Record a failure of the offchain worker of the caller with `code`, and the hash of its
+		/// context, so that failures which never reach the chain otherwise can be diagnosed from
+		/// it. The latest `T::MaxFailureReports` failures are kept in `FailureReports`.
+		///
+		/// This function must be dispatched by one of the authorities, at most once every
+		/// `T::FailureReportInterval` blocks.
+		#[pallet::call_index(50)]
+		#[pallet::weight(T::WeightInfo::report_failure())]
+		pub fn report_failure(
+			origin: OriginFor<T>,
+			code: u32,
+			context_hash: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Unlike the submissions, reports are rejected while there are no authorities, so
+			// that `LastFailureReports` only has entries of the authorities.
+			ensure!(<Authorities<T, I>>::get().contains(&who), Error::<T, I>::NotAuthority);
+			let now = <frame_system::Pallet<T>>::block_number();
+			if let Some(last) = <LastFailureReports<T, I>>::get(&who) {
+				ensure!(
+					now >= last.saturating_add(T::FailureReportInterval::get()),
+					Error::<T, I>::FailureReportTooSoon
+				);
+			}
+
+			<LastFailureReports<T, I>>::insert(&who, now);
+			<FailureReports<T, I>>::mutate(|reports| {
+				let report =
+					FailureReport { who: who.clone(), code, context_hash, reported_at: now };
+				if let Err(report) = reports.try_push(report) {
+					if !reports.is_empty() {
+						reports.remove(0);
+						let _ = reports.try_push(report);
+					}
+				}
+			});
+
+			Self::deposit_event(Event::FailureReported { who, code, context_hash });
+			Ok(())
+		}
+
+		/// Submit a record of the feed at `T::FeedEndpoint` as `submit_record_unsigned` does,
+		/// with the signature of one of the authorities over `payload`.
+		///
+		/// This function must be dispatched by an unsigned extrinsic, which is only accepted
+		/// into the transaction pool if it passes `validate_unsigned`: the signature must be
+		/// valid and its key that of one of the authorities.
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::submit_record_unsigned_with_signed_payload())]
+		pub fn submit_record_unsigned_with_signed_payload(
+			origin: OriginFor<T>,
+			payload: RecordPayload<T::Public>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			Self::add_record(payload.record)
+		}
+	}
+
+	#[pallet::inherent]
+	impl<T: Config<I>, I: 'static> ProvideInherent for Pallet<T, I> {
+		type Call = Call<T, I>;
+		type Error = InherentError;
+		const INHERENT_IDENTIFIER: InherentIdentifier = INHERENT_IDENTIFIER;
+
+		/// Set the value of the inherent data of the author, if the instance accepts values from
+		/// inherents and the value passes `check_inherent_value`. A value which doesn't is left
+		/// out, so that the block stays valid.
+		fn create_inherent(data: &InherentData) -> Option<Self::Call> {
+			let value = data.get_data::<u64>(&INHERENT_IDENTIFIER).ok().flatten()?;
+			if let Err(e) = Self::check_inherent_value(value) {
+				log::warn!(target: LOG_TARGET, "leaving out inherent value {}: {:?}", value, e);
+				return None
+			}
+			Some(Call::set_inherent_value { value })
+		}
+
+		fn check_inherent(call: &Self::Call, _data: &InherentData) -> Result<(), Self::Error> {
+			match call {
+				Call::set_inherent_value { value } => Self::check_inherent_value(*value),
+				_ => Ok(()),
+			}
+		}
+
+		fn is_inherent(call: &Self::Call) -> bool {
+			matches!(call, Call::set_inherent_value { .. })
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config<I>, I: 'static> ValidateUnsigned for Pallet<T, I> {
+		type Call = Call<T, I>;
+
+		/// Validate unsigned call to this module.
+		///
+		/// By default unsigned transactions are disallowed, but implementing the validator
+		/// here we make sure that some particular calls (the ones produced by offchain worker)
+		/// are being whitelisted and marked as valid.
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			if <Paused<T, I>>::get() {
+				return InvalidTransaction::Call.into()
+			}
+			if let Call::submit_payload_unsigned_with_signed_payload { payload, signature } = call {
+				let signature_valid =
+					SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone());
+				if !signature_valid {
+					return InvalidTransaction::BadProof.into()
+				}
+				if !Self::is_authority(&payload.public.clone().into_account()) {
+					return InvalidTransaction::BadSigner.into()
+				}
+				Self::validate_transaction_parameters(&payload.block_number, payload.value)
+			} else if let Call::submit_number_unsigned { block_number, number } = call {
+				// Raw unsigned transactions can't prove which key they come from.
+				if !<Authorities<T, I>>::get().is_empty() {
+					return InvalidTransaction::BadSigner.into()
+				}
+				Self::validate_transaction_parameters(block_number, *number)
+			} else if let Call::heartbeat { heartbeat, signature } = call {
+				Self::validate_heartbeat(heartbeat, signature)
+			} else if let Call::submit_record_unsigned { record } = call {
+				// Raw unsigned transactions can't prove which key they come from.
+				if !<Authorities<T, I>>::get().is_empty() {
+					return InvalidTransaction::BadSigner.into()
+				}
+				Self::validate_record(record)
+			} else if let Call::submit_record_unsigned_with_signed_payload { payload, signature } =
+				call
+			{
+				if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+					return InvalidTransaction::BadProof.into()
+				}
+				if !Self::is_authority(&payload.public.clone().into_account()) {
+					return InvalidTransaction::BadSigner.into()
+				}
+				Self::validate_record(&payload.record)
+			} else {
+				InvalidTransaction::Call.into()
+			}
+		}
+
+		/// Check an unsigned extrinsic of a block before it is dispatched, as `validate_unsigned`
+		/// does, except for the inherent setting the value, which is checked with
+		/// `check_inherent` instead and never enters the transaction pool.
+		fn pre_dispatch(call: &Self::Call) -> Result<(), TransactionValidityError> {
+			if let Call::set_inherent_value { .. } = call {
+				return Ok(())
+			}
+			Self::validate_unsigned(TransactionSource::InBlock, call).map(|_| ())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> ProvideOracleValue<T::AssetId, u64> for Pallet<T, I> {
+		type BlockNumber = BlockNumberFor<T>;
+
+		fn get(asset: &T::AssetId) -> Option<(u64, BlockNumberFor<T>)> {
+			<Prices<T, I>>::get(asset).map(|price| (price.value, price.updated_at))
+		}
+	}
+
+	#[cfg(any(feature = "try-runtime", test))]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Check the invariants of the pallet at the end of block `now` of
+		/// `T::BlockNumberProvider`:
+		///
+		/// - the bounded storage items hold at most as many items as their bounds allow,
+		/// - only the authorities submitted to the current round, and only while it isn't
+		///   finalized,
+		/// - the phase of the current round matches `now`,
+		/// - the deposits and bonds the pallet accounts for are reserved.
+		pub fn do_try_state(now: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let authorities = <Authorities<T, I>>::get();
+			ensure!(
+				<Authorities<T, I>>::decode_len().unwrap_or(0) <= T::MaxAuthorities::get() as usize,
+				"more than `MaxAuthorities` authorities"
+			);
+			ensure!(
+				<PriceHistory<T, I>>::decode_len().unwrap_or(0) <= T::MaxPrices::get() as usize,
+				"more than `MaxPrices` prices"
+			);
+			ensure!(
+				<Prices<T, I>>::iter_keys().count() <= T::MaxAssets::get() as usize,
+				"more than `MaxAssets` prices"
+			);
+			ensure!(
+				<OracleEndpoints<T, I>>::decode_len().unwrap_or(0) <=
+					T::MaxEndpoints::get() as usize,
+				"more than `MaxEndpoints` endpoints"
+			);
+			ensure!(<Topics<T, I>>::count() <= T::MaxTopics::get(), "more than `MaxTopics` topics");
+			ensure!(<Assets<T, I>>::count() <= T::MaxAssets::get(), "more than `MaxAssets` assets");
+			for (asset, who) in <AssetSubmissions<T, I>>::iter_keys() {
+				ensure!(<Assets<T, I>>::contains_key(asset), "submission for an unknown asset");
+				ensure!(
+					authorities.contains(&who),
+					"price submitted by an account which is no authority"
+				);
+			}
+
+			let round = <CurrentRound<T, I>>::get();
+			let mut submitters = 0;
+			for (index, who) in <Submissions<T, I>>::iter_keys() {
+				ensure!(index == round.index, "submission to a round other than the current one");
+				ensure!(
+					authorities.contains(&who),
+					"submission of an account which is no authority"
+				);
+				submitters += 1;
+			}
+			ensure!(submitters <= authorities.len(), "more submissions than authorities");
+			ensure!(
+				round.phase != RoundPhase::Finalized || submitters == 0,
+				"submissions to a finalized round"
+			);
+			ensure!(
+				<LatestRound<T, I>>::get().map_or(true, |(index, _)| index <= round.index),
+				"the latest finalized round is ahead of the current one"
+			);
+
+			let end = Self::round_end(round.started_at);
+			let closing_at = end.saturating_sub(T::ClosingPeriod::get());
+			ensure!(round.started_at <= now && now < end, "the current round doesn't contain now");
+			match round.phase {
+				RoundPhase::Open => ensure!(now < closing_at, "open round past its closing period"),
+				RoundPhase::Closing =>
+					ensure!(now >= closing_at, "closing round before its closing period"),
+				RoundPhase::Finalized => ensure!(
+					now.saturating_add(One::one()) >= end,
+					"round finalized before its last block"
+				),
+			}
+
+			let mut deposits = sp_std::collections::btree_map::BTreeMap::new();
+			let mut add = |who: T::AccountId, amount: BalanceOf<T, I>| {
+				let total: &mut BalanceOf<T, I> = deposits.entry(who).or_default();
+				*total = total.saturating_add(amount);
+			};
+			<SubmittedData<T, I>>::iter().for_each(|(who, entry)| add(who, entry.deposit));
+			<IndexDeposits<T, I>>::iter_values()
+				.flatten()
+				.for_each(|(who, deposit)| add(who, deposit));
+			<Commitments<T, I>>::iter().for_each(|(who, commitment)| add(who, commitment.deposit));
+			<Subscriptions<T, I>>::iter().for_each(|(_, who, deposit)| add(who, deposit));
+			<Disputes<T, I>>::iter_values().for_each(|dispute| add(dispute.who, dispute.bond));
+			for (who, info) in <Deposits<T, I>>::iter() {
+				ensure!(info.reclaimable <= info.reserved, "more reclaimable than reserved");
+				add(who, info.reclaimable);
+			}
+			for (who, amount) in &deposits {
+				ensure!(
+					<Deposits<T, I>>::get(who).reserved == *amount,
+					"deposits not accounted for in `Deposits`"
+				);
+			}
+
+			let fees = <Requests<T, I>>::iter_values()
+				.fold(BalanceOf::<T, I>::zero(), |total, request| {
+					total.saturating_add(request.fee)
+				});
+			ensure!(
+				T::Currency::free_balance(&Self::escrow_account()) >= fees,
+				"fees of the requests are not escrowed"
+			);
+
+			let mut reserved = deposits;
+			for (who, bond) in <Bonds<T, I>>::iter() {
+				ensure!(authorities.contains(&who), "bond of an account which is no authority");
+				let total = reserved.entry(who).or_default();
+				*total = total.saturating_add(bond);
+			}
+			for (who, amount) in reserved {
+				ensure!(
+					T::Currency::reserved_balance(&who) >= amount,
+					"deposits accounted for are not reserved"
+				);
+			}
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Report the authorities which sent no heartbeat in the last period, if a period starts at
+		/// `now`.
+		fn report_offline(now: BlockNumberFor<T>) -> Weight {
+			let period = T::HeartbeatPeriod::get();
+			if period.is_zero() || !(now % period).is_zero() || now < period {
+				return Weight::zero()
+			}
+			// The offchain workers don't run while the pallet is paused.
+			if <Paused<T, I>>::get() {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let authorities = <Authorities<T, I>>::get();
+			let window_start = now.saturating_sub(period);
+			for who in authorities.iter() {
+				if <LastSeen<T, I>>::get(who).map_or(true, |seen| seen < window_start) {
+					Self::deposit_event(Event::OffchainWorkerOffline { who: who.clone() });
+				}
+			}
+			T::DbWeight::get().reads(1 + authorities.len() as u64)
+		}
+
+		/// The block a commitment made at `committed_at` expires at, unless it is revealed
+		/// before. `None` if `T::RevealWindow` is zero.
+		pub fn commitment_expiry(committed_at: BlockNumberFor<T>) -> Option<BlockNumberFor<T>> {
+			let window = T::RevealWindow::get();
+			(!window.is_zero()).then(|| {
+				committed_at
+					.saturating_add(T::RevealDelay::get())
+					.saturating_add(window)
+					.saturating_add(One::one())
+			})
+		}
+
+		/// Remove the commitments expiring at `now`, slashing their deposit, or refunding it if
+		/// the pallet is paused.
+		fn expire_commitments(now: BlockNumberFor<T>) -> Weight {
+			let expiring = <CommitmentExpiries<T, I>>::take(now);
+			let paused = !expiring.is_empty() && <Paused<T, I>>::get();
+			for who in expiring.iter() {
+				// The commitment may have been revealed, and the authority committed again since.
+				let Some(commitment) = <Commitments<T, I>>::get(who) else { continue };
+				if Self::commitment_expiry(commitment.committed_at) != Some(now) {
+					continue
+				}
+				<Commitments<T, I>>::remove(who);
+				if paused {
+					Self::unreserve_deposit(who, commitment.deposit);
+					Self::deposit_event(Event::CommitmentExpired {
+						who: who.clone(),
+						slashed: Zero::zero(),
+					});
+					continue
+				}
+				let slashed = Self::slash_deposit(who, commitment.deposit);
+				Self::deposit_event(Event::CommitmentExpired { who: who.clone(), slashed });
+			}
+			// Per authority: its commitment, its deposits and its account. Then the pause flag.
+			let count = expiring.len() as u64;
+			T::DbWeight::get().reads_writes(2 + 3 * count, 1 + 3 * count)
+		}
+
+		/// Refund up to `max` of the requests of `RequestsByExpiry` at `at` which timed out by
+		/// `now`, emitting `RequestExpired`. The others are moved to the next block, or kept if
+		/// `at` is after `now`. Returns the number of requests looked at.
+		///
+		/// Requests fulfilled or refunded with `refund_request` since they were indexed are
+		/// dropped from the index.
+		pub(crate) fn expire_requests(
+			now: BlockNumberFor<T>,
+			at: BlockNumberFor<T>,
+			max: u32,
+		) -> u32 {
+			let ids = <RequestsByExpiry<T, I>>::take(at);
+			let mut left = Vec::new();
+			let mut expired = 0;
+			for id in ids.iter().copied() {
+				let Some(request) = <Requests<T, I>>::get(id) else { continue };
+				if expired >= max || !Self::is_expired(&request) {
+					left.push(id);
+					continue
+				}
+				<Requests<T, I>>::remove(id);
+				let refund = T::Currency::transfer(
+					&Self::escrow_account(),
+					&request.requester,
+					request.fee,
+					ExistenceRequirement::AllowDeath,
+				);
+				if let Err(e) = refund {
+					log::error!(target: LOG_TARGET, "failed to refund request {}: {:?}", id, e);
+				}
+				Self::deposit_event(Event::RequestExpired {
+					id,
+					who: request.requester,
+					fee: request.fee,
+				});
+				expired += 1;
+			}
+			if !left.is_empty() {
+				// At most `MaxPendingRequests` are pending, so they fit the index of any block.
+				<RequestsByExpiry<T, I>>::mutate(at.max(now.saturating_add(One::one())), |ids| {
+					for id in left {
+						if ids.try_push(id).is_err() {
+							log::error!(target: LOG_TARGET, "failed to index request {}", id);
+						}
+					}
+				});
+			}
+			ids.len() as u32
+		}
+
+		/// Refund the requests which timed out but were left over by `on_initialize`, spending
+		/// at most `remaining_weight`. Returns the weight used.
+		fn expire_left_over_requests(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let next = now.saturating_add(One::one());
+			let len = <RequestsByExpiry<T, I>>::decode_len(next).unwrap_or(0) as u32;
+			if len == 0 {
+				return T::DbWeight::get().reads(1)
+			}
+			let weight = T::WeightInfo::expire_requests(len);
+			if remaining_weight.any_lt(weight) {
+				return T::DbWeight::get().reads(1)
+			}
+			Self::expire_requests(now, next, T::MaxExpiriesPerBlock::get());
+			weight
+		}
+
+		/// Report the leader of the current round if its turn ended with the previous block
+		/// without a value submitted for the round.
+		fn time_out_leader(now: BlockNumberFor<T>) -> Weight {
+			let timeout = T::LeaderTimeout::get();
+			if timeout.is_zero() {
+				return Weight::zero()
+			}
+			let db = T::DbWeight::get();
+			let round = <CurrentRound<T, I>>::get();
+			let elapsed = now.saturating_sub(round.started_at);
+			if round.phase != RoundPhase::Open ||
+				elapsed.is_zero() ||
+				!(elapsed % timeout).is_zero()
+			{
+				return db.reads(1)
+			}
+			if Self::round_has_value(&round) {
+				return db.reads(3)
+			}
+			// The authorities, and the hash of the first block of the round for their order.
+			if let Some(who) = Self::leader(&round, now.saturating_sub(One::one())) {
+				Self::deposit_event(Event::LeaderTimedOut { round: round.index, who });
+			}
+			db.reads(5)
+		}
+
+		/// Whether a value was submitted since `round` started.
+		fn round_has_value(round: &RoundInfo<BlockNumberFor<T>>) -> bool {
+			<LatestValue<T, I>>::get().is_some() &&
+				<LatestValueUpdatedAt<T, I>>::get() >= round.started_at
+		}
+
+		/// The block after the last block of the round started at `started_at`.
+		fn round_end(started_at: BlockNumberFor<T>) -> BlockNumberFor<T> {
+			started_at.saturating_add(T::RoundLength::get().max(One::one()))
+		}
+
+		/// Start the next round once the current one is over, or close the current one once
+		/// its last `T::ClosingPeriod` blocks are reached.
+		fn advance_round(now: BlockNumberFor<T>) -> Weight {
+			let mut round = <CurrentRound<T, I>>::get();
+			let end = Self::round_end(round.started_at);
+			if now >= end {
+				let index = round.index.saturating_add(1);
+				round = RoundInfo {
+					index,
+					started_at: now,
+					phase: RoundPhase::Open,
+					seed: Self::round_seed(index),
+				};
+			}
+			let closing_at =
+				Self::round_end(round.started_at).saturating_sub(T::ClosingPeriod::get());
+			if round.phase == RoundPhase::Open && now >= closing_at {
+				round.phase = RoundPhase::Closing;
+			}
+			<CurrentRound<T, I>>::put(round);
+			// The current round and the parent hash seeding the next one.
+			T::DbWeight::get().reads_writes(2, 1)
+		}
+
+		/// Finalize the current round if `threshold` of its values agree, with
+		/// `FinalizationMode::Threshold`.
+		fn close_round_early() -> Weight {
+			let FinalizationMode::Threshold { threshold, tolerance } = T::FinalizationMode::get()
+			else {
+				return Weight::zero()
+			};
+			// The current round and its submissions.
+			let weight = T::DbWeight::get().reads(1 + T::MaxAuthorities::get() as u64);
+			let round = <CurrentRound<T, I>>::get();
+			if round.phase == RoundPhase::Finalized {
+				return weight
+			}
+			let values = <Submissions<T, I>>::iter_prefix_values(round.index).collect::<Vec<_>>();
+			let agreeing = logic::agreeing(&values, tolerance);
+			if agreeing < threshold.max(1) {
+				return weight
+			}
+			Self::deposit_event(Event::RoundClosedEarly { round: round.index, agreeing });
+			Self::finalize_round();
+			weight.saturating_add(Self::finalize_round_weight())
+		}
+
+		/// The weight of `finalize_round`.
+		fn finalize_round_weight() -> Weight {
+			let max = T::MaxAuthorities::get();
+			// Slashes aren't benchmarked: the authorities, then the bond, the account and the
+			// current round of each authority slashed, along with its submissions and its last
+			// failure report.
+			let slashes = T::DbWeight::get()
+				.reads_writes(3 * max as u64 + 1, 4 * max as u64 + 1)
+				.saturating_add(Self::drop_asset_submissions_weight().saturating_mul(max.into()));
+			T::WeightInfo::finalize_round(max, T::MaxAssets::get()).saturating_add(slashes)
+		}
+
+		/// Whether the current round, which isn't finalized yet, ends with `block_number`.
+		fn ends_round(block_number: BlockNumberFor<T>) -> bool {
+			let round = <CurrentRound<T, I>>::get();
+			round.phase != RoundPhase::Finalized &&
+				block_number.saturating_add(One::one()) >= Self::round_end(round.started_at)
+		}
+
+		/// Record `value` as the submission of `who` for the current round, if `who` is one of
+		/// the authorities. A later submission in the same round replaces the earlier one.
+		fn add_submission(who: &T::AccountId, value: u64) {
+			let round = <CurrentRound<T, I>>::get();
+			if round.phase != RoundPhase::Finalized && <Authorities<T, I>>::get().contains(who) {
+				<Submissions<T, I>>::insert(round.index, who, value);
+			}
+		}
+
+		/// Aggregate the submissions of the current round with the `AggregationStrategy` of
+		/// `effective_ocw_config`, and mark it finalized.
+		pub(crate) fn finalize_round() {
+			let strategy = Self::effective_ocw_config().aggregation;
+			let mut current = <CurrentRound<T, I>>::get();
+			current.phase = RoundPhase::Finalized;
+			<CurrentRound<T, I>>::put(&current);
+
+			let round = current.index;
+			let submissions = <Submissions<T, I>>::drain_prefix(round).collect::<Vec<_>>();
+			Self::anchor_snapshot(round, &submissions);
+			let mut values = submissions.iter().map(|(_, v)| *v).collect::<Vec<_>>();
+			if let Some(median) = logic::aggregate_with(&mut values, strategy) {
+				<LatestRound<T, I>>::put((round, median));
+				<LatestSubmissions<T, I>>::put(BoundedVec::truncate_from(submissions.clone()));
+				Self::observe_median(median);
+				let num_submissions = values.len() as u32;
+				Self::deposit_event(Event::RoundFinalized { round, median, num_submissions });
+				Self::track_reputation(round, median, submissions);
+			}
+			Self::finalize_asset_rounds(strategy);
+		}
+
+		/// Anchor the hash of the snapshot of the `submissions` of `round` in `Snapshots`, if it
+		/// is the last round of a `T::SnapshotPeriod`.
+		///
+		/// The snapshot is the JSON of `json::serialize_snapshot`, indexed into the offchain
+		/// database for `send_snapshot` to archive. Auditors check an archived snapshot against
+		/// the chain with `verify_snapshot`.
+		fn anchor_snapshot(round: RoundIndex, submissions: &[(T::AccountId, u64)]) {
+			let period = T::SnapshotPeriod::get();
+			if period == 0 || round.saturating_add(1) % period != 0 {
+				return
+			}
+			let submissions = submissions
+				.iter()
+				.map(|(who, value)| (http::to_hex(&who.encode()), *value))
+				.collect::<Vec<_>>();
+			let snapshot = json::serialize_snapshot(round.into(), &submissions);
+			let hash = T::Hashing::hash(&snapshot);
+			<Snapshots<T, I>>::insert(round, hash);
+			sp_io::offchain_index::set(&Self::snapshot_key(round), &snapshot.encode());
+
+			Self::deposit_event(Event::SnapshotAnchored { round, hash });
+		}
+
+		/// Whether `snapshot` is the snapshot of `round` anchored in `Snapshots`.
+		pub fn verify_snapshot(round: RoundIndex, snapshot: &[u8]) -> bool {
+			<Snapshots<T, I>>::get(round) == Some(T::Hashing::hash(snapshot))
+		}
+
+		/// The offchain database key of the snapshot of `round`, see `anchor_snapshot`.
+		pub fn snapshot_key(round: RoundIndex) -> Vec<u8> {
+			Self::local_key(&[SNAPSHOT_KEY, &round.encode()].concat())
+		}
+
+		/// Record `median` as the price from now on, for the time-weighted average.
+		///
+		/// The observations which fell out of `TwapWindow` are dropped, but the last one of
+		/// them, the price at the start of the window. The oldest observation is dropped if
+		/// there are `MaxTwapObservations` of them already.
+		fn observe_median(median: u64) {
+			let now = T::TimeProvider::now().as_millis().saturated_into::<u64>();
+			let start = now.saturating_sub(T::TwapWindow::get());
+			<TwapObservations<T, I>>::mutate(|observations| {
+				let expired =
+					observations.iter().skip(1).take_while(|(at, _)| *at <= start).count();
+				observations.drain(..expired);
+				if observations.is_full() {
+					observations.remove(0);
+				}
+				let _ = observations.try_push((now, median));
+			});
+		}
+
+		/// The time-weighted average of the medians of the rounds over the last `TwapWindow`,
+		/// see `logic::time_weighted_average`.
+		///
+		/// Unlike the median of a single round, it can't be moved much by a few rounds, so it is
+		/// the price to use where manipulations would pay off, e.g. for liquidations.
+		pub fn twap() -> Option<u64> {
+			let now = T::TimeProvider::now().as_millis().saturated_into::<u64>();
+			logic::time_weighted_average(
+				&<TwapObservations<T, I>>::get(),
+				now,
+				T::TwapWindow::get(),
+			)
+		}
+
+		/// Set the price of `asset` to `value` of `round`, updated now.
+		fn set_price(asset: T::AssetId, value: u64, round: RoundIndex) {
+			let now = <frame_system::Pallet<T>>::block_number();
+			<Prices<T, I>>::insert(asset, PriceInfo { value, round, updated_at: now });
+			let at = T::TimeProvider::now().as_millis().saturated_into::<u64>();
+			<PriceTimestamps<T, I>>::insert(asset, at);
+		}
+
+		/// Aggregate the prices submitted for each registered asset in the current round with
+		/// `strategy`, which becomes the price of the asset.
+		///
+		/// Assets without submissions keep their price, and their round index.
+		fn finalize_asset_rounds(strategy: AggregationStrategy) {
+			for asset in <Assets<T, I>>::iter_keys() {
+				let mut values = <AssetSubmissions<T, I>>::drain_prefix(asset)
+					.map(|(_, value)| value)
+					.collect::<Vec<_>>();
+				let Some(median) = logic::aggregate_with(&mut values, strategy) else { continue };
+				let previous = <Prices<T, I>>::get(asset);
+				let round = previous.as_ref().map_or(0, |info| info.round.saturating_add(1));
+				Self::set_price(asset, median, round);
+				T::OnNewValue::on_new_value(&asset, &median);
+
+				let num_submissions = values.len() as u32;
+				Self::deposit_event(Event::AssetRoundFinalized {
+					asset,
+					round,
+					median,
+					num_submissions,
+				});
+				if let Some(previous) = previous {
+					Self::note_change(asset, previous.value, median);
+				}
+			}
+		}
+
+		/// The threshold in basis points the price of `asset` must move by for
+		/// `Event::SignificantChange`, its `ChangeThresholds` or `T::ChangeThresholdBps`.
+		pub fn change_threshold(asset: T::AssetId) -> u32 {
+			<ChangeThresholds<T, I>>::get(asset).unwrap_or_else(T::ChangeThresholdBps::get)
+		}
+
+		/// Deposit `Event::SignificantChange` if the price of `asset` moved from `old` to `new`
+		/// by more than its threshold.
+		fn note_change(asset: T::AssetId, old: u64, new: u64) {
+			let delta_bps = logic::change_bps(old, new);
+			if delta_bps > Self::change_threshold(asset) {
+				Self::deposit_event(Event::SignificantChange { asset, old, new, delta_bps });
+			}
+		}
+
+		/// Update the reputation of the authorities which submitted in `round`, slashing and
+		/// removing those which deviated from `median` `T::MaxStrikes` rounds in a row.
+		fn track_reputation(round: RoundIndex, median: u64, submissions: Vec<(T::AccountId, u64)>) {
+			for (who, value) in submissions {
+				let deviation = Perbill::from_rational(value.abs_diff(median), median.max(1));
+				let deviated = deviation > T::MaxDeviation::get();
+				let strikes = <Reputation<T, I>>::mutate(&who, |reputation| {
+					reputation.rounds = reputation.rounds.saturating_add(1);
+					reputation.strikes =
+						if deviated { reputation.strikes.saturating_add(1) } else { 0 };
+					reputation.strikes
+				});
+				if !deviated {
+					continue
+				}
+
+				Self::deposit_event(Event::SubmissionDeviated {
+					who: who.clone(),
+					round,
+					deviation,
+				});
+				if strikes >= T::MaxStrikes::get() {
+					Self::slash_authority(who);
+				}
+			}
+		}
+
+		/// Drop the prices submitted by `who` to the current round, as it leaves the authorities.
+		pub(crate) fn drop_asset_submissions(who: &T::AccountId) {
+			for asset in <Assets<T, I>>::iter_keys() {
+				<AssetSubmissions<T, I>>::remove(asset, who);
+			}
+		}
+
+		/// The weight of `drop_asset_submissions` with `T::MaxAssets` registered assets.
+		pub(crate) fn drop_asset_submissions_weight() -> Weight {
+			let max = T::MaxAssets::get() as u64;
+			T::DbWeight::get().reads_writes(max, max)
+		}
+
+		/// Slash the bond of `who` and remove it from the authorities.
+		///
+		/// Its submissions to the current round, if any, are dropped.
+		fn slash_authority(who: T::AccountId) {
+			<Authorities<T, I>>::mutate(|authorities| {
+				authorities.retain(|authority| authority != &who)
+			});
+			<Submissions<T, I>>::remove(<CurrentRound<T, I>>::get().index, &who);
+			Self::drop_asset_submissions(&who);
+			<Reputation<T, I>>::remove(&who);
+			<LastFailureReports<T, I>>::remove(&who);
+			let bond = <Bonds<T, I>>::take(&who);
+			let (_, unslashed) = T::Currency::slash_reserved(&who, bond);
+			let amount = bond.saturating_sub(unslashed);
+
+			Self::deposit_event(Event::AuthoritySlashed { who: who.clone(), amount });
+			Self::deposit_event(Event::AuthorityRemoved { who });
+		}
+
+		/// The current block number of `T::BlockNumberProvider`, which the rounds and the keys of
+		/// the indexed entries are based on.
+		pub fn current_block() -> BlockNumberFor<T> {
+			T::BlockNumberProvider::current_block_number()
+		}
+
+		/// Whether `who` may submit data, see `Authorities`.
+		pub fn is_authority(who: &T::AccountId) -> bool {
+			let authorities = <Authorities<T, I>>::get();
+			authorities.is_empty() || authorities.contains(who)
+		}
+
+		/// The prefix of the offchain database keys of this instance of the pallet.
+		///
+		/// It is empty for the default instance, so that its keys are kept across the upgrade
+		/// to an instantiable pallet, and the name of the instance in the runtime for the other
+		/// instances, so that the workers of independent feeds don't share their state.
+		pub fn key_scope() -> Vec<u8> {
+			if TypeId::of::<I>() == TypeId::of::<()>() {
+				return Vec::new()
+			}
+			[<Self as PalletInfoAccess>::name().as_bytes(), b"::"].concat()
+		}
+
+		/// The offchain local storage `key` of this instance of the pallet, see `key_scope`.
+		pub fn local_key(key: &[u8]) -> Vec<u8> {
+			logic::build_key(&Self::key_scope(), key)
+		}
+
+		/// The prefix of the offchain database keys the tasks of `kind` queued at `block_number`
+		/// are stored under.
+		pub fn derived_key(kind: KeyKind, block_number: BlockNumberFor<T>) -> Vec<u8> {
+			KeyDerivation::block_prefix(&Self::key_scope(), kind, &block_number)
+		}
+
+		/// The offchain database key of the task of `kind` queued at `index` in `block_number`.
+		pub fn task_key(kind: KeyKind, block_number: BlockNumberFor<T>, index: u32) -> Vec<u8> {
+			KeyDerivation { kind, block_number, index }.key(&Self::key_scope())
+		}
+
+		/// The block and the queue index of the task stored under the offchain database `key`,
+		/// if it is one of the keys derived by this pallet.
+		pub fn decode_key(key: &[u8]) -> Option<KeyDerivation<BlockNumberFor<T>>> {
+			keys::decode_key(&Self::key_scope(), key)
+		}
+
+		/// The offchain database keys of the tasks queued from block `from` to block `to`, both
+		/// included, going by `IndexedKeys`.
+		pub fn indexed_keys(from: BlockNumberFor<T>, to: BlockNumberFor<T>) -> Vec<Vec<u8>> {
+			let mut keys = Vec::new();
+			let mut block_number = from;
+			while block_number <= to {
+				for (index, kind) in <IndexedKeys<T, I>>::get(block_number).into_iter().enumerate()
+				{
+					keys.push(Self::task_key(kind, block_number, index as u32));
+				}
+				for index in 0..<BatchCommitments<T, I>>::decode_len(block_number).unwrap_or(0) {
+					keys.push(Self::task_key(KeyKind::BatchEntry, block_number, index as u32));
+				}
+				if block_number == to {
+					break
+				}
+				block_number = block_number.saturating_add(One::one());
+			}
+			keys
+		}
+
+		/// Index `task` into the offchain database as the latest `IndexedTask` and commit its
+		/// hash on chain, reserving `T::IndexingDeposit` from `who`.
+		///
+		/// The commitment is of the task itself, whatever the version of its indexed layout.
+		fn queue(who: &T::AccountId, task: Task) -> DispatchResult {
+			let block_number = Self::current_block();
+			let hash = T::Hashing::hash_of(&task);
+
+			let index = <IndexCommitments<T, I>>::try_mutate(block_number, |commitments| {
+				ensure!(!commitments.contains(&hash), Error::<T, I>::DuplicateTask);
+				commitments.try_push(hash).map_err(|_| Error::<T, I>::TooManyIndexedEntries)?;
+				Ok::<_, DispatchError>(commitments.len() as u32 - 1)
+			})?;
+			let kind = KeyKind::from(&task);
+			<IndexedKeys<T, I>>::try_mutate(block_number, |kinds| {
+				kinds.try_push(kind).map_err(|_| Error::<T, I>::TooManyIndexedEntries)
+			})?;
+			let deposit = T::IndexingDeposit::get();
+			Self::reserve_deposit(who, deposit)?;
+			<IndexDeposits<T, I>>::try_mutate(block_number, |deposits| {
+				deposits
+					.try_push((who.clone(), deposit))
+					.map_err(|_| Error::<T, I>::TooManyIndexedEntries)
+			})?;
+			let key = Self::task_key(kind, block_number, index);
+			let indexed = IndexedTask::V2 { task, queued_by: who.clone() };
+			sp_io::offchain_index::set(&key, &indexed.encode());
+
+			let key_hash = T::Hashing::hash(&key);
+			Self::deposit_event(Event::OffchainDataIndexed { block: block_number, key_hash });
+			Ok(())
+		}
+
+		/// The account the fees of the pending requests are escrowed in, derived from
+		/// `T::PalletId`.
+		pub fn escrow_account() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Dispatch `callback`, the callback of the request `id` of `who`, with `answer`
+		/// appended, as `who`. Returns the weight it used.
+		///
+		/// A callback which no longer decodes, or became heavier than `T::MaxCallbackWeight`,
+		/// e.g. after a runtime upgrade, isn't dispatched.
+		fn dispatch_callback(
+			id: RequestId,
+			who: T::AccountId,
+			callback: &[u8],
+			answer: u64,
+		) -> Weight {
+			let Some(call) = <T as Config<I>>::RuntimeCall::with_answer(callback, id, answer)
+			else {
+				let error = Error::<T, I>::InvalidCallback.into();
+				Self::deposit_event(Event::CallbackFailed { id, error });
+				return Weight::zero()
+			};
+			let info = call.get_dispatch_info();
+			if info.weight.any_gt(T::MaxCallbackWeight::get()) {
+				let error = Error::<T, I>::CallbackTooHeavy.into();
+				Self::deposit_event(Event::CallbackFailed { id, error });
+				return Weight::zero()
+			}
+			let result = call.dispatch(frame_system::RawOrigin::Signed(who).into());
+			match result {
+				Ok(_) => Self::deposit_event(Event::CallbackDispatched { id }),
+				Err(e) => Self::deposit_event(Event::CallbackFailed { id, error: e.error }),
+			}
+			extract_actual_weight(&result, &info)
+		}
+
+		/// The block `request` times out at, see `Config::RequestTtl`.
+		fn request_expiry(request: &PendingRequestOf<T, I>) -> BlockNumberFor<T> {
+			request.requested_at.saturating_add(T::RequestTtl::get())
+		}
+
+		/// Whether `request` timed out, see `Config::RequestTtl`.
+		fn is_expired(request: &PendingRequestOf<T, I>) -> bool {
+			Self::request_expiry(request) <= <frame_system::Pallet<T>>::block_number()
+		}
+
+		/// Reserve the deposit `amount` from `who`, see `Deposits`.
+		fn reserve_deposit(who: &T::AccountId, amount: BalanceOf<T, I>) -> DispatchResult {
+			T::Currency::reserve(who, amount)?;
+			Self::mutate_deposits(who, |info| info.reserved = info.reserved.saturating_add(amount));
+			Ok(())
+		}
+
+		/// Unreserve the deposit `amount` of `who`.
+		fn unreserve_deposit(who: &T::AccountId, amount: BalanceOf<T, I>) {
+			T::Currency::unreserve(who, amount);
+			Self::forget_deposit(who, amount);
+		}
+
+		/// Slash the deposit `amount` of `who`. Returns the amount slashed.
+		fn slash_deposit(who: &T::AccountId, amount: BalanceOf<T, I>) -> BalanceOf<T, I> {
+			let (_, unslashed) = T::Currency::slash_reserved(who, amount);
+			Self::forget_deposit(who, amount);
+			amount.saturating_sub(unslashed)
+		}
+
+		/// Stop accounting for the deposit `amount` of `who`, which the pallet no longer holds.
+		fn forget_deposit(who: &T::AccountId, amount: BalanceOf<T, I>) {
+			Self::mutate_deposits(who, |info| {
+				info.reserved = info.reserved.saturating_sub(amount);
+				info.reclaimable = info.reclaimable.min(info.reserved);
+			});
+		}
+
+		/// Apply `f` to the deposits of `who`, removing them once none is reserved.
+		fn mutate_deposits(who: &T::AccountId, f: impl FnOnce(&mut DepositInfo<BalanceOf<T, I>>)) {
+			<Deposits<T, I>>::mutate_exists(who, |maybe_info| {
+				let mut info = maybe_info.take().unwrap_or_default();
+				f(&mut info);
+				*maybe_info = (!info.reserved.is_zero()).then_some(info);
+			});
+		}
+
+		/// Schedule `task` for the offchain worker of block `at`, see `ScheduledTasks`.
+		fn schedule(at: BlockNumberFor<T>, task: Task) -> DispatchResult {
+			<ScheduledTasks<T, I>>::try_mutate(at, |tasks| {
+				tasks.try_push(task.clone()).map_err(|_| Error::<T, I>::TooManyIndexedEntries)
+			})?;
+
+			Self::deposit_event(Event::TaskScheduled { at, task });
+			Ok(())
+		}
+
+		/// The number of calls indexing data into the offchain database `who` dispatched in the
+		/// current block, see `CheckOcwRateLimit`.
+		pub fn ocw_calls(who: &T::AccountId) -> u32 {
+			let (block_number, count) = <OcwCalls<T, I>>::get(who);
+			if block_number == <frame_system::Pallet<T>>::block_number() {
+				count
+			} else {
+				0
+			}
+		}
+
+		/// Count a call indexing data into the offchain database dispatched by `who` in the
+		/// current block.
+		pub(crate) fn note_ocw_call(who: &T::AccountId) {
+			let count = Self::ocw_calls(who).saturating_add(1);
+			<OcwCalls<T, I>>::insert(who, (<frame_system::Pallet<T>>::block_number(), count));
+		}
+
+		/// The moving average over the recent prices in `PriceHistory`.
+		pub fn average_price() -> Option<u64> {
+			let prices = <PriceHistory<T, I>>::get();
+			if prices.is_empty() {
+				return None
+			}
+			let sum = prices.iter().map(|price| *price as u128).sum::<u128>();
+			Some((sum / prices.len() as u128) as u64)
+		}
+
+		/// Append `value` to the values stored in the current block.
+		fn store_value(value: u64) -> DispatchResult {
+			let now = <frame_system::Pallet<T>>::block_number();
+			<ValuesByBlock<T, I>>::try_mutate(now, |values| {
+				values.try_push(value).map_err(|_| Error::<T, I>::TooManyValues)
+			})?;
+			match <LatestValuesAt<T, I>>::get() {
+				Some(latest) if latest == now => {},
+				latest => {
+					if let Some(previous) = latest {
+						<PreviousValuesAt<T, I>>::insert(now, previous);
+					}
+					<LatestValuesAt<T, I>>::put(now);
+				},
+			}
+			Ok(())
+		}
+
+		/// The latest `count` values stored with `do_something`, newest first, along with the
+		/// block they were stored in.
+		///
+		/// This walks back the blocks with values, one read per block, and is meant for RPC
+		/// consumers rather than for dispatchables.
+		pub fn latest_n(count: u32) -> Vec<(BlockNumberFor<T>, u64)> {
+			let count = count as usize;
+			let mut latest = Vec::new();
+			let mut at = <LatestValuesAt<T, I>>::get();
+			while let Some(block_number) = at {
+				if latest.len() >= count {
+					break
+				}
+				let values = <ValuesByBlock<T, I>>::get(block_number);
+				latest.extend(
+					values.iter().rev().take(count - latest.len()).map(|v| (block_number, *v)),
+				);
+				at = <PreviousValuesAt<T, I>>::get(block_number);
+			}
+			latest
+		}
+
+		/// The blocks with queued tasks the offchain worker didn't confirm yet, along with the
+		/// number of their tasks, oldest first.
+		///
+		/// This iterates over all of `IndexDeposits` and is meant for RPC consumers rather than for
+		/// dispatchables.
+		pub fn pending_tasks() -> Vec<(BlockNumberFor<T>, u32)> {
+			let mut pending = <IndexDeposits<T, I>>::iter()
+				.map(|(block_number, deposits)| (block_number, deposits.len() as u32))
+				.collect::<Vec<_>>();
+			pending.sort_unstable_by_key(|(block_number, _)| *block_number);
+			pending
+		}
+
+		/// The configuration of the offchain worker, see `OcwConfig`.
+		pub fn effective_ocw_config() -> OcwConfigOf<T, I> {
+			<OcwConfig<T, I>>::get().unwrap_or_else(Self::runtime_ocw_config)
+		}
+
+		/// The behavior of the offchain worker configured in the runtime, with no limit on the
+		/// size of a response.
+		pub fn runtime_ocw_config() -> OcwConfigOf<T, I> {
+			OcwConfigInfo {
+				endpoint: BoundedVec::default(),
+				fetch_interval: T::UnsignedInterval::get(),
+				max_payload_size: u32::MAX,
+				max_retries: T::MaxRetries::get(),
+				format: ResponseFormat::Json,
+				aggregation: AggregationStrategy::Median,
+			}
+		}
+
+		/// Record a newly submitted price, dropping the oldest one if the buffer is full.
+		///
+		/// This also postpones the next run of the offchain worker by the fetch interval of
+		/// `effective_ocw_config`.
+		fn add_price(price: u64) {
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			let interval = Self::effective_ocw_config().fetch_interval;
+			<NextUnsignedAt<T, I>>::put(current_block.saturating_add(interval));
+			<LatestValue<T, I>>::put(price);
+			<LatestValueUpdatedAt<T, I>>::put(Self::current_block());
+			<PriceHistory<T, I>>::mutate(|prices| {
+				if let Err(price) = prices.try_push(price) {
+					if !prices.is_empty() {
+						prices.remove(0);
+						let _ = prices.try_push(price);
+					}
+				}
+			});
+		}
+
+		/// Run the `WorkerStage`s of the offchain worker of `block_number` in order, starting
+		/// with the one the previous run ran out of time before.
+		///
+		/// Once `T::WorkerBudgetMs` is exhausted, the remaining stages are left to the next
+		/// run, which runs the stages it skips once they are done. This keeps the later stages
+		/// from being starved by slow endpoints, and the run from being killed in the middle of
+		/// a stage.
+		fn run_stages(block_number: BlockNumberFor<T>) {
+			let budget = budget::Budget::start(T::WorkerBudgetMs::get());
+			let key = Self::local_key(STAGE_KEY);
+			let mut next = StorageValueRef::persistent(&key);
+			let first = next.get::<WorkerStage>().ok().flatten().unwrap_or(WorkerStage::Heartbeats);
+			let start = WorkerStage::ALL.iter().position(|stage| *stage == first).unwrap_or(0);
+			let count = WorkerStage::ALL.len();
+			for i in 0..count {
+				let stage = WorkerStage::ALL[(start + i) % count];
+				if i > 0 && budget.is_exhausted() {
+					next.set(&stage);
+					log::warn!(
+						target: logging::SUBMIT,
+						"{} out of time, the next run starts with {:?}",
+						Self::log_fields(Some(&key)),
+						stage
+					);
+					return
+				}
+				Self::run_stage(stage, block_number);
+			}
+			next.clear();
+		}
+
+		/// Run `stage` of the offchain worker of `block_number`.
+		fn run_stage(stage: WorkerStage, block_number: BlockNumberFor<T>) {
+			match stage {
+				WorkerStage::Heartbeats => Self::send_heartbeats(block_number),
+				WorkerStage::Pipeline => Self::run_pipeline(block_number),
+				WorkerStage::CommitReveal => Self::commit_reveal(block_number),
+				WorkerStage::Report => Self::send_report(),
+				WorkerStage::Archive => Self::send_snapshot(),
+				WorkerStage::Topics => Self::send_topic_updates(block_number),
+				WorkerStage::AssetPrices => Self::send_asset_prices(),
+				WorkerStage::Feed => Self::follow_feed(block_number),
+				WorkerStage::Documents => Self::send_document_anchors(block_number),
+				WorkerStage::Requests => Self::send_request_fulfillments(block_number),
+				WorkerStage::GarbageCollection => Self::collect_garbage(Self::current_block()),
+			}
+		}
+
+		/// Run the tasks queued in `block_number`, then, every fetch interval of `OcwConfig`,
+		/// fetch the current price and submit it back on chain, see `choose_transaction_type`.
+		///
+		/// A value queued with `Task::SubmitValue` takes precedence over the fetched price, so
+		/// that a single value is submitted per run. Values queued while the worker waits for
+		/// its next run are dropped. Nothing is submitted unless the current round is open.
+		///
+		/// The fetched price goes through the `PipelineState`s, and a run resumes after the
+		/// last one a previous run of the same round and interval reached.
+		fn run_pipeline(block_number: BlockNumberFor<T>) {
+			let started_at = sp_io::offchain::timestamp();
+			for (label, value) in Self::read_batch(Self::current_block()) {
+				log::info!(
+					target: logging::STORAGE,
+					"{} batch entry {}: {}",
+					Self::log_fields(None),
+					sp_std::str::from_utf8(&label).unwrap_or("<invalid utf-8>"),
+					value
+				);
+			}
+			let queued_value = Self::run_tasks(block_number);
+
+			let phase = <CurrentRound<T, I>>::get().phase;
+			if phase != RoundPhase::Open {
+				log::debug!(
+					target: logging::SUBMIT,
+					"{} skipping submission, the round is {:?}",
+					Self::log_fields(None),
+					phase
+				);
+				return
+			}
+
+			let next_unsigned_at = <NextUnsignedAt<T, I>>::get();
+			if next_unsigned_at > block_number {
+				log::debug!(
+					target: logging::SUBMIT,
+					"{} skipping submission, next run at {:?}",
+					Self::log_fields(None),
+					next_unsigned_at
+				);
+				return
+			}
+
+			let key = Self::local_key(RETRY_KEY);
+			let mut retry = StorageValueRef::persistent(&key);
+			let state =
+				retry.get::<RetryState<BlockNumberFor<T>>>().ok().flatten().unwrap_or_default();
+			if state.next_retry_at > block_number {
+				log::debug!(
+					target: logging::SUBMIT,
+					"{} skipping submission, backing off until {:?}",
+					Self::log_fields(None),
+					state.next_retry_at
+				);
+				return
+			}
+
+			let resumed = Self::pipeline_state();
+			if queued_value.is_none() {
+				if !Self::is_fetcher() {
+					log::debug!(
+						target: logging::SUBMIT,
+						"{} skipping submission, no local key was elected to fetch",
+						Self::log_fields(None)
+					);
+					return
+				}
+				if !Self::is_leader(Self::current_block()) {
+					log::debug!(
+						target: logging::SUBMIT,
+						"{} skipping submission, no local key leads the round",
+						Self::log_fields(None)
+					);
+					return
+				}
+				if !matches!(resumed, Some(PipelineState::Fetched(_) | PipelineState::Parsed(_))) {
+					Self::wait_jitter(block_number);
+				}
+			}
+
+			let value = match queued_value {
+				Some(value) => Some(Ok(value)),
+				None => match resumed {
+					Some(PipelineState::Parsed(price)) => {
+						log::info!(
+							target: logging::SUBMIT,
+							"{} resuming with the checked price: {}",
+							Self::log_fields(None),
+							price
+						);
+						Some(Ok(price))
+					},
+					Some(PipelineState::Fetched(price)) => {
+						log::info!(
+							target: logging::PARSE,
+							"{} resuming with the fetched price: {}",
+							Self::log_fields(None),
+							price
+						);
+						Self::check_fetched(block_number, price)
+					},
+					_ => Self::fetch_checked(block_number),
+				},
+			};
+			let Some(value) = value else { return };
+
+			let result = value.and_then(|value| {
+				Self::submit_value(block_number, value).map_err(|e| {
+					log::error!(
+						target: logging::SUBMIT,
+						"{} offchain worker error: {}",
+						Self::log_fields(None),
+						e
+					);
+					FailureReason::Submit
+				})
+			});
+			if result.is_ok() {
+				Self::save_pipeline_state(PipelineState::Submitted);
+				Self::update_metrics(|metrics| {
+					metrics.submissions.saturating_inc();
+					metrics.submission_latency_ms =
+						sp_io::offchain::timestamp().diff(&started_at).millis();
+				});
+			}
+			match result {
+				Ok(()) if state.attempts > 0 => retry.clear(),
+				Ok(()) => (),
+				Err(reason) =>
+					Self::record_failure(block_number, state.attempts.saturating_add(1), reason),
+			}
+		}
+
+		/// Fetch the price for the pipeline run of `block_number` and check it, see
+		/// `check_fetched`.
+		///
+		/// `None` if the price was dropped as an anomaly, `Some(Err(_))` if it couldn't be
+		/// fetched, which counts as a failure of the run.
+		fn fetch_checked(block_number: BlockNumberFor<T>) -> Option<Result<u64, FailureReason>> {
+			match Self::fetch_and_store() {
+				Ok(price) => {
+					log::info!(
+						target: logging::FETCH,
+						"{} fetched price: {}",
+						Self::log_fields(None),
+						price
+					);
+					Self::save_pipeline_state(PipelineState::Fetched(price));
+					Self::check_fetched(block_number, price)
+				},
+				Err(e) => {
+					log::warn!(
+						target: logging::failure_target(&e),
+						"{} failed to fetch price: {:?}",
+						Self::log_fields(None),
+						e
+					);
+					Some(Err(FailureReason::Fetch(e)))
+				},
+			}
+		}
+
+		/// Check the `price` fetched for the pipeline run of `block_number` with `check_value`.
+		///
+		/// `None` if it is dropped as an anomaly, in which case the next run fetches the price
+		/// again.
+		fn check_fetched(
+			block_number: BlockNumberFor<T>,
+			price: u64,
+		) -> Option<Result<u64, FailureReason>> {
+			if let Err(reason) = Self::check_value(price) {
+				log::warn!(
+					target: logging::PARSE,
+					"{} dropping price {}: {:?}",
+					Self::log_fields(None),
+					price,
+					reason
+				);
+				Self::record_anomaly(Anomaly { block_number, value: price, reason });
+				Self::clear_pipeline_state();
+				return None
+			}
+			Self::save_pipeline_state(PipelineState::Parsed(price));
+			Some(Ok(price))
+		}
+
+		/// The `PipelineState` a previous run of the pipeline reached with the price of the
+		/// current round, unless `NextUnsignedAt` changed since.
+		///
+		/// Can only be called from the offchain worker context.
+		pub fn pipeline_state() -> Option<PipelineState> {
+			let key = Self::local_key(PIPELINE_KEY);
+			let saved = StorageValueRef::persistent(&key)
+				.get::<(RoundIndex, BlockNumberFor<T>, PipelineState)>()
+				.ok()
+				.flatten()?;
+			let (round, due_at, state) = saved;
+			(round == <CurrentRound<T, I>>::get().index && due_at == <NextUnsignedAt<T, I>>::get())
+				.then_some(state)
+		}
+
+		/// Save the `state` the pipeline reached with the price of the current round.
+		fn save_pipeline_state(state: PipelineState) {
+			let saved = (<CurrentRound<T, I>>::get().index, <NextUnsignedAt<T, I>>::get(), state);
+			StorageValueRef::persistent(&Self::local_key(PIPELINE_KEY)).set(&saved);
+		}
+
+		/// Forget the `PipelineState` of the price of the current round.
+		fn clear_pipeline_state() {
+			StorageValueRef::persistent(&Self::local_key(PIPELINE_KEY)).clear();
+		}
+
+		/// The `logging::Fields` of a message about `key`, in the current round and block.
+		pub(crate) fn log_fields(key: Option<&[u8]>) -> logging::Fields<'_, BlockNumberFor<T>> {
+			logging::Fields {
+				round: <CurrentRound<T, I>>::get().index,
+				block: <frame_system::Pallet<T>>::block_number(),
+				key,
+			}
+		}
+
+		/// Fail with `StalePrice` unless `asset` has a price updated within the last `max_age`
+		/// blocks, so that consumer pallets can refuse to act on outdated values.
+		pub fn ensure_fresh(asset: &T::AssetId, max_age: BlockNumberFor<T>) -> DispatchResult {
+			let price = <Prices<T, I>>::get(asset).ok_or(Error::<T, I>::StalePrice)?;
+			let age = <frame_system::Pallet<T>>::block_number().saturating_sub(price.updated_at);
+			ensure!(age <= max_age, Error::<T, I>::StalePrice);
+			Ok(())
+		}
+
+		/// Emit `ValueStaleWarning` for the prices of the assets which became stale in block
+		/// `now`, see `Config::StalenessThreshold`. Each stale price is warned about once.
+		fn warn_stale_prices(now: BlockNumberFor<T>) -> Weight {
+			let threshold = T::StalenessThreshold::get();
+			if threshold.is_zero() {
+				return Weight::zero()
+			}
+			let mut reads = 0u64;
+			for (asset, price) in <Prices<T, I>>::iter().take(T::MaxAssets::get() as usize) {
+				reads.saturating_inc();
+				if now.saturating_sub(price.updated_at) == threshold {
+					Self::deposit_event(Event::ValueStaleWarning {
+						asset,
+						updated_at: price.updated_at,
+					});
+				}
+			}
+			T::DbWeight::get().reads(reads)
+		}
+
+		/// Fail with `FeedPaused` if the pallet is paused, see `pause`.
+		fn ensure_not_paused() -> DispatchResult {
+			ensure!(!<Paused<T, I>>::get(), Error::<T, I>::FeedPaused);
+			Ok(())
+		}
+
+		/// Accept `record` as the latest record of the feed, if it follows the `feed_cursor`.
+		fn add_record(record: FeedRecord) -> DispatchResult {
+			Self::ensure_not_paused()?;
+			ensure!(
+				record.since == Self::feed_cursor() && record.id > record.since,
+				Error::<T, I>::OutOfOrderRecord
+			);
+
+			<LatestRecord<T, I>>::put((record.id, record.value));
+
+			Self::deposit_event(Event::RecordSubmitted { id: record.id, value: record.value });
+			Ok(())
+		}
+
+		/// Check `value`, to be set with an inherent: the instance must accept values from
+		/// inherents and not be paused, and `value` must be within `T::MaxDeviation` of the
+		/// median of the latest finalized round.
+		pub fn check_inherent_value(value: u64) -> Result<(), InherentError> {
+			if !T::AcceptInherentValues::get() {
+				return Err(InherentError::Disabled)
+			}
+			if <Paused<T, I>>::get() {
+				return Err(InherentError::Paused)
+			}
+			let (_, median) = <LatestRound<T, I>>::get().ok_or(InherentError::NoMedian)?;
+			let deviation = Perbill::from_rational(value.abs_diff(median), median.max(1));
+			if deviation > T::MaxDeviation::get() {
+				return Err(InherentError::TooFarFromMedian)
+			}
+			Ok(())
+		}
+
+		/// Whether the operator of this node disabled its offchain worker, see `DISABLED_KEY`.
+		///
+		/// Can only be called from the offchain worker context.
+		pub fn is_disabled() -> bool {
+			let key = Self::local_key(DISABLED_KEY);
+			sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, &key).is_some()
+		}
+
+		/// Log what the worker of `block_number` would fetch and submit, without doing it.
+		fn log_dry_run(block_number: BlockNumberFor<T>) {
+			let round = <CurrentRound<T, I>>::get();
+			let next_unsigned_at = <NextUnsignedAt<T, I>>::get();
+			if round.phase != RoundPhase::Open || next_unsigned_at > block_number {
+				log::info!(
+					target: logging::SUBMIT,
+					"{} dry run: would skip the price, the round is {:?}, next run at {:?}",
+					Self::log_fields(None),
+					round.phase,
+					next_unsigned_at
+				);
+			} else if !Self::is_fetcher() || !Self::is_leader(Self::current_block()) {
+				log::info!(
+					target: logging::SUBMIT,
+					"{} dry run: would skip the price, no local key fetches it",
+					Self::log_fields(None)
+				);
+			} else {
+				log::info!(
+					target: logging::SUBMIT,
+					"{} dry run: would fetch the price and submit it with {:?}",
+					Self::log_fields(None),
+					Self::choose_transaction_type(block_number)
+				);
+			}
+			for (asset, info) in <Assets<T, I>>::iter().take(T::MaxAssets::get() as usize) {
+				match Self::asset_url(&info) {
+					Ok(url) => log::info!(
+						target: logging::FETCH,
+						"{} dry run: would fetch the price of {:?} from {}",
+						Self::log_fields(None),
+						asset,
+						sp_std::str::from_utf8(&url).unwrap_or("<invalid utf-8>")
+					),
+					Err(e) => log::warn!(
+						target: logging::FETCH,
+						"{} invalid URL of {:?}: {:?}",
+						Self::log_fields(None),
+						asset,
+						e
+					),
+				}
+			}
+		}
+
+		/// After how many blocks the pipeline is retried after its `attempts`th failure in a
+		/// row: 1, 2, 4, 8, ... blocks.
+		pub fn retry_delay(attempts: u32) -> BlockNumberFor<T> {
+			let delay = 1u32.checked_shl(attempts.saturating_sub(1)).unwrap_or(u32::MAX);
+			delay.saturated_into()
+		}
+
+		/// Record and report the `attempts`th failure in a row of the pipeline run of
+		/// `block_number` and back off, or give up and report it once the retries of `OcwConfig`
+		/// failed.
+		fn record_failure(block_number: BlockNumberFor<T>, attempts: u32, reason: FailureReason) {
+			if let Err(e) = Self::report_submission_failure_tx(reason) {
+				log::error!(
+					target: logging::SUBMIT,
+					"{} failed to report failure: {}",
+					Self::log_fields(None),
+					e
+				);
+			}
+
+			let key = Self::local_key(RETRY_KEY);
+			let mut retry = StorageValueRef::persistent(&key);
+			if attempts <= Self::effective_ocw_config().max_retries {
+				let next_retry_at = block_number.saturating_add(Self::retry_delay(attempts));
+				retry.set(&RetryState { attempts, next_retry_at });
+				return
+			}
+
+			log::error!(
+				target: logging::SUBMIT,
+				"{} giving up after {} attempts",
+				Self::log_fields(None),
+				attempts
+			);
+			retry.clear();
+			if let Err(e) = Self::report_retries_exhausted_tx(attempts) {
+				log::error!(
+					target: logging::SUBMIT,
+					"{} failed to report exhausted retries: {}",
+					Self::log_fields(None),
+					e
+				);
+			}
+		}
+
+		/// Whether `encoded` is the task queued at `index` in `block_number`, going by the
+		/// on-chain commitments.
+		pub fn is_committed(block_number: BlockNumberFor<T>, index: u32, encoded: &[u8]) -> bool {
+			<IndexCommitments<T, I>>::get(block_number).get(index as usize) ==
+				Some(&T::Hashing::hash(encoded))
+		}
+
+		/// Remove the commitments and the indexed keys of the blocks which fell out of
+		/// `T::CommitmentRetention`, spending at most `remaining_weight`. Returns the weight used.
+		///
+		/// The deposits of the tasks of these blocks which weren't confirmed become reclaimable,
+		/// see `reclaim_deposit`.
+		pub(crate) fn prune_commitments(
+			now: BlockNumberFor<T>,
+			remaining_weight: Weight,
+		) -> Weight {
+			let mut consumed = T::WeightInfo::prune_commitments(0);
+			let per_block = T::WeightInfo::prune_commitments(1).saturating_sub(consumed);
+			if remaining_weight.any_lt(consumed) {
+				return Weight::zero()
+			}
+
+			let up_to = now.saturating_sub(T::CommitmentRetention::get());
+			let mut cursor = <CommitmentsCursor<T, I>>::get();
+			if cursor >= up_to {
+				return T::DbWeight::get().reads(1)
+			}
+			while cursor < up_to && remaining_weight.all_gte(consumed.saturating_add(per_block)) {
+				<IndexCommitments<T, I>>::remove(cursor);
+				<IndexedKeys<T, I>>::remove(cursor);
+				<BatchCommitments<T, I>>::remove(cursor);
+				for (who, deposit) in <IndexDeposits<T, I>>::take(cursor) {
+					Self::mutate_deposits(&who, |info| {
+						info.reclaimable = info.reclaimable.saturating_add(deposit)
+					});
+				}
+				cursor = cursor.saturating_add(One::one());
+				consumed = consumed.saturating_add(per_block);
+			}
+			<CommitmentsCursor<T, I>>::put(cursor);
+
+			consumed
+		}
+
+		/// Queue `item` in the `PendingWork` of `on_idle`.
+		pub(crate) fn defer(item: WorkItem<BlockNumberFor<T>>) -> DispatchResult {
+			<PendingWork<T, I>>::try_append(item).map_err(|_| Error::<T, I>::TooMuchPendingWork)?;
+			Ok(())
+		}
+
+		/// Process the items of `PendingWork` in order, spending at most `remaining_weight`.
+		/// Returns the weight used.
+		fn process_pending_work(remaining_weight: Weight) -> Weight {
+			let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+			if remaining_weight.any_lt(consumed) {
+				return Weight::zero()
+			}
+
+			let mut pending = <PendingWork<T, I>>::get();
+			if pending.is_empty() {
+				return T::DbWeight::get().reads(1)
+			}
+			while let Some(item) = pending.first().cloned() {
+				let (used, done) =
+					Self::process_work_item(item, remaining_weight.saturating_sub(consumed));
+				consumed = consumed.saturating_add(used);
+				if !done {
+					break
+				}
+				pending.remove(0);
+			}
+			<PendingWork<T, I>>::put(pending);
+
+			consumed
+		}
+
+		/// Process as much of `item` as `remaining_weight` allows. Returns the weight used, and
+		/// whether the item is done.
+		fn process_work_item(
+			item: WorkItem<BlockNumberFor<T>>,
+			remaining_weight: Weight,
+		) -> (Weight, bool) {
+			match item {
+				WorkItem::PruneHistory { up_to } =>
+					Self::prune_history_within(up_to, remaining_weight),
+			}
+		}
+
+		/// Remove the values of the blocks which fell out of `T::HistoryDepth`, spending at most
+		/// `remaining_weight`. Returns the weight used.
+		fn prune_expired_history(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let up_to = now.saturating_sub(T::HistoryDepth::get());
+			Self::prune_history_within(up_to, remaining_weight).0
+		}
+
+		/// Remove the values stored before block `up_to`, spending at most `remaining_weight`.
+		/// Returns the weight used, and whether all of them were removed.
+		fn prune_history_within(
+			up_to: BlockNumberFor<T>,
+			remaining_weight: Weight,
+		) -> (Weight, bool) {
+			let db = T::DbWeight::get();
+			// The cursor, and the latest block with values if it is pruned.
+			let base = db.reads_writes(2, 2);
+			let per_block = db.writes(2);
+			if remaining_weight.any_lt(base) {
+				return (Weight::zero(), false)
+			}
+
+			let cursor = <HistoryCursor<T, I>>::get();
+			if cursor >= up_to {
+				return (db.reads(1), true)
+			}
+			let max_blocks = remaining_weight
+				.saturating_sub(base)
+				.checked_div_per_component(&per_block)
+				.unwrap_or(u64::MAX)
+				.saturated_into();
+			let pruned_up_to = Self::prune_values(up_to, max_blocks);
+			let pruned = pruned_up_to.saturating_sub(cursor);
+			(
+				base.saturating_add(per_block.saturating_mul(pruned.saturated_into())),
+				pruned_up_to >= up_to,
+			)
+		}
+
+		/// Remove the values stored before block `up_to`, from at most `max_blocks` blocks.
+		/// Returns the oldest block whose values are left.
+		fn prune_values(up_to: BlockNumberFor<T>, max_blocks: u32) -> BlockNumberFor<T> {
+			let mut cursor = <HistoryCursor<T, I>>::get();
+			let up_to = up_to.min(cursor.saturating_add(max_blocks.into()));
+			while cursor < up_to {
+				<ValuesByBlock<T, I>>::remove(cursor);
+				<PreviousValuesAt<T, I>>::remove(cursor);
+				cursor = cursor.saturating_add(One::one());
+			}
+			<HistoryCursor<T, I>>::put(cursor);
+			if <LatestValuesAt<T, I>>::get().map_or(false, |latest| latest < cursor) {
+				<LatestValuesAt<T, I>>::kill();
+			}
+			cursor
+		}
+
+		/// Drain the tasks queued in `block_number` from the offchain database, in queue order.
+		///
+		/// Tasks which don't match their on-chain commitment, or whose `IndexedTask` version is
+		/// unknown to this runtime, are dropped with a warning.
+		fn drain_tasks(block_number: BlockNumberFor<T>) -> Vec<Task> {
+			let mut tasks = Vec::new();
+			for (index, kind) in <IndexedKeys<T, I>>::get(block_number).into_iter().enumerate() {
+				let index = index as u32;
+				let (key, bytes) = Self::find_task_entry(kind, block_number, index);
+				let mut storage = StorageValueRef::persistent(&key);
+				match bytes.as_deref().map(IndexedTask::<T::AccountId>::decode_versioned) {
+					Some(Ok(Some(indexed)))
+						if !Self::is_committed(block_number, index, &indexed.task().encode()) =>
+						log::warn!(
+							target: logging::STORAGE,
+							"{} task {} doesn't match the commitment",
+							Self::log_fields(Some(&key)),
+							index
+						),
+					Some(Ok(Some(indexed))) => {
+						if let IndexedTask::V2 { queued_by, .. } = &indexed {
+							log::debug!(
+								target: logging::STORAGE,
+								"{} task {} queued by {:?}",
+								Self::log_fields(Some(&key)),
+								index,
+								queued_by
+							);
+						}
+						tasks.push(indexed.task().clone());
+					},
+					Some(Ok(None)) => log::warn!(
+						target: logging::STORAGE,
+						"{} task {} has a version unknown to this runtime",
+						Self::log_fields(Some(&key)),
+						index
+					),
+					None => log::debug!(
+						target: logging::STORAGE,
+						"{} task {} is not indexed",
+						Self::log_fields(Some(&key)),
+						index
+					),
+					Some(Err(_)) => log::warn!(
+						target: logging::STORAGE,
+						"{} failed to decode task {}",
+						Self::log_fields(Some(&key)),
+						index
+					),
+				}
+				storage.clear();
+			}
+			tasks
+		}
+
+		/// The key of the local storage the task of `kind` queued at `index` in `block_number` is
+		/// stored under, and its indexed bytes, if any.
+		///
+		/// The task of a block before `T::LegacyKeysUntil` which isn't under the key of the
+		/// current layout is looked up under its legacy key, see `keys::legacy_key`.
+		///
+		/// Can only be called from the offchain worker context.
+		pub fn find_task_entry(
+			kind: KeyKind,
+			block_number: BlockNumberFor<T>,
+			index: u32,
+		) -> (Vec<u8>, Option<Vec<u8>>) {
+			let key = Self::task_key(kind, block_number, index);
+			let bytes = sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, &key);
+			if bytes.is_some() || block_number >= T::LegacyKeysUntil::get() {
+				return (key, bytes)
+			}
+			let legacy = keys::legacy_key(&Self::key_scope(), &block_number, index);
+			let bytes = sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, &legacy);
+			(legacy, bytes)
+		}
+
+		/// Move the tasks of the blocks before `T::LegacyKeysUntil` from their legacy key to the
+		/// key of the current layout, at most `GC_MAX_BLOCKS_PER_RUN` blocks per run.
+		///
+		/// The blocks are gone through once, from the first one whose commitments aren't pruned
+		/// yet, the tasks of the others can't be found anymore. Once done, a run only reads the
+		/// cursor of the local storage.
+		fn migrate_legacy_keys() {
+			let until = T::LegacyKeysUntil::get();
+			let key = Self::local_key(LEGACY_KEYS_CURSOR_KEY);
+			let cursor = StorageValueRef::persistent(&key);
+			let next = cursor.get::<BlockNumberFor<T>>().ok().flatten().unwrap_or_else(Zero::zero);
+			if next >= until {
+				return
+			}
+
+			let mut next = next.max(<CommitmentsCursor<T, I>>::get());
+			let (mut migrated, mut moved) = (0, 0);
+			while next < until && migrated < GC_MAX_BLOCKS_PER_RUN {
+				for (index, kind) in <IndexedKeys<T, I>>::get(next).into_iter().enumerate() {
+					let legacy = keys::legacy_key(&Self::key_scope(), &next, index as u32);
+					let bytes =
+						sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, &legacy);
+					if let Some(bytes) = bytes {
+						let current = Self::task_key(kind, next, index as u32);
+						sp_io::offchain::local_storage_set(
+							StorageKind::PERSISTENT,
+							&current,
+							&bytes,
+						);
+						sp_io::offchain::local_storage_clear(StorageKind::PERSISTENT, &legacy);
+						moved += 1;
+					}
+				}
+				next = next.saturating_add(One::one());
+				migrated += 1;
+			}
+			cursor.set(&next);
+			log::info!(
+				target: logging::STORAGE,
+				"{} moved {} tasks from their legacy key, up to block {:?}",
+				Self::log_fields(Some(&key)),
+				moved,
+				next
+			);
+		}
+
+		/// Read back and clear the entries indexed with `write_batch_to_ocs` in `block_number`, in
+		/// order.
+		///
+		/// Entries which don't match their on-chain commitment are dropped with a warning.
+		pub fn read_batch(block_number: BlockNumberFor<T>) -> Vec<BatchEntry<T, I>> {
+			let commitments = <BatchCommitments<T, I>>::get(block_number);
+			let mut entries = Vec::new();
+			for (index, commitment) in commitments.iter().enumerate() {
+				let key = Self::task_key(KeyKind::BatchEntry, block_number, index as u32);
+				let mut storage = StorageValueRef::persistent(&key);
+				match storage.get::<BatchEntry<T, I>>() {
+					Ok(Some(entry)) if T::Hashing::hash_of(&entry) != *commitment => log::warn!(
+						target: logging::STORAGE,
+						"{} batch entry {} doesn't match the commitment",
+						Self::log_fields(Some(&key)),
+						index
+					),
+					Ok(Some(entry)) => entries.push(entry),
+					Ok(None) => log::debug!(
+						target: logging::STORAGE,
+						"{} batch entry {} is not indexed",
+						Self::log_fields(Some(&key)),
+						index
+					),
+					Err(_) => log::warn!(
+						target: logging::STORAGE,
+						"{} failed to decode batch entry {}",
+						Self::log_fields(Some(&key)),
+						index
+					),
+				}
+				storage.clear();
+			}
+			entries
+		}
+
+		/// The payload indexed with `index_payload` whose Merkle root is `root`, reassembled from
+		/// its chunks and decompressed, if they are all in the local storage and match their
+		/// manifest.
+		///
+		/// Can only be called from the offchain worker context.
+		pub fn read_payload(root: &T::Hash) -> Option<Vec<u8>> {
+			let encoded = blob::read::<T::Hashing>(&keys::payload_key(&Self::key_scope(), root))?;
+			compression::decode(&encoded, T::MaxPayloadLen::get())
+		}
+
+		/// Remove the entries indexed in the blocks which fell out of `T::RetentionBlocks` from
+		/// the local storage, at most `GC_MAX_BLOCKS_PER_RUN` blocks per run.
+		///
+		/// This catches the entries the worker didn't drain, e.g. those indexed while the node
+		/// was syncing, which the worker never ran for.
+		fn collect_garbage(block_number: BlockNumberFor<T>) {
+			let up_to = block_number.saturating_sub(T::RetentionBlocks::get());
+			let key = Self::local_key(GC_CURSOR_KEY);
+			let cursor = StorageValueRef::persistent(&key);
+			let mut next = match cursor.get::<BlockNumberFor<T>>() {
+				Ok(Some(last)) => last.saturating_add(One::one()),
+				_ => Zero::zero(),
+			};
+			// The entries of the pruned blocks can't be found anymore.
+			next = next.max(<CommitmentsCursor<T, I>>::get());
+
+			let mut collected = 0;
+			while next < up_to && collected < GC_MAX_BLOCKS_PER_RUN {
+				for key in Self::indexed_keys(next, next) {
+					StorageValueRef::persistent(&key).clear();
+				}
+				cursor.set(&next);
+				next = next.saturating_add(One::one());
+				collected += 1;
+			}
+		}
+
+		/// Run the tasks scheduled for `block_number`, then those queued in it, and confirm the
+		/// latter on chain, returning the value of the last `Task::SubmitValue` among them, if
+		/// any.
+		///
+		/// The queued tasks are keyed by the number of `T::BlockNumberProvider` of the block.
+		fn run_tasks(block_number: BlockNumberFor<T>) -> Option<u64> {
+			let indexed_at = Self::current_block();
+			let queued = Self::drain_tasks(indexed_at);
+			let confirm = !queued.is_empty();
+			let mut tasks = <ScheduledTasks<T, I>>::get(block_number).into_inner();
+			tasks.extend(queued);
+			if tasks.is_empty() {
+				return None
+			}
+
+			let mut value = None;
+			for task in tasks {
+				log::debug!(
+					target: logging::STORAGE,
+					"{} running task: {:?}",
+					Self::log_fields(None),
+					task
+				);
+				match task {
+					Task::FetchPrice => match Self::fetch_and_store() {
+						Ok(price) => log::info!(
+							target: logging::FETCH,
+							"{} fetched price: {}",
+							Self::log_fields(None),
+							price
+						),
+						Err(e) => log::warn!(
+							target: logging::failure_target(&e),
+							"{} failed to fetch price: {:?}",
+							Self::log_fields(None),
+							e
+						),
+					},
+					Task::SubmitValue(v) => value = Some(v),
+					Task::VerifyPayload(expected) => match <LatestValue<T, I>>::get() {
+						Some(latest) if latest == expected => log::info!(
+							target: logging::STORAGE,
+							"{} latest value is {}",
+							Self::log_fields(None),
+							expected
+						),
+						latest => log::warn!(
+							target: logging::STORAGE,
+							"{} latest value is {:?}, expected {}",
+							Self::log_fields(None),
+							latest,
+							expected
+						),
+					},
+					Task::Notify(message) => log::info!(
+						target: logging::STORAGE,
+						"{} notification: {}",
+						Self::log_fields(None),
+						sp_std::str::from_utf8(&message).unwrap_or("<invalid utf-8>")
+					),
+				}
+			}
+
+			if !confirm {
+				return value
+			}
+			if let Err(e) = Self::confirm_tasks_tx(indexed_at) {
+				log::error!(
+					target: logging::SUBMIT,
+					"{} failed to confirm tasks: {}",
+					Self::log_fields(None),
+					e
+				);
+			}
+			value
+		}
+
+		/// The latest value fetched over HTTP by the offchain worker of this node, if any.
+		///
+		/// Can only be called from the offchain worker context.
+		pub fn fetched_value() -> Option<u64> {
+			StorageValueRef::persistent(&Self::local_key(FETCHED_VALUE_KEY))
+				.get::<u64>()
+				.ok()
+				.flatten()
+		}
+
+		/// The metrics of the offchain worker of this node, see `OcwMetrics`.
+		///
+		/// Can only be called from the offchain worker context.
+		pub fn ocw_metrics() -> OcwMetrics {
+			let key = Self::local_key(METRICS_KEY);
+			StorageValueRef::persistent(&key)
+				.get::<OcwMetrics>()
+				.ok()
+				.flatten()
+				.unwrap_or_default()
+		}
+
+		/// Apply `f` to the metrics of the offchain worker of this node.
+		fn update_metrics(f: impl FnOnce(&mut OcwMetrics)) {
+			let mut metrics = Self::ocw_metrics();
+			f(&mut metrics);
+			let key = Self::local_key(METRICS_KEY);
+			StorageValueRef::persistent(&key).set(&metrics);
+		}
+
+		/// Whether a submission of `value` from `who` for `round` with a transaction of type
+		/// `kind` would be accepted, checked as the call of `kind` or `validate_unsigned` does,
+		/// but without changing any state, so that operators can find out why the submissions
+		/// of their offchain worker are rejected or dropped. `who` is ignored with
+		/// `TransactionType::Raw`.
+		///
+		/// Unsigned submissions are checked as values computed by the offchain worker of the
+		/// current block. Once the checks of the chain pass, `value` goes through the sanity
+		/// checks of the offchain workers, see `check_value`, reported as
+		/// `SubmissionValidity::Anomalous`.
+		pub fn can_submit(
+			who: &T::AccountId,
+			round: RoundIndex,
+			value: u64,
+			kind: TransactionType,
+		) -> SubmissionValidity {
+			if <Paused<T, I>>::get() {
+				return SubmissionValidity::Paused
+			}
+			if kind == TransactionType::Raw {
+				if !<Authorities<T, I>>::get().is_empty() {
+					return SubmissionValidity::NeedsSignedPayload
+				}
+			} else if !Self::is_authority(who) {
+				return SubmissionValidity::NotAuthority
+			}
+			let current = <CurrentRound<T, I>>::get();
+			if round != current.index {
+				return SubmissionValidity::WrongRound { current: current.index }
+			}
+			if kind == TransactionType::Signed {
+				// Signed submissions are only counted until the round is finalized, see
+				// `add_submission`.
+				if current.phase == RoundPhase::Finalized {
+					return SubmissionValidity::Finalized
+				}
+			} else {
+				if <NextUnsignedAt<T, I>>::get() > <frame_system::Pallet<T>>::block_number() {
+					return SubmissionValidity::TooEarly
+				}
+				match current.phase {
+					RoundPhase::Open => {},
+					RoundPhase::Closing => return SubmissionValidity::Closing,
+					RoundPhase::Finalized => return SubmissionValidity::Finalized,
+				}
+			}
+			match Self::check_value(value) {
+				Ok(()) => SubmissionValidity::Valid,
+				Err(reason) => SubmissionValidity::Anomalous(reason),
+			}
+		}
+
+		/// Check `value` against the sanity bounds of the runtime before it is submitted: it must
+		/// be within `T::MinValue` and `T::MaxValue`, and differ from the median of the latest
+		/// round by at most `T::MaxChangePerRound`.
+		pub fn check_value(value: u64) -> Result<(), AnomalyReason> {
+			logic::check_bounds(
+				value,
+				T::MinValue::get(),
+				T::MaxValue::get(),
+				T::MaxChangePerRound::get(),
+				<LatestRound<T, I>>::get().map(|(_, median)| median),
+			)
+		}
+
+		/// The latest values the offchain worker of this node dropped, oldest first, see
+		/// `check_value`.
+		///
+		/// Can only be called from the offchain worker context. The list is also readable with
+		/// the `offchain_localStorageGet` RPC, under `ANOMALIES_KEY`.
+		pub fn anomalies() -> Vec<Anomaly<BlockNumberFor<T>>> {
+			let key = Self::local_key(ANOMALIES_KEY);
+			StorageValueRef::persistent(&key)
+				.get::<Vec<Anomaly<BlockNumberFor<T>>>>()
+				.ok()
+				.flatten()
+				.unwrap_or_default()
+		}
+
+		/// Append `anomaly` to the `anomalies`, keeping the latest `MAX_ANOMALIES`.
+		fn record_anomaly(anomaly: Anomaly<BlockNumberFor<T>>) {
+			let mut anomalies = Self::anomalies();
+			anomalies.push(anomaly);
+			let excess = anomalies.len().saturating_sub(MAX_ANOMALIES);
+			anomalies.drain(..excess);
+			let key = Self::local_key(ANOMALIES_KEY);
+			StorageValueRef::persistent(&key).set(&anomalies);
+		}
+
+		/// The seed of the randomness of this node, drawn from `random_seed` on the first run of
+		/// its offchain worker and kept in the offchain local storage from then on.
+		///
+		/// Can only be called from the offchain worker context.
+		pub fn node_seed() -> [u8; 32] {
+			let key = Self::local_key(NODE_SEED_KEY);
+			let storage = StorageValueRef::persistent(&key);
+			match storage.get::<[u8; 32]>() {
+				Ok(Some(seed)) => seed,
+				_ => {
+					let seed = sp_io::offchain::random_seed();
+					storage.set(&seed);
+					seed
+				},
+			}
+		}
+
+		/// How long, in milliseconds, the worker of `block_number` waits before fetching the
+		/// price: at most `T::MaxJitterMs`, derived from `node_seed` so that it differs between
+		/// the nodes but is reproducible on each of them.
+		///
+		/// Can only be called from the offchain worker context.
+		pub fn jitter_ms(block_number: BlockNumberFor<T>) -> u64 {
+			let max = T::MaxJitterMs::get();
+			if max == 0 {
+				return 0
+			}
+			let hash = sp_io::hashing::blake2_256(&(Self::node_seed(), block_number).encode());
+			let mut random = [0u8; 8];
+			random.copy_from_slice(&hash[..8]);
+			u64::from_le_bytes(random) % max.saturating_add(1)
+		}
+
+		/// Wait for the `jitter_ms` of `block_number`.
+		fn wait_jitter(block_number: BlockNumberFor<T>) {
+			let jitter = Self::jitter_ms(block_number);
+			if jitter > 0 {
+				let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(jitter));
+				sp_io::offchain::sleep_until(deadline);
+			}
+		}
+
+		/// The `k` authorities elected with `seed`: those with the lowest hashes of `seed` along
+		/// with their account, or all of them if there are at most `k`.
+		pub fn elect(seed: &[u8], k: u32) -> Vec<T::AccountId> {
+			let mut authorities = <Authorities<T, I>>::get()
+				.into_iter()
+				.map(|who| (sp_io::hashing::blake2_256(&(seed, &who).encode()), who))
+				.collect::<Vec<_>>();
+			authorities.sort_by(|(a, _), (b, _)| a.cmp(b));
+			authorities.into_iter().take(k as usize).map(|(_, who)| who).collect()
+		}
+
+		/// The seed of the elections of the round `index` opened in the current block, the same
+		/// on all the nodes: the hash of the index along with the parent hash of the block.
+		///
+		/// The seed is stored in the `RoundInfo` as the round opens, as the blocks of the round
+		/// may be counted by `T::BlockNumberProvider` rather than by `frame_system`.
+		pub fn round_seed(index: RoundIndex) -> [u8; 32] {
+			let parent_hash = <frame_system::Pallet<T>>::parent_hash();
+			sp_io::hashing::blake2_256(&(index, parent_hash).encode())
+		}
+
+		/// Whether the worker of this node fetches the price in the current round: always if
+		/// `T::FetchersPerRound` is zero, otherwise only if one of its keys belongs to one of the
+		/// `T::FetchersPerRound` authorities elected with the seed of the current round.
+		pub fn is_fetcher() -> bool {
+			let k = T::FetchersPerRound::get();
+			if k == 0 {
+				return true
+			}
+			let fetchers = Self::elect(&<CurrentRound<T, I>>::get().seed, k);
+			Self::local_accounts().iter().any(|who| fetchers.contains(who))
+		}
+
+		/// The authority which fetches and submits the value of `round` at `block_number`.
+		///
+		/// The authorities take turns in the order of `elect` with the seed of `round`: the first
+		/// one leads the round for `T::LeaderTimeout` blocks, then the next one, and so on. `None`
+		/// if there are no authorities or `T::LeaderTimeout` is zero.
+		pub fn leader(
+			round: &RoundInfo<BlockNumberFor<T>>,
+			block_number: BlockNumberFor<T>,
+		) -> Option<T::AccountId> {
+			let timeout = T::LeaderTimeout::get();
+			if timeout.is_zero() {
+				return None
+			}
+			let authorities = Self::elect(&round.seed, u32::MAX);
+			logic::choose_leader(authorities, round.started_at, block_number, timeout)
+		}
+
+		/// Whether the worker of this node submits the value of the current round at
+		/// `block_number`: if one of its keys belongs to the `leader`, and the round has no value
+		/// yet. Without a leader, every worker submits.
+		pub fn is_leader(block_number: BlockNumberFor<T>) -> bool {
+			let round = <CurrentRound<T, I>>::get();
+			let Some(leader) = Self::leader(&round, block_number) else { return true };
+			!Self::round_has_value(&round) && Self::local_accounts().contains(&leader)
+		}
+
+		/// The accounts of the local offchain worker keys of this node.
+		fn local_accounts() -> Vec<T::AccountId> {
+			Self::local_publics().into_iter().map(|public| public.into_account()).collect()
+		}
+
+		/// The local offchain worker keys of this node.
+		fn local_publics() -> Vec<T::Public> {
+			<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::all()
+				.into_iter()
+				.map(|key| {
+					let generic: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic =
+						key.into();
+					generic.into()
+				})
+				.collect()
+		}
+
+		/// The median of `values`, the mean of the two middle values for an even count.
+		pub fn median(values: &mut [u64]) -> Option<u64> {
+			logic::aggregate(values)
+		}
+
+		/// Fetch the values of `T::Sources` and store their median in the offchain local storage,
+		/// see `fetch_sources`.
+		fn fetch_and_store() -> Result<u64, http::OffchainError> {
+			let value = Self::fetch_sources();
+			Self::update_metrics(|metrics| match value {
+				Ok(_) => metrics.fetch_successes.saturating_inc(),
+				Err(_) => metrics.fetch_failures.saturating_inc(),
+			});
+			let value = value?;
+			StorageValueRef::persistent(&Self::local_key(FETCHED_VALUE_KEY)).set(&value);
+			Ok(value)
+		}
+
+		/// The median of the values of `T::Sources`.
+		///
+		/// With a single response, its failure is returned as is, otherwise the values of the
+		/// responses that failed are ignored.
+		fn fetch_sources() -> Result<u64, http::OffchainError> {
+			let results = T::Sources::values();
+			let single = results.len() == 1;
+			let mut values = Vec::with_capacity(results.len());
+			for result in results {
+				match result {
+					Ok(value) => values.push(value),
+					Err(e) if single => return Err(e),
+					Err(_) => (),
+				}
+			}
+			Self::median(&mut values).ok_or(http::OffchainError::NoSource)
+		}
+
+		/// Choose which kind of transaction the offchain worker of `block_number` submits with.
+		///
+		/// Nodes without an offchain worker key can only submit raw unsigned transactions,
+		/// nodes with one alternate between fee paying signed transactions on even blocks and
+		/// fee-free unsigned transactions with signed payloads on odd blocks.
+		fn choose_transaction_type(block_number: BlockNumberFor<T>) -> TransactionType {
+			if !Signer::<T, T::AuthorityId>::any_account().can_sign() {
+				return TransactionType::Raw
+			}
+			match block_number.saturated_into::<u64>() % 2 {
+				0 => TransactionType::Signed,
+				_ => TransactionType::UnsignedWithSignedPayload,
+			}
+		}
+
+		/// Submit `value` computed by the offchain worker of `block_number` on chain.
+		fn submit_value(block_number: BlockNumberFor<T>, value: u64) -> Result<(), &'static str> {
+			match Self::choose_transaction_type(block_number) {
+				TransactionType::Signed => Self::submit_data_signed_tx(value),
+				TransactionType::UnsignedWithSignedPayload =>
+					Self::submit_payload_unsigned_with_signed_payload_tx(block_number, value),
+				TransactionType::Raw => Self::submit_number_unsigned_tx(block_number, value),
+			}
+		}
+
+		/// Submit `value` on chain with an unsigned transaction carrying a signed payload.
+		fn submit_payload_unsigned_with_signed_payload_tx(
+			block_number: BlockNumberFor<T>,
+			value: u64,
+		) -> Result<(), &'static str> {
+			let (_, result) = Signer::<T, T::AuthorityId>::any_account()
+				.send_unsigned_transaction(
+					|account| DataPayload { block_number, value, public: account.public.clone() },
+					|payload, signature| Call::submit_payload_unsigned_with_signed_payload {
+						payload,
+						signature,
+					},
+				)
+				.ok_or("No local accounts accounts available.")?;
+			result.map_err(|()| "Unable to submit transaction")?;
+
+			log::info!(
+				target: logging::SUBMIT,
+				"{} submitted value with signed payload: {}",
+				Self::log_fields(None),
+				value
+			);
+			Ok(())
+		}
+
+		/// Submit `number` on chain with an unsigned transaction.
+		fn submit_number_unsigned_tx(
+			block_number: BlockNumberFor<T>,
+			number: u64,
+		) -> Result<(), &'static str> {
+			let call = Call::submit_number_unsigned { block_number, number };
+
+			SubmitTransaction::<T, Call<T, I>>::submit_unsigned_transaction(call.into())
+				.map_err(|()| "Unable to submit unsigned transaction.")?;
+
+			log::info!(
+				target: logging::SUBMIT,
+				"{} submitted unsigned value: {}",
+				Self::log_fields(None),
+				number
+			);
+			Ok(())
+		}
+
+		/// Checks of a heartbeat: it must be signed by the key of an authority, and be the first
+		/// one of the authority in the current period.
+		fn validate_heartbeat(
+			heartbeat: &HeartbeatPayload<T::Public, BlockNumberFor<T>>,
+			signature: &T::Signature,
+		) -> TransactionValidity {
+			if !SignedPayload::<T>::verify::<T::AuthorityId>(heartbeat, signature.clone()) {
+				return InvalidTransaction::BadProof.into()
+			}
+			let who = heartbeat.public.clone().into_account();
+			if !<Authorities<T, I>>::get().contains(&who) {
+				return InvalidTransaction::BadSigner.into()
+			}
+
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			if heartbeat.block_number > current_block {
+				return InvalidTransaction::Future.into()
+			}
+			let period = T::HeartbeatPeriod::get();
+			let last_seen = <LastSeen<T, I>>::get(&who);
+			if last_seen.map_or(false, |seen| seen.saturating_add(period) > current_block) ||
+				heartbeat.block_number.saturating_add(period) <= current_block
+			{
+				return InvalidTransaction::Stale.into()
+			}
+
+			ValidTransaction::with_tag_prefix("TemplateOffchainWorkerHeartbeat")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((who, heartbeat.block_number))
+				.longevity(period.saturated_into())
+				.propagate(true)
+				.build()
+		}
+
+		/// Send a heartbeat from each local offchain worker key of an authority which didn't
+		/// send one within the last `T::HeartbeatPeriod` blocks.
+		fn send_heartbeats(block_number: BlockNumberFor<T>) {
+			let authorities = <Authorities<T, I>>::get();
+			let period = T::HeartbeatPeriod::get();
+			let is_due = |public: &T::Public| {
+				let who = public.clone().into_account();
+				authorities.contains(&who) &&
+					<LastSeen<T, I>>::get(&who)
+						.map_or(true, |seen| seen.saturating_add(period) <= block_number)
+			};
+			let mut keys = Vec::new();
+			for key in
+				<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::all()
+			{
+				let generic: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic =
+					key.into();
+				let public: T::Public = generic.into();
+				if is_due(&public) {
+					keys.push(public);
+				}
+			}
+			if keys.is_empty() {
+				return
+			}
+
+			let results = Signer::<T, T::AuthorityId>::all_accounts()
+				.with_filter(keys)
+				.send_unsigned_transaction(
+					|account| HeartbeatPayload { block_number, public: account.public.clone() },
+					|heartbeat, signature| Call::heartbeat { heartbeat, signature },
+				);
+			for (account, result) in &results {
+				if result.is_err() {
+					log::error!(
+						target: logging::SUBMIT,
+						"{} failed to send heartbeat",
+						Self::log_fields(Some(&account.id.encode()))
+					);
+				}
+			}
+		}
+
+		/// Send the median of the latest finalized round to `T::ReportEndpoint`, unless it was
+		/// already sent.
+		///
+		/// The report is a JSON object with the `round`, the `value` and its `decimals`. It is
+		/// signed with the first key of the pallet in the keystore, the hex encoded public key and
+		/// signature are sent in the `X-Public-Key` and `X-Signature` headers, so that receivers
+		/// can authenticate the node.
+		fn send_report() {
+			let url = T::ReportEndpoint::get();
+			let Some((round, value)) = <LatestRound<T, I>>::get() else { return };
+			let report_key = Self::local_key(REPORT_KEY);
+			let last_report = StorageValueRef::persistent(&report_key);
+			if url.is_empty() || last_report.get::<RoundIndex>().ok().flatten() >= Some(round) {
+				return
+			}
+			let Some(key) =
+				<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::all()
+					.into_iter()
+					.next()
+			else {
+				log::warn!(
+					target: logging::SUBMIT,
+					"{} no local key to sign the report of round {}",
+					Self::log_fields(None),
+					round
+				);
+				return
+			};
+
+			let body = json::serialize_u64_fields(&[
+				("round", round.into()),
+				("value", value),
+				("decimals", PRICE_DECIMALS.into()),
+			]);
+			let Some(signature) = key.sign(&body) else {
+				log::error!(
+					target: logging::SUBMIT,
+					"{} failed to sign the report of round {}",
+					Self::log_fields(None),
+					round
+				);
+				return
+			};
+			let public = http::to_hex(&key.encode());
+			let signature = http::to_hex(&signature.encode());
+			let headers = [
+				("Content-Type", "application/json"),
+				("X-Public-Key", sp_std::str::from_utf8(&public).unwrap_or_default()),
+				("X-Signature", sp_std::str::from_utf8(&signature).unwrap_or_default()),
+			];
+
+			match http::post(
+				url,
+				&body,
+				&headers,
+				T::HttpTimeoutMs::get(),
+				T::MaxOcwPayload::get(),
+				T::MinRequestIntervalMs::get(),
+			) {
+				Ok(_) => last_report.set(&round),
+				Err(e) => log::warn!(
+					target: logging::SUBMIT,
+					"{} failed to send the report of round {}: {:?}",
+					Self::log_fields(None),
+					round,
+					e
+				),
+			}
+		}
+
+		/// Send the snapshot of the latest finalized round to `T::ArchiveEndpoint`, if one was
+		/// anchored in `Snapshots` and it wasn't sent already.
+		///
+		/// The snapshot is read from the offchain database, where `anchor_snapshot` indexed it,
+		/// so the node must run with offchain indexing enabled. It is removed once sent.
+		fn send_snapshot() {
+			let url = T::ArchiveEndpoint::get();
+			let current = <CurrentRound<T, I>>::get();
+			let round = if current.phase == RoundPhase::Finalized {
+				current.index
+			} else {
+				let Some(round) = current.index.checked_sub(1) else { return };
+				round
+			};
+			let archive_key = Self::local_key(ARCHIVE_KEY);
+			let last_archive = StorageValueRef::persistent(&archive_key);
+			if url.is_empty() ||
+				!<Snapshots<T, I>>::contains_key(round) ||
+				last_archive.get::<RoundIndex>().ok().flatten() >= Some(round)
+			{
+				return
+			}
+			let snapshot_key = Self::snapshot_key(round);
+			let mut snapshot = StorageValueRef::persistent(&snapshot_key);
+			let Ok(Some(body)) = snapshot.get::<Vec<u8>>() else {
+				log::warn!(
+					target: logging::STORAGE,
+					"{} no snapshot of round {} in the offchain database",
+					Self::log_fields(Some(&snapshot_key)),
+					round
+				);
+				return
+			};
+
+			let headers = [("Content-Type", "application/json")];
+			match http::post(
+				url,
+				&body,
+				&headers,
+				T::HttpTimeoutMs::get(),
+				T::MaxOcwPayload::get(),
+				T::MinRequestIntervalMs::get(),
+			) {
+				Ok(_) => {
+					last_archive.set(&round);
+					snapshot.clear();
+				},
+				Err(e) => log::warn!(
+					target: logging::SUBMIT,
+					"{} failed to archive the snapshot of round {}: {:?}",
+					Self::log_fields(None),
+					round,
+					e
+				),
+			}
+		}
+
+		/// Fetch the values of the subscribed topics every `T::TopicPeriod` blocks and publish
+		/// them with `update_topics`, with the key of `local_authority`.
+		///
+		/// The value of a topic is its field of the JSON object at `T::TopicEndpoint` followed by
+		/// the topic, topics whose value can't be fetched are skipped.
+		fn send_topic_updates(block_number: BlockNumberFor<T>) {
+			let period = T::TopicPeriod::get();
+			if period.is_zero() || !(block_number % period).is_zero() {
+				return
+			}
+			let Some(public) = Self::local_authority() else { return };
+			let topics = <Topics<T, I>>::iter_keys()
+				.take(T::MaxTopics::get() as usize)
+				.filter(|topic| sp_std::str::from_utf8(topic).is_ok())
+				.collect::<Vec<_>>();
+			if topics.is_empty() {
+				return
+			}
+
+			let urls = topics
+				.iter()
+				.map(|topic| [T::TopicEndpoint::get().as_bytes(), &topic[..]].concat())
+				.collect::<Vec<_>>();
+			let urls = urls
+				.iter()
+				.map(|url| sp_std::str::from_utf8(url).unwrap_or_default())
+				.collect::<Vec<_>>();
+			let bodies = http::fetch_all_cached(
+				&urls,
+				T::HttpTimeoutMs::get(),
+				T::CacheTtl::get(),
+				T::MaxOcwPayload::get(),
+				T::MinRequestIntervalMs::get(),
+			);
+			let updates = topics
+				.into_iter()
+				.zip(bodies)
+				.filter_map(|(topic, body)| {
+					let field = sp_std::str::from_utf8(&topic).unwrap_or_default();
+					match body.and_then(|body| logic::parse_response(&body, field, PRICE_DECIMALS))
+					{
+						Ok(value) => Some((topic, value)),
+						Err(e) => {
+							log::warn!(
+								target: logging::failure_target(&e),
+								"{} failed to fetch topic {}: {:?}",
+								Self::log_fields(None),
+								field,
+								e
+							);
+							None
+						},
+					}
+				})
+				.collect::<Vec<_>>();
+			if updates.is_empty() {
+				return
+			}
+
+			// At most `MaxTopics` topics were fetched.
+			let updates = BoundedVec::truncate_from(updates);
+			let results = Signer::<T, T::AuthorityId>::all_accounts()
+				.with_filter(sp_std::vec![public])
+				.send_signed_transaction(|_account| Call::update_topics {
+					updates: updates.clone(),
+				});
+			if results.is_empty() || results.iter().any(|(_, result)| result.is_err()) {
+				log::error!(
+					target: logging::SUBMIT,
+					"{} failed to send the updates of the topics",
+					Self::log_fields(None)
+				);
+			}
+		}
+
+		/// Fetch the prices of the registered assets and submit them with
+		/// `submit_asset_prices`, with the key of `local_authority`, once per open round.
+		///
+		/// Assets whose price can't be fetched are skipped.
+		fn send_asset_prices() {
+			let round = <CurrentRound<T, I>>::get();
+			let key = Self::local_key(ASSET_ROUND_KEY);
+			let last_round = StorageValueRef::persistent(&key);
+			if round.phase != RoundPhase::Open ||
+				last_round.get::<RoundIndex>().ok().flatten() >= Some(round.index)
+			{
+				return
+			}
+			let Some(public) = Self::local_authority() else { return };
+			let assets = <Assets<T, I>>::iter()
+				.take(T::MaxAssets::get() as usize)
+				.filter(|(_, info)| sp_std::str::from_utf8(&info.field).is_ok())
+				.filter_map(|(asset, info)| match Self::asset_url(&info) {
+					Ok(url) => Some((asset, info, url)),
+					Err(e) => {
+						log::warn!(
+							target: logging::FETCH,
+							"{} invalid URL of {:?}: {:?}",
+							Self::log_fields(None),
+							asset,
+							e
+						);
+						None
+					},
+				})
+				.collect::<Vec<_>>();
+			if assets.is_empty() {
+				return
+			}
+
+			let urls = assets
+				.iter()
+				.map(|(_, _, url)| sp_std::str::from_utf8(url).unwrap_or_default())
+				.collect::<Vec<_>>();
+			let bodies = http::fetch_all_cached(
+				&urls,
+				T::HttpTimeoutMs::get(),
+				T::CacheTtl::get(),
+				T::MaxOcwPayload::get(),
+				T::MinRequestIntervalMs::get(),
+			);
+			let prices = assets
+				.iter()
+				.zip(bodies)
+				.filter_map(|((asset, info, _), body)| {
+					let field = sp_std::str::from_utf8(&info.field).unwrap_or_default();
+					match body.and_then(|body| logic::parse_response(&body, field, PRICE_DECIMALS))
+					{
+						Ok(price) => Some((*asset, price)),
+						Err(e) => {
+							log::warn!(
+								target: logging::failure_target(&e),
+								"{} failed to fetch the price of {:?}: {:?}",
+								Self::log_fields(None),
+								asset,
+								e
+							);
+							None
+						},
+					}
+				})
+				.collect::<Vec<_>>();
+			if prices.is_empty() {
+				return
+			}
+
+			// At most `MaxAssets` assets were fetched.
+			let prices = BoundedVec::truncate_from(prices);
+			let results = Signer::<T, T::AuthorityId>::all_accounts()
+				.with_filter(sp_std::vec![public])
+				.send_signed_transaction(|_account| Call::submit_asset_prices {
+					prices: prices.clone(),
+				});
+			if results.is_empty() || results.iter().any(|(_, result)| result.is_err()) {
+				log::error!(
+					target: logging::SUBMIT,
+					"{} failed to send the prices of the assets",
+					Self::log_fields(None)
+				);
+				return
+			}
+			last_round.set(&round.index);
+		}
+
+		/// The hash a value is committed to with `commit_value`, salted so that it can't be
+		/// guessed from the usual values.
+		pub fn reveal_hash(value: u64, salt: &[u8; 32]) -> T::Hash {
+			T::Hashing::hash_of(&(value, salt))
+		}
+
+		/// The URL the price of the asset of `info` is fetched from: its endpoint, or the
+		/// `EndpointTemplate` if it has none, with its field substituted for `{ASSET}`.
+		pub fn asset_url(info: &AssetInfoOf<T, I>) -> Result<Vec<u8>, url::TemplateError> {
+			let template = if info.endpoint.is_empty() {
+				<EndpointTemplate<T, I>>::get().into_inner()
+			} else {
+				info.endpoint.to_vec()
+			};
+			url::substitute(&template, &[(url::ASSET, &info.field[..])])
+		}
+
+		/// The id of the latest record of the feed at `T::FeedEndpoint` submitted on chain, zero
+		/// if there is none.
+		pub fn feed_cursor() -> u64 {
+			<LatestRecord<T, I>>::get().map_or(0, |(id, _)| id)
+		}
+
+		/// Fetch the records of the feed at `T::FeedEndpoint` after the last one the worker
+		/// submitted, and submit each of them with `submit_record_unsigned`, oldest first.
+		///
+		/// The id of the last record submitted is kept in the offchain local storage under
+		/// `FEED_CURSOR_KEY`, so that only the new records are fetched. If it isn't on chain
+		/// within `UNSIGNED_TX_LONGEVITY` blocks, the worker fetches the records after the
+		/// `feed_cursor` again.
+		fn follow_feed(block_number: BlockNumberFor<T>) {
+			let endpoint = T::FeedEndpoint::get();
+			if endpoint.is_empty() {
+				return
+			}
+			let key = Self::local_key(FEED_CURSOR_KEY);
+			let local_cursor = StorageValueRef::persistent(&key);
+			let on_chain = Self::feed_cursor();
+			let since = match local_cursor.get::<(u64, BlockNumberFor<T>)>().ok().flatten() {
+				Some((cursor, submitted_at))
+					if cursor > on_chain &&
+						block_number.saturating_sub(submitted_at) <=
+							UNSIGNED_TX_LONGEVITY.saturated_into() =>
+					cursor,
+				_ => on_chain,
+			};
+
+			let url = url::with_query(endpoint.as_bytes(), b"since", since);
+			let url = sp_std::str::from_utf8(&url).unwrap_or_default();
+			let records = http::fetch(
+				url,
+				T::HttpTimeoutMs::get(),
+				T::MaxOcwPayload::get(),
+				T::MinRequestIntervalMs::get(),
+			)
+			.and_then(|body| json::parse_records(&body, PRICE_DECIMALS));
+			let mut records = match records {
+				Ok(records) => records,
+				Err(e) => {
+					log::warn!(
+						target: logging::failure_target(&e),
+						"{} failed to fetch the feed since {}: {:?}",
+						Self::log_fields(Some(&key)),
+						since,
+						e
+					);
+					return
+				},
+			};
+			records.retain(|(id, _)| *id > since);
+			records.sort_unstable_by_key(|(id, _)| *id);
+			records.dedup_by_key(|(id, _)| *id);
+
+			let mut cursor = since;
+			for (id, value) in records.into_iter().take(MAX_FEED_RECORDS) {
+				let record = FeedRecord { since: cursor, id, value };
+				if let Err(e) = Self::submit_record_tx(record) {
+					log::error!(
+						target: logging::SUBMIT,
+						"{} failed to submit record {} of the feed: {}",
+						Self::log_fields(Some(&key)),
+						id,
+						e
+					);
+					break
+				}
+				cursor = id;
+			}
+			if cursor > since {
+				log::info!(
+					target: logging::SUBMIT,
+					"{} submitted the records of the feed up to {}",
+					Self::log_fields(Some(&key)),
+					cursor
+				);
+				local_cursor.set(&(cursor, block_number));
+			}
+		}
+
+		/// Fetch the documents of `DocumentRequests` from `T::IpfsGateway` and anchor the hash of
+		/// their content with `anchor_document`, with the key of `local_authority`.
+		///
+		/// Documents whose content doesn't match their CID are skipped. The anchor of a document
+		/// is sent again if it isn't on chain once the transaction expired, see `tx_era`.
+		fn send_document_anchors(block_number: BlockNumberFor<T>) {
+			let gateway = T::IpfsGateway::get();
+			if gateway.is_empty() {
+				return
+			}
+			let Some(public) = Self::local_authority() else { return };
+			let longevity = T::TxLongevity::get();
+			let is_due = |cid: &CidOf<T, I>| {
+				let key = [&Self::local_key(DOCUMENT_KEY)[..], cid].concat();
+				let sent_at = StorageValueRef::persistent(&key).get::<BlockNumberFor<T>>();
+				sent_at
+					.ok()
+					.flatten()
+					.map_or(true, |sent_at| sent_at.saturating_add(longevity) <= block_number)
+			};
+			let due = <DocumentRequests<T, I>>::iter_keys()
+				.filter(is_due)
+				.take(MAX_DOCUMENTS_PER_RUN)
+				.collect::<Vec<_>>();
+			for cid in due {
+				let key = [&Self::local_key(DOCUMENT_KEY)[..], &cid].concat();
+				let content = ipfs::IpfsSource::fetch(
+					gateway,
+					&cid,
+					T::HttpTimeoutMs::get(),
+					T::MaxOcwPayload::get(),
+					T::MinRequestIntervalMs::get(),
+				);
+				let content = match content {
+					Ok(content) => content,
+					Err(e) => {
+						log::warn!(
+							target: logging::failure_target(&e),
+							"{} failed to fetch the document: {:?}",
+							Self::log_fields(Some(&cid)),
+							e
+						);
+						continue
+					},
+				};
+				let hash = T::Hashing::hash(&content);
+				let results = Signer::<T, T::AuthorityId>::all_accounts()
+					.with_filter(sp_std::vec![public.clone()])
+					.send_signed_transaction(|_account| Call::anchor_document {
+						cid: cid.clone(),
+						hash,
+					});
+				if results.is_empty() || results.iter().any(|(_, result)| result.is_err()) {
+					log::error!(
+						target: logging::SUBMIT,
+						"{} failed to send the anchor of the document",
+						Self::log_fields(Some(&cid))
+					);
+					continue
+				}
+				StorageValueRef::persistent(&key).set(&block_number);
+			}
+		}
+
+		/// Fetch the answers of the pending `Requests` from `T::QueryEndpoint` and send them
+		/// with `fulfill_request`, with the key of `local_authority`.
+		///
+		/// Requests whose answer can't be fetched, or which timed out, are skipped. The answer
+		/// of a request is sent again if it isn't on chain once the transaction expired, see
+		/// `tx_era`.
+		fn send_request_fulfillments(block_number: BlockNumberFor<T>) {
+			let endpoint = T::QueryEndpoint::get();
+			if endpoint.is_empty() {
+				return
+			}
+			let Some(public) = Self::local_authority() else { return };
+			let longevity = T::TxLongevity::get();
+			let request_key =
+				|id: RequestId| [&Self::local_key(REQUEST_KEY)[..], &id.encode()].concat();
+			let is_due = |id: &RequestId| {
+				let sent_at =
+					StorageValueRef::persistent(&request_key(*id)).get::<BlockNumberFor<T>>();
+				sent_at
+					.ok()
+					.flatten()
+					.map_or(true, |sent_at| sent_at.saturating_add(longevity) <= block_number)
+			};
+			let due = <Requests<T, I>>::iter()
+				.filter(|(id, request)| is_due(id) && !Self::is_expired(request))
+				.take(MAX_REQUESTS_PER_RUN)
+				.collect::<Vec<_>>();
+			for (id, request) in due {
+				let key = request_key(id);
+				let answer = url::substitute(endpoint.as_bytes(), &[(url::QUERY, &request.query)])
+					.map_err(|_| http::OffchainError::ParseFailed)
+					.and_then(|url| {
+						let url = sp_std::str::from_utf8(&url)
+							.map_err(|_| http::OffchainError::ParseFailed)?;
+						http::fetch(
+							url,
+							T::HttpTimeoutMs::get(),
+							T::MaxOcwPayload::get(),
+							T::MinRequestIntervalMs::get(),
+						)
+					})
+					.and_then(|body| {
+						logic::parse_response(&body, T::PriceField::get(), PRICE_DECIMALS)
+					});
+				let answer = match answer {
+					Ok(answer) => answer,
+					Err(e) => {
+						log::warn!(
+							target: logging::failure_target(&e),
+							"{} failed to fetch the answer of request {}: {:?}",
+							Self::log_fields(Some(&key)),
+							id,
+							e
+						);
+						continue
+					},
+				};
+				let results = Signer::<T, T::AuthorityId>::all_accounts()
+					.with_filter(sp_std::vec![public.clone()])
+					.send_signed_transaction(|_account| Call::fulfill_request { id, answer });
+				if results.is_empty() || results.iter().any(|(_, result)| result.is_err()) {
+					log::error!(
+						target: logging::SUBMIT,
+						"{} failed to send the answer of request {}",
+						Self::log_fields(Some(&key)),
+						id
+					);
+					continue
+				}
+				StorageValueRef::persistent(&key).set(&block_number);
+			}
+		}
+
+		/// The first local offchain worker key of an authority, if any.
+		fn local_authority() -> Option<T::Public> {
+			let authorities = <Authorities<T, I>>::get();
+			for key in
+				<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::all()
+			{
+				let generic: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic =
+					key.into();
+				let public: T::Public = generic.into();
+				if authorities.contains(&public.clone().into_account()) {
+					return Some(public)
+				}
+			}
+			None
+		}
+
+		/// Commit to the latest fetched value, or reveal the value committed to once
+		/// `T::RevealDelay` blocks passed, with the key of `local_authority`.
+		///
+		/// The preimage is kept in the local storage of the node in between. A commitment in flight
+		/// is given `T::RevealDelay` blocks to land before the worker commits again.
+		fn commit_reveal(block_number: BlockNumberFor<T>) {
+			let Some(public) = Self::local_authority() else { return };
+			let key = Self::local_key(REVEAL_KEY);
+			let mut pending = StorageValueRef::persistent(&key);
+			let reveal = pending.get::<PendingReveal<BlockNumberFor<T>>>().ok().flatten();
+			let delay = T::RevealDelay::get();
+
+			let call = match (<Commitments<T, I>>::get(public.clone().into_account()), reveal) {
+				(Some(commitment), Some(reveal))
+					if commitment.hash == Self::reveal_hash(reveal.value, &reveal.salt) =>
+				{
+					if commitment.committed_at.saturating_add(delay) > block_number {
+						return
+					}
+					pending.clear();
+					Call::reveal_value { value: reveal.value, salt: reveal.salt }
+				},
+				(Some(_), _) => {
+					log::debug!(
+						target: logging::SUBMIT,
+						"{} waiting for the pending reveal to land",
+						Self::log_fields(None)
+					);
+					return
+				},
+				(None, Some(reveal)) if reveal.sent_at.saturating_add(delay) > block_number =>
+					return,
+				(None, _) => {
+					let Some(value) = Self::fetched_value() else { return };
+					let salt = sp_io::offchain::random_seed();
+					pending.set(&PendingReveal { value, salt, sent_at: block_number });
+					Call::commit_value { hash: Self::reveal_hash(value, &salt) }
+				},
+			};
+
+			let results = Signer::<T, T::AuthorityId>::all_accounts()
+				.with_filter(sp_std::vec![public])
+				.send_signed_transaction(|_account| call.clone());
+			if results.is_empty() || results.iter().any(|(_, result)| result.is_err()) {
+				log::error!(
+					target: logging::SUBMIT,
+					"{} failed to send {:?}",
+					Self::log_fields(None),
+					call
+				);
+			}
+		}
+
+		/// The priority of an unsigned transaction submitting `value`.
+		///
+		/// On top of `T::UnsignedPriority`, a submission gets up to `T::UnsignedPriority` more the
+		/// further `value` deviates from `LatestValue`, relative to it, and up to
+		/// `T::UnsignedPriority` more the longer no value was submitted, up to
+		/// `PRIORITY_STALENESS_BLOCKS`. The pool thus prefers the submissions which change the
+		/// chain the most under congestion.
+		pub fn submission_priority(value: u64) -> TransactionPriority {
+			let base = T::UnsignedPriority::get();
+			let deviation = match <LatestValue<T, I>>::get() {
+				Some(latest) => Perbill::from_rational(value.abs_diff(latest), latest.max(1)),
+				None => Perbill::one(),
+			};
+			let staleness = Self::current_block()
+				.saturating_sub(<LatestValueUpdatedAt<T, I>>::get())
+				.saturated_into::<u32>()
+				.min(PRIORITY_STALENESS_BLOCKS);
+			let staleness = Perbill::from_rational(staleness, PRIORITY_STALENESS_BLOCKS);
+			base.saturating_add(deviation.mul_floor(base))
+				.saturating_add(staleness.mul_floor(base))
+		}
+
+		/// Checks shared by all unsigned transactions produced by the offchain worker of
+		/// `block_number`, submitting `value`.
+		fn validate_transaction_parameters(
+			block_number: &BlockNumberFor<T>,
+			value: u64,
+		) -> TransactionValidity {
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			// Let's make sure to reject transactions from the future.
+			if &current_block < block_number {
+				return InvalidTransaction::Future.into()
+			}
+			// Values computed for blocks that passed out of the longevity window are stale.
+			if current_block.saturating_sub(*block_number) > UNSIGNED_TX_LONGEVITY.saturated_into()
+			{
+				return InvalidTransaction::Stale.into()
+			}
+			// Now let's check if the transaction has any chance to succeed.
+			let next_unsigned_at = <NextUnsignedAt<T, I>>::get();
+			if &next_unsigned_at > block_number {
+				return InvalidTransaction::Stale.into()
+			}
+			// Values are only accepted while the round they were computed in is open. The rounds
+			// may be counted in the blocks of `T::BlockNumberProvider`, which advance at least as
+			// fast as the local ones.
+			let round = <CurrentRound<T, I>>::get();
+			let age = current_block.saturating_sub(*block_number);
+			if round.phase != RoundPhase::Open ||
+				age > Self::current_block().saturating_sub(round.started_at)
+			{
+				return InvalidTransaction::Stale.into()
+			}
+
+			ValidTransaction::with_tag_prefix("TemplateOffchainWorker")
+				.priority(Self::submission_priority(value))
+				// Only a single unsigned transaction is accepted per block, no matter how many
+				// nodes run the offchain worker, so the copies of the other nodes are dropped by
+				// the pool instead of being gossiped around.
+				.and_provides(block_number)
+				.longevity(UNSIGNED_TX_LONGEVITY)
+				.propagate(true)
+				.build()
+		}
+
+		/// Validate the unsigned submission of a record of the feed, once its signer is checked.
+		///
+		/// A record submitted before the one it follows is included waits in the pool until it
+		/// is, as it requires the tag of the previous record.
+		fn validate_record(record: &FeedRecord) -> TransactionValidity {
+			if record.id <= record.since {
+				return InvalidTransaction::Call.into()
+			}
+			let cursor = Self::feed_cursor();
+			if record.since < cursor {
+				return InvalidTransaction::Stale.into()
+			}
+
+			let mut transaction = ValidTransaction::with_tag_prefix("TemplateFeedRecord")
+				.priority(T::UnsignedPriority::get())
+				.and_provides(record.id)
+				.longevity(UNSIGNED_TX_LONGEVITY)
+				.propagate(true);
+			if record.since > cursor {
+				transaction = transaction.and_requires(record.since);
+			}
+			transaction.build()
+		}
+
+		/// Submit `record` on chain with an unsigned transaction: a raw one while there are no
+		/// authorities, one carrying a payload signed by a local authority key otherwise.
+		fn submit_record_tx(record: FeedRecord) -> Result<(), &'static str> {
+			if <Authorities<T, I>>::get().is_empty() {
+				let call = Call::submit_record_unsigned { record };
+				return SubmitTransaction::<T, Call<T, I>>::submit_unsigned_transaction(call.into())
+					.map_err(|()| "Unable to submit transaction")
+			}
+			let (_, result) = Signer::<T, T::AuthorityId>::any_account()
+				.send_unsigned_transaction(
+					|account| RecordPayload {
+						record: record.clone(),
+						public: account.public.clone(),
+					},
+					|payload, signature| Call::submit_record_unsigned_with_signed_payload {
+						payload,
+						signature,
+					},
+				)
+				.ok_or("No local accounts accounts available.")?;
+			result.map_err(|()| "Unable to submit transaction")
+		}
+
+		/// Report `attempts` failed pipeline runs on chain with a signed transaction.
+		fn report_retries_exhausted_tx(attempts: u32) -> Result<(), &'static str> {
+			let (_, result) = Signer::<T, T::AuthorityId>::any_account()
+				.send_signed_transaction(|_account| Call::report_retries_exhausted { attempts })
+				.ok_or("No local accounts accounts available.")?;
+			result.map_err(|()| "Unable to submit transaction")
+		}
+
+		/// Confirm the tasks of `block_number` were processed with a signed transaction.
+		///
+		/// The worker runs on the state of `block_number`, in which the tasks can't be confirmed
+		/// yet, so the transaction is only valid from the next block on.
+		fn confirm_tasks_tx(block_number: BlockNumberFor<T>) -> Result<(), &'static str> {
+			let (_, result) = Signer::<T, T::AuthorityId>::any_account()
+				.send_signed_transaction(|_account| Call::confirm_tasks { block_number })
+				.ok_or("No local accounts accounts available.")?;
+			result.map_err(|()| "Unable to submit transaction")
+		}
+
+		/// Report a failed pipeline run on chain with a signed transaction.
+		fn report_submission_failure_tx(reason: FailureReason) -> Result<(), &'static str> {
+			let (_, result) = Signer::<T, T::AuthorityId>::any_account()
+				.send_signed_transaction(|_account| Call::report_submission_failure { reason })
+				.ok_or("No local accounts accounts available.")?;
+			result.map_err(|()| "Unable to submit transaction")
+		}
+
+		/// The era of a signed transaction of the offchain worker built at `block_number`, for
+		/// the runtime to use in `CreateSignedTransaction::create_transaction`.
+		///
+		/// The transaction is mortal, valid for `T::TxLongevity` blocks, so that a value which
+		/// didn't make it into a block soon isn't included long after it went stale.
+		pub fn tx_era(block_number: BlockNumberFor<T>) -> Era {
+			let max: u64 = T::BlockHashCount::get().saturated_into::<u64>().max(4);
+			// The checkpoint of the era must be one of the block hashes kept by the system.
+			let max = 1 << (u64::BITS - 1 - max.leading_zeros());
+			let period = T::TxLongevity::get().saturated_into::<u64>().min(max);
+			Era::mortal(period, block_number.saturated_into())
+		}
+
+		/// Submit `value` on chain with a signed transaction from every local account which
+		/// didn't submit one in the current round yet, see `claim_submission`.
+		fn submit_data_signed_tx(value: u64) -> Result<(), &'static str> {
+			if !Signer::<T, T::AuthorityId>::all_accounts().can_sign() {
+				return Err(
+					"No local accounts available. Consider adding one via `author_insertKey` RPC.",
+				)
+			}
+
+			let round = <CurrentRound<T, I>>::get().index;
+			let claimed = Self::local_publics()
+				.into_iter()
+				.filter(|public| Self::claim_submission(&public.clone().into_account(), round))
+				.collect::<Vec<_>>();
+			if claimed.is_empty() {
+				log::debug!(
+					target: logging::SUBMIT,
+					"{} skipping submission, all local accounts submitted in the round",
+					Self::log_fields(None)
+				);
+				return Ok(())
+			}
+
+			let results = Signer::<T, T::AuthorityId>::all_accounts()
+				.with_filter(claimed)
+				.send_signed_transaction(|_account| Call::submit_data_signed { value });
+			for (account, result) in &results {
+				match result {
+					Ok(()) => log::info!(
+						target: logging::SUBMIT,
+						"{} submitted value: {}",
+						Self::log_fields(Some(&account.id.encode())),
+						value
+					),
+					Err(e) => {
+						log::error!(
+							target: logging::SUBMIT,
+							"{} failed to submit transaction: {:?}",
+							Self::log_fields(Some(&account.id.encode())),
+							e
+						);
+						Self::release_submission(&account.id);
+					},
+				}
+			}
+
+			Ok(())
+		}
+
+		/// The offchain local storage key of the last round `who` submitted a signed value in.
+		fn submission_key(who: &T::AccountId) -> Vec<u8> {
+			let mut key = Self::local_key(SUBMITTED_KEY);
+			who.encode_to(&mut key);
+			key
+		}
+
+		/// Claim the signed submission of the value of `round` by `who`, so that neither the
+		/// worker of a later block nor one running concurrently submits it again with another
+		/// nonce. `false` if `who` already claimed it, or a later round.
+		///
+		/// The claim is a compare-and-set on the offchain local storage, so that only one of
+		/// two concurrent workers gets it.
+		pub(crate) fn claim_submission(who: &T::AccountId, round: RoundIndex) -> bool {
+			StorageValueRef::persistent(&Self::submission_key(who))
+				.mutate(|last: Result<Option<RoundIndex>, StorageRetrievalError>| match last {
+					Ok(Some(last)) if last >= round => Err(()),
+					_ => Ok(round),
+				})
+				.is_ok()
+		}
+
+		/// Drop the claim of `who` on the submission of the current round, after it failed to
+		/// submit it, so that it can try again.
+		fn release_submission(who: &T::AccountId) {
+			StorageValueRef::persistent(&Self::submission_key(who)).clear();
 		}
 	}
 }
@@ -0,0 +1,56 @@
+//! Log targets of the stages of the offchain worker, so that the logs of a stage can be filtered
+//! on their own, e.g. with `-l ocw::submit=debug`.
+//!
+//! The messages start with their `Fields`: the current round and block, and the hash of the key
+//! they are about, if any.
+
+use crate::{http::OffchainError, RoundIndex};
+use core::fmt;
+
+/// The target of the HTTP requests to the endpoints.
+pub const FETCH: &str = "ocw::fetch";
+
+/// The target of the parsing and the validation of the responses of the endpoints.
+pub const PARSE: &str = "ocw::parse";
+
+/// The target of the transactions sent by the offchain worker, and of the reasons not to send
+/// them.
+pub const SUBMIT: &str = "ocw::submit";
+
+/// The target of the offchain database and local storage.
+pub const STORAGE: &str = "ocw::storage";
+
+/// The target of a failure to fetch a value: `PARSE` if a response was received but holds no
+/// valid value, `FETCH` otherwise.
+pub fn failure_target(error: &OffchainError) -> &'static str {
+	match error {
+		OffchainError::InvalidBody |
+		OffchainError::ParseFailed |
+		OffchainError::BodyTooLarge |
+		OffchainError::ResponseTooLarge |
+		OffchainError::HashMismatch => PARSE,
+		OffchainError::Http(_) | OffchainError::NoSource | OffchainError::RateLimited => FETCH,
+	}
+}
+
+/// The fields at the start of a message, formatted as `round=1 block=4 key=0123456789abcdef`.
+///
+/// The key is an offchain storage key or an account, hashed with `twox_64` to keep the logs
+/// short. It is formatted as `key=-` if there is none.
+pub struct Fields<'a, BlockNumber> {
+	pub round: RoundIndex,
+	pub block: BlockNumber,
+	pub key: Option<&'a [u8]>,
+}
+
+impl<BlockNumber: fmt::Debug> fmt::Display for Fields<'_, BlockNumber> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "round={} block={:?} key=", self.round, self.block)?;
+		match self.key {
+			Some(key) => sp_io::hashing::twox_64(key)
+				.iter()
+				.try_for_each(|byte| write!(f, "{:02x}", byte)),
+			None => f.write_str("-"),
+		}
+	}
+}
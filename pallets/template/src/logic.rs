@@ -0,0 +1,190 @@
+//! The decisions of the offchain worker, as pure functions of their inputs.
+//!
+//! The pallet reads the inputs from its storage, the offchain database or the responses of the
+//! endpoints, and delegates to these functions, which don't touch any externalities. They can be
+//! tested on their own, without a runtime or an offchain environment.
+
+use crate::{http::OffchainError, json, AggregationStrategy, AnomalyReason, ResponseFormat};
+use codec::DecodeAll;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Zero},
+	Perbill, SaturatedConversion,
+};
+use sp_std::vec::Vec;
+
+/// The offchain storage `key` in `scope`, see `Pallet::key_scope`.
+pub fn build_key(scope: &[u8], key: &[u8]) -> Vec<u8> {
+	[scope, key].concat()
+}
+
+/// The value of the top-level `field` of the JSON response `body`, as a fixed point number with
+/// `decimals` decimals.
+pub fn parse_response(body: &[u8], field: &str, decimals: u32) -> Result<u64, OffchainError> {
+	json::parse_decimal_as_fixed(body, field, decimals)
+}
+
+/// The price with `decimals` decimals in the response `body` of an endpoint of the price, in
+/// `format`, with the price in its `field` if it is a JSON object.
+///
+/// The SCALE and plain text responses hold the price as is, so they must be served with
+/// `decimals` decimals. A response with anything after the price, but whitespace in plain
+/// text, is rejected.
+pub fn decode_response(
+	body: &[u8],
+	format: ResponseFormat,
+	field: &str,
+	decimals: u32,
+) -> Result<u64, OffchainError> {
+	match format {
+		ResponseFormat::Json => parse_response(body, field, decimals),
+		ResponseFormat::Scale =>
+			u64::decode_all(&mut &body[..]).map_err(|_| OffchainError::ParseFailed),
+		ResponseFormat::PlainTextU64 => {
+			let body = sp_std::str::from_utf8(body).map_err(|_| OffchainError::InvalidBody)?;
+			let digits = body.trim();
+			if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+				return Err(OffchainError::ParseFailed)
+			}
+			digits.parse().map_err(|_| OffchainError::ParseFailed)
+		},
+	}
+}
+
+/// The leader at `block_number` of a round started at `started_at`, among `candidates` in their
+/// order of election.
+///
+/// Each candidate leads for `timeout` blocks, then the next one, wrapping around. `None` if there
+/// are no candidates or `timeout` is zero.
+pub fn choose_leader<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy>(
+	candidates: Vec<AccountId>,
+	started_at: BlockNumber,
+	block_number: BlockNumber,
+	timeout: BlockNumber,
+) -> Option<AccountId> {
+	if timeout.is_zero() || candidates.is_empty() {
+		return None
+	}
+	let turn: u64 = (block_number.saturating_sub(started_at) / timeout).saturated_into();
+	let index = (turn % candidates.len() as u64) as usize;
+	candidates.into_iter().nth(index)
+}
+
+/// How many median absolute deviations from their median the values kept by
+/// `AggregationStrategy::MadFiltered` are at most.
+pub const MAD_THRESHOLD: u64 = 3;
+
+/// The median of `values`, the mean of the two middle values for an even count.
+pub fn aggregate(values: &mut [u64]) -> Option<u64> {
+	if values.is_empty() {
+		return None
+	}
+	values.sort_unstable();
+	let mid = values.len() / 2;
+	if values.len() % 2 == 0 {
+		Some(((values[mid - 1] as u128 + values[mid] as u128) / 2) as u64)
+	} else {
+		Some(values[mid])
+	}
+}
+
+/// The aggregate of `values` with `strategy`, `None` if there are no values.
+///
+/// Unlike the plain mean, none of the strategies can be moved arbitrarily far by less than a
+/// quarter of the values: the trimmed mean drops them if they are the lowest or the highest,
+/// the filtered mean if they are far from the median.
+pub fn aggregate_with(values: &mut [u64], strategy: AggregationStrategy) -> Option<u64> {
+	match strategy {
+		AggregationStrategy::Median => aggregate(values),
+		AggregationStrategy::TrimmedMean => {
+			values.sort_unstable();
+			let trim = values.len() / 4;
+			mean(&values[trim..values.len() - trim])
+		},
+		AggregationStrategy::MadFiltered => {
+			let median = aggregate(values)?;
+			let mut deviations =
+				values.iter().map(|value| value.abs_diff(median)).collect::<Vec<_>>();
+			let max_deviation = aggregate(&mut deviations)?.saturating_mul(MAD_THRESHOLD);
+			let kept = values
+				.iter()
+				.copied()
+				.filter(|value| value.abs_diff(median) <= max_deviation)
+				.collect::<Vec<_>>();
+			mean(&kept)
+		},
+	}
+}
+
+/// The mean of `values`, rounded down.
+fn mean(values: &[u64]) -> Option<u64> {
+	if values.is_empty() {
+		return None
+	}
+	let sum = values.iter().map(|value| *value as u128).sum::<u128>();
+	Some((sum / values.len() as u128) as u64)
+}
+
+/// The change from `old` to `new` in basis points of `old`, saturating at `u32::MAX`, e.g. 250
+/// from 1000 to 975.
+pub fn change_bps(old: u64, new: u64) -> u32 {
+	if old == 0 {
+		return if new == 0 { 0 } else { u32::MAX }
+	}
+	(old.abs_diff(new) as u128 * 10_000 / old as u128).saturated_into()
+}
+
+/// The largest number of `values` within `tolerance` of one of them, relative to it, e.g. 3 for
+/// `[100, 101, 99, 120]` within 2%.
+pub fn agreeing(values: &[u64], tolerance: Perbill) -> u32 {
+	values
+		.iter()
+		.map(|center| {
+			let max = tolerance.mul_floor(*center);
+			values.iter().filter(|value| value.abs_diff(*center) <= max).count() as u32
+		})
+		.max()
+		.unwrap_or(0)
+}
+
+/// The average of the prices of `observations`, pairs of a time and the price from that time
+/// on, oldest first, weighted by how long each was the price over the `window` before `now`.
+///
+/// The average covers the time since the first observation if it is within the window. `None`
+/// if there are no observations, the latest price if no time passed since it.
+pub fn time_weighted_average(observations: &[(u64, u64)], now: u64, window: u64) -> Option<u64> {
+	let start = now.saturating_sub(window);
+	let ends = observations.iter().skip(1).map(|(at, _)| *at).chain(sp_std::iter::once(now));
+	let (mut weighted, mut elapsed) = (0u128, 0u128);
+	for (&(at, price), end) in observations.iter().zip(ends) {
+		let duration = end.min(now).saturating_sub(at.max(start)) as u128;
+		weighted += price as u128 * duration;
+		elapsed += duration;
+	}
+	if elapsed == 0 {
+		return observations.last().map(|(_, price)| *price)
+	}
+	Some((weighted / elapsed) as u64)
+}
+
+/// Whether `value` is within `min` and `max`, and within `max_change` of the `previous` value,
+/// if any. A zero `max_change` disables the last check.
+pub fn check_bounds(
+	value: u64,
+	min: u64,
+	max: u64,
+	max_change: Perbill,
+	previous: Option<u64>,
+) -> Result<(), AnomalyReason> {
+	if value < min {
+		return Err(AnomalyReason::BelowMin)
+	}
+	if value > max {
+		return Err(AnomalyReason::AboveMax)
+	}
+	if let Some(previous) = previous {
+		if !max_change.is_zero() && value.abs_diff(previous) > max_change * previous {
+			return Err(AnomalyReason::TooLargeChange)
+		}
+	}
+	Ok(())
+}
@@ -0,0 +1,109 @@
+//! A binary Merkle tree over the chunks of a payload, so that the inclusion of a chunk can be
+//! proven against the root committed on chain, see `index_payload` and `verify_chunk`.
+//!
+//! Leaves are hashed as `hash(0 ++ chunk)` and inner nodes as `hash(1 ++ left ++ right)`, so that
+//! an inner node can't pass for a leaf. The last node of a level without a sibling is promoted to
+//! the next level as is.
+
+use codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_runtime::traits::Hash;
+use sp_std::vec::Vec;
+
+/// The proof of the inclusion of the leaf at `index` in a tree of `leaf_count` leaves: the
+/// siblings on the path from the leaf to the root, bottom up.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct MerkleProof<Hash> {
+	pub index: u32,
+	pub leaf_count: u32,
+	pub siblings: Vec<Hash>,
+}
+
+/// The hash of the leaf of `chunk`.
+pub fn leaf_hash<H: Hash>(chunk: &[u8]) -> H::Output {
+	H::hash(&[&[0u8][..], chunk].concat())
+}
+
+/// The hash of the inner node above `left` and `right`.
+fn node_hash<H: Hash>(left: &H::Output, right: &H::Output) -> H::Output {
+	H::hash(&[&[1u8][..], left.as_ref(), right.as_ref()].concat())
+}
+
+/// The hashes of the leaves of `data` split into chunks of `chunk_len` bytes, the last one
+/// possibly shorter.
+pub fn leaves<H: Hash>(data: &[u8], chunk_len: usize) -> Vec<H::Output> {
+	data.chunks(chunk_len).map(leaf_hash::<H>).collect()
+}
+
+/// The level of the tree above `level`.
+fn parent_level<H: Hash>(level: &[H::Output]) -> Vec<H::Output> {
+	level
+		.chunks(2)
+		.map(|pair| match pair {
+			[left, right] => node_hash::<H>(left, right),
+			_ => pair[0],
+		})
+		.collect()
+}
+
+/// The root of the tree of `leaves`, `None` if there are none.
+pub fn root<H: Hash>(leaves: &[H::Output]) -> Option<H::Output> {
+	let mut level = leaves.to_vec();
+	while level.len() > 1 {
+		level = parent_level::<H>(&level);
+	}
+	level.first().copied()
+}
+
+/// The proof of the inclusion of the leaf at `index` in the tree of `leaves`, `None` if there is
+/// no such leaf.
+pub fn proof<H: Hash>(leaves: &[H::Output], index: u32) -> Option<MerkleProof<H::Output>> {
+	if index as usize >= leaves.len() {
+		return None
+	}
+	let mut siblings = Vec::new();
+	let mut level = leaves.to_vec();
+	let mut position = index as usize;
+	while level.len() > 1 {
+		if let Some(sibling) = level.get(position ^ 1) {
+			siblings.push(*sibling);
+		}
+		level = parent_level::<H>(&level);
+		position /= 2;
+	}
+	Some(MerkleProof { index, leaf_count: leaves.len() as u32, siblings })
+}
+
+/// The root of the tree that includes `leaf` according to `proof`, `None` if the siblings of the
+/// proof don't match the shape of the tree.
+pub fn root_from_proof<H: Hash>(
+	proof: &MerkleProof<H::Output>,
+	leaf: H::Output,
+) -> Option<H::Output> {
+	if proof.index >= proof.leaf_count {
+		return None
+	}
+	let mut siblings = proof.siblings.iter();
+	let mut hash = leaf;
+	let mut position = proof.index;
+	let mut width = proof.leaf_count;
+	while width > 1 {
+		if position ^ 1 < width {
+			let sibling = siblings.next()?;
+			hash = if position % 2 == 0 {
+				node_hash::<H>(&hash, sibling)
+			} else {
+				node_hash::<H>(sibling, &hash)
+			};
+		}
+		position /= 2;
+		width = width / 2 + width % 2;
+	}
+	siblings.next().is_none().then_some(hash)
+}
+
+/// Whether `chunk` is the leaf at the index of `proof` in the tree of `root`.
+pub fn verify<H: Hash>(root: &H::Output, proof: &MerkleProof<H::Output>, chunk: &[u8]) -> bool {
+	root_from_proof::<H>(proof, leaf_hash::<H>(chunk)).as_ref() == Some(root)
+}
@@ -0,0 +1,506 @@
+//! Storage migrations of the template pallet.
+
+use crate::{
+	AggregationStrategy, BalanceOf, Commitments, Config, DepositInfo, Deposits, IndexDeposits,
+	LatestValuesAt, OcwConfig, OcwConfigInfo, Pallet, PriceHistory, ResponseFormat, RoundIndex,
+	RoundInfo, RoundPhase, SubmittedData, Subscriptions, ValuesByBlock, LOG_TARGET,
+};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{traits::Zero, Saturating};
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Migration of the storage from version 0 to version 1.
+pub mod v1 {
+	use super::*;
+
+	mod v0 {
+		use super::*;
+
+		/// `Something` as it was stored in version 0: the bare value.
+		#[frame_support::storage_alias]
+		pub type Something<T: Config> = StorageValue<Pallet<T>, u32>;
+	}
+
+	/// A value stored with `do_something`, along with the block it was stored at.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct SomethingInfo<BlockNumber> {
+		pub value: u32,
+		pub updated_at: BlockNumber,
+	}
+
+	/// `Something` as it was stored in version 1, replaced by `ValuesByBlock` in version 2.
+	#[frame_support::storage_alias]
+	pub type Something<T: Config> =
+		StorageValue<Pallet<T>, SomethingInfo<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Reshapes `Something` from a bare `u32` into a `SomethingInfo`.
+	///
+	/// The block a version 0 value was stored at is unknown, so the block of the migration is
+	/// recorded instead.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 0 {
+				log::info!(
+					target: LOG_TARGET,
+					"MigrateToV1 should be removed, on-chain storage version is {:?}",
+					on_chain
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let updated_at = <frame_system::Pallet<T>>::block_number();
+			let migrated = Something::<T>::translate::<u32, _>(|old| {
+				old.map(|value| SomethingInfo { value, updated_at })
+			})
+			.map_or(false, |new| new.is_some());
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: LOG_TARGET, "migrated storage to version 1");
+			if migrated {
+				T::DbWeight::get().reads_writes(3, 2)
+			} else {
+				T::DbWeight::get().reads_writes(3, 1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok(v0::Something::<T>::get().encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 1, "storage version not updated");
+			let old = Option::<u32>::decode(&mut &state[..])
+				.map_err(|_| "the state of pre_upgrade can't be decoded")?;
+			ensure!(
+				old == Something::<T>::get().map(|info| info.value),
+				"the value of Something changed"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migration of the storage from version 1 to version 2.
+pub mod v2 {
+	use super::*;
+
+	/// Moves the single value of `Something` into `ValuesByBlock`, at the block it was stored at.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 1 {
+				log::info!(
+					target: LOG_TARGET,
+					"MigrateToV2 should be removed, on-chain storage version is {:?}",
+					on_chain
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let migrated = match v1::Something::<T>::take() {
+				Some(v1::SomethingInfo { value, updated_at }) => {
+					let values = BoundedVec::truncate_from(sp_std::vec![value.into()]);
+					ValuesByBlock::<T>::insert(updated_at, values);
+					LatestValuesAt::<T>::put(updated_at);
+					true
+				},
+				None => false,
+			};
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!(target: LOG_TARGET, "migrated storage to version 2");
+			if migrated {
+				T::DbWeight::get().reads_writes(2, 4)
+			} else {
+				T::DbWeight::get().reads_writes(2, 2)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok(v1::Something::<T>::get().encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 2, "storage version not updated");
+			let old = Option::<v1::SomethingInfo<BlockNumberFor<T>>>::decode(&mut &state[..])
+				.map_err(|_| "the state of pre_upgrade can't be decoded")?;
+			ensure!(!v1::Something::<T>::exists(), "Something was not removed");
+			ensure!(
+				old.map(|info| (info.updated_at, info.value as u64)) ==
+					Pallet::<T>::latest_n(1).first().copied(),
+				"the value of Something was not moved"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migration of the storage from version 2 to version 3.
+pub mod v3 {
+	use super::*;
+
+	/// `Prices` as it was stored in version 2: the ring buffer of the most recent prices, which
+	/// became `PriceHistory` in version 3, when `Prices` became the prices of the assets.
+	#[frame_support::storage_alias]
+	pub type Prices<T: Config> =
+		StorageValue<Pallet<T>, BoundedVec<u64, <T as Config>::MaxPrices>, OptionQuery>;
+
+	/// The configuration of the offchain worker as it was stored in version 3, before it had a
+	/// `ResponseFormat`.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct OcwConfigInfoV3<BlockNumber, Endpoint> {
+		pub endpoint: Endpoint,
+		pub fetch_interval: BlockNumber,
+		pub max_payload_size: u32,
+		pub max_retries: u32,
+	}
+
+	/// `OcwConfig` as it was stored in version 3.
+	#[frame_support::storage_alias]
+	pub type OcwConfig<T: Config> = StorageValue<
+		Pallet<T>,
+		OcwConfigInfoV3<BlockNumberFor<T>, BoundedVec<u8, <T as Config>::MaxEndpointLen>>,
+		OptionQuery,
+	>;
+
+	/// Moves the ring buffer of the most recent prices from `Prices` to `PriceHistory`.
+	///
+	/// The ring buffer is stored under the prefix of the map of the prices of the assets, so it
+	/// has to be moved before any asset is registered.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 2 {
+				log::info!(
+					target: LOG_TARGET,
+					"MigrateToV3 should be removed, on-chain storage version is {:?}",
+					on_chain
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let migrated = match Prices::<T>::take() {
+				Some(prices) => {
+					PriceHistory::<T>::put(prices);
+					true
+				},
+				None => false,
+			};
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			log::info!(target: LOG_TARGET, "migrated storage to version 3");
+			if migrated {
+				T::DbWeight::get().reads_writes(2, 3)
+			} else {
+				T::DbWeight::get().reads_writes(2, 1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok(Prices::<T>::get().unwrap_or_default().encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 3, "storage version not updated");
+			let old = BoundedVec::<u64, T::MaxPrices>::decode(&mut &state[..])
+				.map_err(|_| "the state of pre_upgrade can't be decoded")?;
+			ensure!(!Prices::<T>::exists(), "Prices was not removed");
+			ensure!(old == PriceHistory::<T>::get(), "the prices were not moved");
+			Ok(())
+		}
+	}
+}
+
+/// Migration of the storage from version 3 to version 4.
+pub mod v4 {
+	use super::*;
+
+	/// The configuration of the offchain worker as it was stored in version 4, before it had an
+	/// `AggregationStrategy`.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct OcwConfigInfoV4<BlockNumber, Endpoint> {
+		pub endpoint: Endpoint,
+		pub fetch_interval: BlockNumber,
+		pub max_payload_size: u32,
+		pub max_retries: u32,
+		pub format: ResponseFormat,
+	}
+
+	/// `OcwConfig` as it was stored in version 4.
+	#[frame_support::storage_alias]
+	pub type OcwConfig<T: Config> = StorageValue<
+		Pallet<T>,
+		OcwConfigInfoV4<BlockNumberFor<T>, BoundedVec<u8, <T as Config>::MaxEndpointLen>>,
+		OptionQuery,
+	>;
+
+	/// Adds the `ResponseFormat` of the responses of the endpoints to `OcwConfig`, JSON as
+	/// before.
+	pub struct MigrateToV4<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 3 {
+				log::info!(
+					target: LOG_TARGET,
+					"MigrateToV4 should be removed, on-chain storage version is {:?}",
+					on_chain
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let migrated = OcwConfig::<T>::translate::<v3::OcwConfigInfoV3<_, _>, _>(|old| {
+				old.map(|old| OcwConfigInfoV4 {
+					endpoint: old.endpoint,
+					fetch_interval: old.fetch_interval,
+					max_payload_size: old.max_payload_size,
+					max_retries: old.max_retries,
+					format: ResponseFormat::Json,
+				})
+			})
+			.map_or(false, |new| new.is_some());
+			StorageVersion::new(4).put::<Pallet<T>>();
+
+			log::info!(target: LOG_TARGET, "migrated storage to version 4");
+			if migrated {
+				T::DbWeight::get().reads_writes(2, 2)
+			} else {
+				T::DbWeight::get().reads_writes(2, 1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok(v3::OcwConfig::<T>::get().encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 4, "storage version not updated");
+			let old = Option::<
+				v3::OcwConfigInfoV3<BlockNumberFor<T>, BoundedVec<u8, T::MaxEndpointLen>>,
+			>::decode(&mut &state[..])
+			.map_err(|_| "the state of pre_upgrade can't be decoded")?;
+			ensure!(
+				old.map(|old| old.endpoint) == OcwConfig::<T>::get().map(|new| new.endpoint),
+				"the configuration of the offchain worker changed"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migration of the storage from version 4 to version 5.
+pub mod v5 {
+	use super::*;
+
+	/// Adds the `AggregationStrategy` of the submissions to `OcwConfig`, the median as before.
+	pub struct MigrateToV5<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 4 {
+				log::info!(
+					target: LOG_TARGET,
+					"MigrateToV5 should be removed, on-chain storage version is {:?}",
+					on_chain
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let migrated = OcwConfig::<T>::translate::<v4::OcwConfigInfoV4<_, _>, _>(|old| {
+				old.map(|old| OcwConfigInfo {
+					endpoint: old.endpoint,
+					fetch_interval: old.fetch_interval,
+					max_payload_size: old.max_payload_size,
+					max_retries: old.max_retries,
+					format: old.format,
+					aggregation: AggregationStrategy::Median,
+				})
+			})
+			.map_or(false, |new| new.is_some());
+			StorageVersion::new(5).put::<Pallet<T>>();
+
+			log::info!(target: LOG_TARGET, "migrated storage to version 5");
+			if migrated {
+				T::DbWeight::get().reads_writes(2, 2)
+			} else {
+				T::DbWeight::get().reads_writes(2, 1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok(v4::OcwConfig::<T>::get().encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 5, "storage version not updated");
+			let old = Option::<
+				v4::OcwConfigInfoV4<BlockNumberFor<T>, BoundedVec<u8, T::MaxEndpointLen>>,
+			>::decode(&mut &state[..])
+			.map_err(|_| "the state of pre_upgrade can't be decoded")?;
+			ensure!(
+				old.map(|old| (old.endpoint, old.format)) ==
+					OcwConfig::<T>::get().map(|new| (new.endpoint, new.format)),
+				"the configuration of the offchain worker changed"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migration of the storage from version 5 to version 6.
+pub mod v6 {
+	use super::*;
+
+	/// Accounts for the deposits reserved before version 6 in `Deposits`, none of them
+	/// reclaimable.
+	pub struct MigrateToV6<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 5 {
+				log::info!(
+					target: LOG_TARGET,
+					"MigrateToV6 should be removed, on-chain storage version is {:?}",
+					on_chain
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads = 1u64;
+			let mut deposits = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+			let entries = SubmittedData::<T>::iter()
+				.map(|(who, entry)| (who, entry.deposit))
+				.chain(IndexDeposits::<T>::iter_values().flatten())
+				.chain(Commitments::<T>::iter().map(|(who, commitment)| (who, commitment.deposit)))
+				.chain(Subscriptions::<T>::iter().map(|(_, who, deposit)| (who, deposit)));
+			for (who, amount) in entries {
+				reads.saturating_inc();
+				let total = deposits.entry(who).or_default();
+				*total = total.saturating_add(amount);
+			}
+			let mut writes = 1u64;
+			for (who, reserved) in deposits {
+				if !reserved.is_zero() {
+					Deposits::<T>::insert(who, DepositInfo { reserved, reclaimable: Zero::zero() });
+					writes.saturating_inc();
+				}
+			}
+			StorageVersion::new(6).put::<Pallet<T>>();
+
+			log::info!(target: LOG_TARGET, "migrated storage to version 6");
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 6, "storage version not updated");
+			ensure!(
+				Deposits::<T>::iter_values().all(|info| info.reclaimable.is_zero()),
+				"deposits reclaimable before any was pruned"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migration of the storage from version 6 to version 7.
+pub mod v7 {
+	use super::*;
+
+	/// The round as it was stored in version 6, before it had a seed.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct RoundInfoV6<BlockNumber> {
+		pub index: RoundIndex,
+		pub started_at: BlockNumber,
+		pub phase: RoundPhase,
+	}
+
+	/// `CurrentRound` as it was stored in version 6.
+	#[frame_support::storage_alias]
+	pub type CurrentRound<T: Config> =
+		StorageValue<Pallet<T>, RoundInfoV6<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Adds the seed of the elections to `CurrentRound`, from the parent hash of the block of
+	/// the upgrade as the round opened before.
+	pub struct MigrateToV7<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV7<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 6 {
+				log::info!(
+					target: LOG_TARGET,
+					"MigrateToV7 should be removed, on-chain storage version is {:?}",
+					on_chain
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let migrated = crate::CurrentRound::<T>::translate::<RoundInfoV6<_>, _>(|old| {
+				old.map(|old| RoundInfo {
+					index: old.index,
+					started_at: old.started_at,
+					phase: old.phase,
+					seed: Pallet::<T>::round_seed(old.index),
+				})
+			})
+			.map_or(false, |new| new.is_some());
+			StorageVersion::new(7).put::<Pallet<T>>();
+
+			log::info!(target: LOG_TARGET, "migrated storage to version 7");
+			if migrated {
+				T::DbWeight::get().reads_writes(3, 2)
+			} else {
+				T::DbWeight::get().reads_writes(3, 1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok(CurrentRound::<T>::get().encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 7, "storage version not updated");
+			let old = Option::<RoundInfoV6<BlockNumberFor<T>>>::decode(&mut &state[..])
+				.map_err(|_| "the state of pre_upgrade can't be decoded")?;
+			let new = crate::CurrentRound::<T>::get();
+			ensure!(
+				old.map_or(true, |old| (old.index, old.started_at, old.phase) ==
+					(new.index, new.started_at, new.phase)),
+				"the current round changed"
+			);
+			Ok(())
+		}
+	}
+}
@@ -1,19 +1,40 @@
 use crate as pallet_template;
-use frame_support::traits::{ConstU16, ConstU64};
-use sp_core::H256;
+use crate::Instance1;
+use frame_support::{
+	traits::{ConstBool, ConstU16, ConstU32, ConstU64, EitherOfDiverse},
+	weights::Weight,
+	PalletId,
+};
+use parking_lot::RwLock;
+use sp_core::{
+	offchain::{
+		testing::{self, OffchainState, PoolState},
+		Externalities, HttpError, HttpRequestId, HttpRequestStatus, OffchainDbExt,
+		OffchainWorkerExt, OpaqueNetworkState, OpaquePeerId, Timestamp, TransactionPoolExt,
+	},
+	H256,
+};
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	testing::{TestSignature, TestXt, UintAuthorityId},
+	traits::{BlakeTwo256, BlockNumberProvider, Extrinsic as ExtrinsicT, IdentityLookup},
+	BuildStorage, Perbill,
+};
+use std::{
+	path::{Path, PathBuf},
+	sync::Arc,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		TemplateModule: pallet_template,
+		Weather: pallet_template::<Instance1>,
 	}
 );
 
@@ -34,7 +55,7 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -43,12 +64,537 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ();
+}
+
+/// Offchain worker keys for tests: `UintAuthorityId(n)` signs for account `n`, and the set of
+/// "keystore" keys is controlled with `UintAuthorityId::set_all_keys`.
+pub struct TestAuthId;
+
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+	type GenericPublic = UintAuthorityId;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		_public: UintAuthorityId,
+		_account: u64,
+		nonce: u64,
+	) -> Option<(RuntimeCall, <Extrinsic as ExtrinsicT>::SignaturePayload)> {
+		Some((call, (nonce, ())))
+	}
+}
+
+frame_support::parameter_types! {
+	pub const HttpEndpoint: &'static str = "http://localhost:8080/value";
+	pub static PriceField: &'static str = "value";
+	pub const MaxDeviation: Perbill = Perbill::from_percent(10);
+	pub static CacheTtl: u64 = 0;
+	pub static MaxOcwPayload: u32 = 1_024;
+	pub static MinRequestIntervalMs: u64 = 0;
+	pub static MaxJitterMs: u64 = 0;
+	pub static WorkerBudgetMs: u64 = 0;
+	pub static FetchersPerRound: u32 = 0;
+	pub static LeaderTimeout: u64 = 0;
+	pub static RevealWindow: u64 = 0;
+	pub static TxLongevity: u64 = 64;
+	pub static MinValue: u64 = 0;
+	pub static MaxValue: u64 = u64::MAX;
+	pub static MaxChangePerRound: Perbill = Perbill::zero();
+	pub static ReportEndpoint: &'static str = "";
+	pub static SnapshotPeriod: u32 = 0;
+	pub static ArchiveEndpoint: &'static str = "";
+	pub static FeedEndpoint: &'static str = "";
+	pub static IpfsGateway: &'static str = "";
+	pub static QueryEndpoint: &'static str = "";
+	pub const TemplatePalletId: PalletId = PalletId(*b"py/tmplt");
+	pub const WeatherPalletId: PalletId = PalletId(*b"py/weath");
+	pub const MaxCallbackWeight: Weight = Weight::from_parts(1_000_000_000, 65_536);
+	pub const TopicEndpoint: &'static str = "http://localhost:8080/topics?symbol=";
+	pub static NewValues: Vec<(u32, u64)> = Vec::new();
+	pub static Now: u64 = 0;
+	pub static StalenessThreshold: u64 = 0;
+	pub static RelayBlockNumber: Option<u64> = None;
+	pub static LegacyKeysUntil: u64 = 0;
+	pub static AcceptInherentValues: bool = false;
+	pub static Finalization: pallet_template::FinalizationMode =
+		pallet_template::FinalizationMode::RoundEnd;
+}
+
+frame_support::ord_parameter_types! {
+	pub const Admin: u64 = 9;
+}
+
+/// A clock at `Now` milliseconds, in place of `pallet_timestamp`.
+pub struct MockTime;
+
+impl frame_support::traits::UnixTime for MockTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::from_millis(Now::get())
+	}
+}
+
+/// The block numbers of a relay chain, `RelayBlockNumber` if set, the local ones otherwise.
+pub struct MockRelayChain;
+
+impl BlockNumberProvider for MockRelayChain {
+	type BlockNumber = u64;
+
+	fn current_block_number() -> u64 {
+		RelayBlockNumber::get().unwrap_or_else(System::block_number)
+	}
+}
+
+/// Records the new values of the assets in `NewValues`.
+pub struct RecordNewValues;
+
+impl pallet_template::traits::OnNewValue<u32, u64> for RecordNewValues {
+	fn on_new_value(asset: &u32, value: &u64) {
+		NewValues::mutate(|values| values.push((*asset, *value)));
+	}
+}
+
 impl pallet_template::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type AuthorityId = TestAuthId;
+	type UnsignedPriority = ConstU64<100>;
+	type HttpEndpoint = HttpEndpoint;
+	type PriceField = PriceField;
+	type Sources = (crate::sources::HttpJson,);
+	type MaxPrices = ConstU32<4>;
+	type TimeProvider = MockTime;
+	type TwapWindow = ConstU64<10_000>;
+	type MaxTwapObservations = ConstU32<4>;
+	type LockTimeout = ConstU64<3_000>;
+	type WorkerBudgetMs = WorkerBudgetMs;
+	type UnsignedInterval = ConstU64<5>;
+	type TxLongevity = TxLongevity;
+	type MaxIndexedPerBlock = ConstU32<2>;
+	type CommitmentRetention = ConstU64<10>;
+	type HistoryDepth = ConstU64<20>;
+	type MaxHistoryPrune = ConstU32<5>;
+	type MaxPendingWork = ConstU32<2>;
+	type MaxEndpoints = ConstU32<3>;
+	type MaxEndpointLen = ConstU32<64>;
+	type MaxRetries = ConstU32<3>;
+	type MaxAuthorities = ConstU32<3>;
+	type Currency = Balances;
+	type MaxDataLen = ConstU32<16>;
+	type DataDepositBase = ConstU64<10>;
+	type DataDepositPerByte = ConstU64<1>;
+	type IndexingDeposit = ConstU64<5>;
+	type HeartbeatPeriod = ConstU64<10>;
+	type MaxBatch = ConstU32<4>;
+	type MaxBatchLabelLen = ConstU32<8>;
+	type MaxPayloadLen = ConstU32<1024>;
+	type ChunkSize = ConstU32<128>;
+	type MaxCallsPerBlock = ConstU32<2>;
+	type RetentionBlocks = ConstU64<4>;
+	type LegacyKeysUntil = LegacyKeysUntil;
+	type RevealDelay = ConstU64<2>;
+	type RevealWindow = RevealWindow;
+	type CommitDeposit = ConstU64<20>;
+	type BlockNumberProvider = MockRelayChain;
+	type RoundLength = ConstU64<4>;
+	type ClosingPeriod = ConstU64<1>;
+	type FinalizationMode = Finalization;
+	type AuthorityBond = ConstU64<10>;
+	type MinDisputeBond = ConstU64<50>;
+	type MaxDeviation = MaxDeviation;
+	type ChangeThresholdBps = ConstU32<1_000>;
+	type AcceptInherentValues = AcceptInherentValues;
+	type MaxStrikes = ConstU32<2>;
+	type CacheTtl = CacheTtl;
+	type HttpTimeoutMs = ConstU64<2_000>;
+	type MaxOcwPayload = MaxOcwPayload;
+	type MinRequestIntervalMs = MinRequestIntervalMs;
+	type MaxJitterMs = MaxJitterMs;
+	type FetchersPerRound = FetchersPerRound;
+	type LeaderTimeout = LeaderTimeout;
+	type MinValue = MinValue;
+	type MaxValue = MaxValue;
+	type MaxChangePerRound = MaxChangePerRound;
+	type ReportEndpoint = ReportEndpoint;
+	type SnapshotPeriod = SnapshotPeriod;
+	type ArchiveEndpoint = ArchiveEndpoint;
+	type TopicEndpoint = TopicEndpoint;
+	type FeedEndpoint = FeedEndpoint;
+	type IpfsGateway = IpfsGateway;
+	type MaxCidLen = ConstU32<64>;
+	type QueryEndpoint = QueryEndpoint;
+	type MaxQueryLen = ConstU32<16>;
+	type MaxPendingRequests = ConstU32<2>;
+	type PalletId = TemplatePalletId;
+	type RequestTtl = ConstU64<10>;
+	type MaxExpiriesPerBlock = ConstU32<1>;
+	type MaxCallbackLen = ConstU32<64>;
+	type MaxCallbackWeight = MaxCallbackWeight;
+	type MaxFailureReports = ConstU32<2>;
+	type FailureReportInterval = ConstU64<5>;
+	type MaxTopicLen = ConstU32<8>;
+	type MaxTopics = ConstU32<2>;
+	type SubscriptionDeposit = ConstU64<5>;
+	type TopicPeriod = ConstU64<2>;
+	type MaxValuesPerBlock = ConstU32<2>;
+	type AssetId = u32;
+	type MaxAssets = ConstU32<2>;
+	type StalenessThreshold = StalenessThreshold;
+	type OnNewValue = RecordNewValues;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type RemoteOrigin = frame_system::EnsureRoot<u64>;
+	type SchedulerOrigin = frame_system::EnsureRoot<u64>;
+	type AdminOrigin =
+		EitherOfDiverse<frame_system::EnsureRoot<u64>, frame_system::EnsureSignedBy<Admin, u64>>;
+	type FeederOrigin = frame_system::EnsureSigned<u64>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+	type WeightInfo = ();
+}
+
+/// A second, independent feed.
+impl pallet_template::Config<Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type AuthorityId = TestAuthId;
+	type UnsignedPriority = ConstU64<100>;
+	type HttpEndpoint = HttpEndpoint;
+	type PriceField = PriceField;
+	type Sources = (crate::sources::HttpJson,);
+	type MaxPrices = ConstU32<4>;
+	type TimeProvider = MockTime;
+	type TwapWindow = ConstU64<10_000>;
+	type MaxTwapObservations = ConstU32<4>;
+	type LockTimeout = ConstU64<3_000>;
+	type WorkerBudgetMs = WorkerBudgetMs;
+	type UnsignedInterval = ConstU64<5>;
+	type TxLongevity = TxLongevity;
+	type MaxIndexedPerBlock = ConstU32<2>;
+	type CommitmentRetention = ConstU64<10>;
+	type HistoryDepth = ConstU64<20>;
+	type MaxHistoryPrune = ConstU32<5>;
+	type MaxPendingWork = ConstU32<2>;
+	type MaxEndpoints = ConstU32<3>;
+	type MaxEndpointLen = ConstU32<64>;
+	type MaxRetries = ConstU32<3>;
+	type MaxAuthorities = ConstU32<3>;
+	type Currency = Balances;
+	type MaxDataLen = ConstU32<16>;
+	type DataDepositBase = ConstU64<10>;
+	type DataDepositPerByte = ConstU64<1>;
+	type IndexingDeposit = ConstU64<5>;
+	type HeartbeatPeriod = ConstU64<10>;
+	type MaxBatch = ConstU32<4>;
+	type MaxBatchLabelLen = ConstU32<8>;
+	type MaxPayloadLen = ConstU32<1024>;
+	type ChunkSize = ConstU32<128>;
+	type MaxCallsPerBlock = ConstU32<2>;
+	type RetentionBlocks = ConstU64<4>;
+	type LegacyKeysUntil = ConstU64<0>;
+	type RevealDelay = ConstU64<2>;
+	type RevealWindow = RevealWindow;
+	type CommitDeposit = ConstU64<20>;
+	type BlockNumberProvider = System;
+	type RoundLength = ConstU64<4>;
+	type ClosingPeriod = ConstU64<1>;
+	type FinalizationMode = Finalization;
+	type AuthorityBond = ConstU64<10>;
+	type MinDisputeBond = ConstU64<50>;
+	type MaxDeviation = MaxDeviation;
+	type ChangeThresholdBps = ConstU32<1_000>;
+	type AcceptInherentValues = ConstBool<false>;
+	type MaxStrikes = ConstU32<2>;
+	type CacheTtl = CacheTtl;
+	type HttpTimeoutMs = ConstU64<2_000>;
+	type MaxOcwPayload = MaxOcwPayload;
+	type MinRequestIntervalMs = MinRequestIntervalMs;
+	type MaxJitterMs = MaxJitterMs;
+	type FetchersPerRound = FetchersPerRound;
+	type LeaderTimeout = LeaderTimeout;
+	type MinValue = MinValue;
+	type MaxValue = MaxValue;
+	type MaxChangePerRound = MaxChangePerRound;
+	type ReportEndpoint = ReportEndpoint;
+	type SnapshotPeriod = SnapshotPeriod;
+	type ArchiveEndpoint = ArchiveEndpoint;
+	type TopicEndpoint = TopicEndpoint;
+	type FeedEndpoint = FeedEndpoint;
+	type IpfsGateway = IpfsGateway;
+	type MaxCidLen = ConstU32<64>;
+	type QueryEndpoint = QueryEndpoint;
+	type MaxQueryLen = ConstU32<16>;
+	type MaxPendingRequests = ConstU32<2>;
+	type PalletId = WeatherPalletId;
+	type RequestTtl = ConstU64<10>;
+	type MaxExpiriesPerBlock = ConstU32<1>;
+	type MaxCallbackLen = ConstU32<64>;
+	type MaxCallbackWeight = MaxCallbackWeight;
+	type MaxFailureReports = ConstU32<2>;
+	type FailureReportInterval = ConstU64<5>;
+	type MaxTopicLen = ConstU32<8>;
+	type MaxTopics = ConstU32<2>;
+	type SubscriptionDeposit = ConstU64<5>;
+	type TopicPeriod = ConstU64<2>;
+	type MaxValuesPerBlock = ConstU32<2>;
+	type AssetId = u32;
+	type MaxAssets = ConstU32<2>;
+	type StalenessThreshold = StalenessThreshold;
+	type OnNewValue = ();
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type RemoteOrigin = frame_system::EnsureRoot<u64>;
+	type SchedulerOrigin = frame_system::EnsureRoot<u64>;
+	type AdminOrigin = frame_system::EnsureRoot<u64>;
+	type FeederOrigin = frame_system::EnsureSigned<u64>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 100), (2, 100), (7, 100), (8, 100)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	t.into()
+}
+
+/// Build genesis storage with the offchain worker extensions registered.
+///
+/// The offchain database is shared with the externalities, so that data indexed by extrinsics
+/// reaches the offchain worker once `persist_offchain_overlay` is called. HTTP requests are
+/// answered from the expectations set on the returned `OffchainState`, with the status set
+/// with `mock_http_get`, and the transactions submitted by the worker land in the returned
+/// `PoolState`.
+pub fn new_test_ext_with_offchain(
+) -> (sp_io::TestExternalities, Arc<RwLock<OffchainState>>, Arc<RwLock<PoolState>>) {
+	let mut t = new_test_ext();
+	let (offchain, offchain_state) = testing::TestOffchainExt::with_offchain_db(t.offchain_db());
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	t.register_extension(OffchainDbExt::new(offchain.clone()));
+	t.register_extension(OffchainWorkerExt::new(StatusOffchainExt {
+		inner: offchain,
+		state: offchain_state.clone(),
+	}));
+	t.register_extension(TransactionPoolExt::new(pool));
+	(t, offchain_state, pool_state)
+}
+
+/// The response header through which `mock_http_get` sets the status of a response, which
+/// `TestOffchainExt` always answers with a 200. It is hidden from the worker.
+const STATUS_HEADER: &str = "x-mock-status";
+
+/// `TestOffchainExt`, answering the requests with the status of their `STATUS_HEADER`.
+pub struct StatusOffchainExt {
+	inner: testing::TestOffchainExt,
+	state: Arc<RwLock<OffchainState>>,
+}
+
+impl StatusOffchainExt {
+	/// The status set with `mock_http_get` of the response to `id`, if any.
+	fn status(&self, id: &HttpRequestId) -> Option<u16> {
+		let state = self.state.read();
+		let headers = &state.requests.get(id)?.response_headers;
+		headers.iter().find(|(name, _)| name == STATUS_HEADER)?.1.parse().ok()
+	}
+}
+
+impl Externalities for StatusOffchainExt {
+	fn is_validator(&self) -> bool {
+		self.inner.is_validator()
+	}
+
+	fn network_state(&self) -> Result<OpaqueNetworkState, ()> {
+		self.inner.network_state()
+	}
+
+	fn timestamp(&mut self) -> Timestamp {
+		self.inner.timestamp()
+	}
+
+	fn sleep_until(&mut self, deadline: Timestamp) {
+		self.inner.sleep_until(deadline)
+	}
+
+	fn random_seed(&mut self) -> [u8; 32] {
+		self.inner.random_seed()
+	}
+
+	fn http_request_start(
+		&mut self,
+		method: &str,
+		uri: &str,
+		meta: &[u8],
+	) -> Result<HttpRequestId, ()> {
+		self.inner.http_request_start(method, uri, meta)
+	}
+
+	fn http_request_add_header(
+		&mut self,
+		request_id: HttpRequestId,
+		name: &str,
+		value: &str,
+	) -> Result<(), ()> {
+		self.inner.http_request_add_header(request_id, name, value)
+	}
+
+	fn http_request_write_body(
+		&mut self,
+		request_id: HttpRequestId,
+		chunk: &[u8],
+		deadline: Option<Timestamp>,
+	) -> Result<(), HttpError> {
+		self.inner.http_request_write_body(request_id, chunk, deadline)
+	}
+
+	fn http_response_wait(
+		&mut self,
+		ids: &[HttpRequestId],
+		deadline: Option<Timestamp>,
+	) -> Vec<HttpRequestStatus> {
+		let statuses = self.inner.http_response_wait(ids, deadline);
+		ids.iter()
+			.zip(statuses)
+			.map(|(id, status)| match (status, self.status(id)) {
+				(HttpRequestStatus::Finished(_), Some(code)) => HttpRequestStatus::Finished(code),
+				(status, _) => status,
+			})
+			.collect()
+	}
+
+	fn http_response_headers(&mut self, request_id: HttpRequestId) -> Vec<(Vec<u8>, Vec<u8>)> {
+		let mut headers = self.inner.http_response_headers(request_id);
+		headers.retain(|(name, _)| name != STATUS_HEADER.as_bytes());
+		headers
+	}
+
+	fn http_response_read_body(
+		&mut self,
+		request_id: HttpRequestId,
+		buffer: &mut [u8],
+		deadline: Option<Timestamp>,
+	) -> Result<usize, HttpError> {
+		self.inner.http_response_read_body(request_id, buffer, deadline)
+	}
+
+	fn set_authorized_nodes(&mut self, nodes: Vec<OpaquePeerId>, authorized_only: bool) {
+		self.inner.set_authorized_nodes(nodes, authorized_only)
+	}
+}
+
+/// Expect a GET request of `HttpEndpoint`, answered with `body`.
+pub fn expect_price_request(state: &RwLock<OffchainState>, body: &[u8]) {
+	mock_http_get(state, HttpEndpoint::get(), 200, body);
+}
+
+/// Expect a GET request of `url`, answered with `status` and `body` by the externalities of
+/// `new_test_ext_with_offchain`.
+pub fn mock_http_get(state: &RwLock<OffchainState>, url: &str, status: u16, body: &[u8]) {
+	state.write().expect_request(testing::PendingRequest {
+		method: "GET".into(),
+		uri: url.into(),
+		response: Some(body.to_vec()),
+		response_headers: vec![(STATUS_HEADER.into(), status.to_string())],
+		sent: true,
+		..Default::default()
+	});
+}
+
+/// The responses of an API recorded in `tests/fixtures`, see `load_fixture`.
+pub struct Fixture {
+	/// The name of the file of the fixture.
+	pub name: String,
+	/// What the responses are, e.g. which API they were recorded from.
+	pub description: String,
+	/// The field of the responses the price is read from, leaked so that it can be set as
+	/// `PriceField`.
+	pub field: &'static str,
+	/// The URLs of the responses, to be set as the `OracleEndpoints`.
+	pub urls: Vec<String>,
+	/// The price the offchain worker fetches from the responses, `None` if it finds none.
+	pub expected: Option<u64>,
+}
+
+/// The paths of the fixtures in `tests/fixtures`, in the order of their names.
+pub fn fixtures() -> Vec<PathBuf> {
+	let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+	let mut paths = std::fs::read_dir(dir)
+		.expect("the fixtures directory is readable")
+		.map(|entry| entry.expect("the fixtures directory is readable").path())
+		.filter(|path| path.extension().map_or(false, |extension| extension == "json"))
+		.collect::<Vec<_>>();
+	paths.sort();
+	paths
+}
+
+/// Load the fixture at `path`, expecting the GET requests of its responses on `state`.
+///
+/// A fixture is a JSON object like
+/// `{"description": "...", "field": "USD", "responses": [{"url": "...", "status": 200,
+/// "body": {"USD": 1.5}}], "expected": 1500000}`, with a `null` `expected` if the worker finds
+/// no price in the responses. A `body` which is a JSON string is the raw body of the response,
+/// any other JSON value is served serialized. The `status` is 200 if left out.
+pub fn load_fixture(state: &RwLock<OffchainState>, path: &Path) -> Fixture {
+	let name = path.file_name().unwrap().to_string_lossy().into_owned();
+	let content = std::fs::read(path).unwrap_or_else(|e| panic!("can't read {}: {}", name, e));
+	let fixture: serde_json::Value =
+		serde_json::from_slice(&content).unwrap_or_else(|e| panic!("invalid {}: {}", name, e));
+	let field = fixture["field"].as_str().unwrap_or_else(|| panic!("no field in {}", name));
+	let responses = fixture["responses"]
+		.as_array()
+		.unwrap_or_else(|| panic!("no responses in {}", name));
+
+	let mut urls = Vec::new();
+	for response in responses {
+		let url = response["url"].as_str().unwrap_or_else(|| panic!("no url in {}", name));
+		let status = response["status"].as_u64().unwrap_or(200) as u16;
+		let body = match &response["body"] {
+			serde_json::Value::String(raw) => raw.clone().into_bytes(),
+			body => body.to_string().into_bytes(),
+		};
+		mock_http_get(state, url, status, &body);
+		urls.push(url.to_owned());
+	}
+
+	Fixture {
+		description: fixture["description"].as_str().unwrap_or_default().to_owned(),
+		field: Box::leak(field.to_owned().into_boxed_str()),
+		urls,
+		expected: fixture["expected"].as_u64(),
+		name,
+	}
 }
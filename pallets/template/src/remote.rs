@@ -0,0 +1,75 @@
+//! Propagation of the prices of the assets to sibling chains over XCM, with the `xcm` feature.
+//!
+//! `SendToSiblings` is an `OnNewValue` handler which sends each new price to the oracle pallet
+//! of each destination, as a `Transact` of its `receive_remote_value`. The destination accepts
+//! it from its `Config::RemoteOrigin`, e.g. `pallet_xcm::EnsureXcm` of the sibling parachain
+//! of the oracle. The messages request `UnpaidExecution`, so the destinations must let the
+//! oracle chain execute them for free, e.g. with `AllowExplicitUnpaidExecutionFrom`.
+
+use crate::traits::OnNewValue;
+use codec::Encode;
+use frame_support::traits::Get;
+use sp_std::{marker::PhantomData, vec, vec::Vec};
+use xcm::latest::{
+	send_xcm, Instruction, MultiLocation, OriginKind, SendXcm, Weight, WeightLimit, Xcm,
+};
+
+/// The log target of the messages sent to the siblings.
+pub const LOG_TARGET: &str = "template::xcm";
+
+/// The call index of `receive_remote_value`.
+pub const RECEIVE_REMOTE_VALUE_CALL_INDEX: u8 = 39;
+
+/// The encoded `receive_remote_value` of `value` for `asset`, for a runtime in which the oracle
+/// pallet is at `pallet_index`.
+pub fn remote_call<AssetId: Encode>(pallet_index: u8, asset: &AssetId, value: u64) -> Vec<u8> {
+	(pallet_index, RECEIVE_REMOTE_VALUE_CALL_INDEX, asset, value).encode()
+}
+
+/// The message dispatching the encoded `call` on its destination with the origin of the
+/// sending chain, with at most `weight` for its execution.
+pub fn transact_message(call: Vec<u8>, weight: Weight) -> Xcm<()> {
+	Xcm(vec![
+		Instruction::UnpaidExecution { weight_limit: WeightLimit::Unlimited, check_origin: None },
+		Instruction::Transact {
+			origin_kind: OriginKind::Xcm,
+			require_weight_at_most: weight,
+			call: call.into(),
+		},
+	])
+}
+
+/// Sends the new prices with `Sender` to each of the `Destinations`, in whose runtimes the
+/// oracle pallet is at `PalletIndex`, allowing `RemoteWeight` for `receive_remote_value`.
+///
+/// A destination the message can't be sent to is skipped, the price is final on this chain
+/// either way.
+pub struct SendToSiblings<Sender, Destinations, PalletIndex, RemoteWeight>(
+	PhantomData<(Sender, Destinations, PalletIndex, RemoteWeight)>,
+);
+
+impl<AssetId, Sender, Destinations, PalletIndex, RemoteWeight> OnNewValue<AssetId, u64>
+	for SendToSiblings<Sender, Destinations, PalletIndex, RemoteWeight>
+where
+	AssetId: Encode,
+	Sender: SendXcm,
+	Destinations: Get<Vec<MultiLocation>>,
+	PalletIndex: Get<u8>,
+	RemoteWeight: Get<Weight>,
+{
+	fn on_new_value(asset: &AssetId, value: &u64) {
+		let call = remote_call(PalletIndex::get(), asset, *value);
+		for destination in Destinations::get() {
+			let message = transact_message(call.clone(), RemoteWeight::get());
+			if let Err(e) = send_xcm::<Sender>(destination, message) {
+				log::warn!(
+					target: LOG_TARGET,
+					"failed to send the value {} to {:?}: {:?}",
+					value,
+					destination,
+					e
+				);
+			}
+		}
+	}
+}
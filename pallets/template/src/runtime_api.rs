@@ -0,0 +1,42 @@
+//! The runtime API exposing the results of the offchain workers, so that frontends and the RPC
+//! layer of the node can query them without decoding raw storage.
+
+use crate::{RoundIndex, RoundInfo, SubmissionValidity, TransactionType};
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The results of the offchain workers and the state of the rounds.
+	///
+	/// Version 2 added `twap` and `can_submit`.
+	#[api_version(2)]
+	pub trait OcwApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// The latest value submitted by an offchain worker, see `LatestValue`.
+		fn latest_value() -> Option<u64>;
+		/// The current round, see `CurrentRound`.
+		fn round_info() -> RoundInfo<BlockNumber>;
+		/// The latest finalized round and the median of its submissions, see `LatestRound`.
+		fn latest_round() -> Option<(RoundIndex, u64)>;
+		/// The time-weighted average of the medians of the recent rounds, see `Pallet::twap`.
+		#[api_version(2)]
+		fn twap() -> Option<u64>;
+		/// The accounts of the offchain worker keys allowed to submit data, see `Authorities`.
+		fn authorities() -> Vec<AccountId>;
+		/// The blocks with tasks the offchain worker didn't confirm yet, along with the number of
+		/// their tasks, see `IndexDeposits`.
+		fn pending_tasks() -> Vec<(BlockNumber, u32)>;
+		/// Whether a submission of `value` from `account` for `round` with a transaction of type
+		/// `kind` would be accepted, without submitting it, see `Pallet::can_submit`.
+		#[api_version(2)]
+		fn can_submit(
+			account: AccountId,
+			round: RoundIndex,
+			value: u64,
+			kind: TransactionType,
+		) -> SubmissionValidity;
+	}
+}
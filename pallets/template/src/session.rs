@@ -0,0 +1,102 @@
+//! Tracking of the validator set of `pallet_session`, with the `session` feature.
+//!
+//! The pallet is a `OneSessionHandler` of the keys its offchain workers sign with: once it is
+//! part of the session keys of the runtime, the `Authorities` are the validators of the current
+//! session, updated on each new session rather than with `add_authority` and
+//! `remove_authority`. An authority is the account of the session key of a validator, the
+//! account its offchain worker submits with, which needn't be the account of the validator.
+//!
+//! The validators are bonded by their staking, so no `Config::AuthorityBond` is reserved from
+//! the accounts of their keys. The bonds of the authorities added before are unreserved when
+//! they leave the set.
+
+use crate::{
+	Authorities, Bonds, Config, CurrentRound, Event, LastFailureReports, Pallet, Submissions,
+	LOG_TARGET,
+};
+use codec::Decode;
+use frame_support::{
+	traits::{Get, OneSessionHandler, ReservableCurrency},
+	BoundedVec,
+};
+use frame_system::offchain::{AppCrypto, SigningTypes};
+use sp_runtime::{traits::IdentifyAccount, BoundToRuntimeAppPublic};
+use sp_std::vec::Vec;
+
+/// The session key of the offchain workers of the instance `I` of the pallet.
+pub type SessionKeyOf<T, I> = <<T as Config<I>>::AuthorityId as AppCrypto<
+	<T as SigningTypes>::Public,
+	<T as SigningTypes>::Signature,
+>>::RuntimeAppPublic;
+
+impl<T: Config<I>, I: 'static> BoundToRuntimeAppPublic for Pallet<T, I> {
+	type Public = SessionKeyOf<T, I>;
+}
+
+impl<T: Config<I>, I: 'static> OneSessionHandler<T::AccountId> for Pallet<T, I>
+where
+	SessionKeyOf<T, I>: Decode,
+{
+	type Key = SessionKeyOf<T, I>;
+
+	fn on_genesis_session<'a, It>(validators: It)
+	where
+		It: Iterator<Item = (&'a T::AccountId, Self::Key)>,
+		T::AccountId: 'a,
+	{
+		Self::set_session_authorities(validators.map(|(_, key)| key));
+	}
+
+	fn on_new_session<'a, It>(changed: bool, validators: It, _queued_validators: It)
+	where
+		It: Iterator<Item = (&'a T::AccountId, Self::Key)>,
+		T::AccountId: 'a,
+	{
+		if changed {
+			Self::set_session_authorities(validators.map(|(_, key)| key));
+		}
+	}
+
+	fn on_disabled(_validator_index: u32) {}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Replace the `Authorities` with the accounts of `keys`, the session keys of the
+	/// validators, up to `Config::MaxAuthorities` of them.
+	///
+	/// The authorities leaving the set are removed as with `remove_authority`.
+	fn set_session_authorities(keys: impl Iterator<Item = SessionKeyOf<T, I>>) {
+		let mut authorities = Vec::new();
+		for key in keys {
+			let generic: <T::AuthorityId as AppCrypto<_, _>>::GenericPublic = key.into();
+			let who = Into::<T::Public>::into(generic).into_account();
+			if !authorities.contains(&who) {
+				authorities.push(who);
+			}
+		}
+		let max = T::MaxAuthorities::get() as usize;
+		if authorities.len() > max {
+			log::warn!(
+				target: LOG_TARGET,
+				"{} validators but at most {} authorities, leaving out the last ones",
+				authorities.len(),
+				max,
+			);
+		}
+		let authorities = BoundedVec::<_, T::MaxAuthorities>::truncate_from(authorities);
+
+		let previous = <Authorities<T, I>>::get();
+		let round = <CurrentRound<T, I>>::get().index;
+		for who in previous.iter().filter(|who| !authorities.contains(who)) {
+			T::Currency::unreserve(who, <Bonds<T, I>>::take(who));
+			<Submissions<T, I>>::remove(round, who);
+			Self::drop_asset_submissions(who);
+			<LastFailureReports<T, I>>::remove(who);
+			Self::deposit_event(Event::AuthorityRemoved { who: who.clone() });
+		}
+		for who in authorities.iter().filter(|who| !previous.contains(who)) {
+			Self::deposit_event(Event::AuthorityAdded { who: who.clone() });
+		}
+		<Authorities<T, I>>::put(authorities);
+	}
+}
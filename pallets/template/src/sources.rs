@@ -0,0 +1,121 @@
+//! Sources of the values fetched by the offchain worker, see `Config::Sources`.
+//!
+//! A source fetches raw responses, e.g. the bodies of HTTP requests, and parses each of them into
+//! a value with `PRICE_DECIMALS` decimals. The worker queries all the sources of the tuple
+//! configured in `Config::Sources` and submits the median of their values, so runtimes can add
+//! their own sources, e.g. an IPFS gateway, next to `HttpJson`.
+
+use crate::{http, logging, logic, Config, OracleEndpoints, Pallet, PRICE_DECIMALS};
+use frame_support::traits::Get;
+use http::OffchainError;
+use sp_std::vec::Vec;
+
+/// A source of values for the offchain worker.
+pub trait DataSource<T: Config<I>, I: 'static = ()> {
+	/// The name of the source in the logs of the offchain worker.
+	const NAME: &'static str;
+
+	/// Fetch the raw responses of the source, one per endpoint it queries.
+	fn fetch() -> Vec<Result<Vec<u8>, OffchainError>>;
+
+	/// Parse a raw response of the source into a value with `PRICE_DECIMALS` decimals.
+	fn parse(raw: &[u8]) -> Result<u64, OffchainError>;
+
+	/// Fetch the raw responses of the source and parse them.
+	fn values() -> Vec<Result<u64, OffchainError>> {
+		Self::fetch()
+			.into_iter()
+			.map(|raw| raw.and_then(|raw| Self::parse(&raw)))
+			.collect()
+	}
+}
+
+/// The sources queried by the offchain worker: a tuple of `DataSource`s.
+pub trait DataSources<T: Config<I>, I: 'static = ()> {
+	/// The values of all the sources, in the order of the tuple.
+	///
+	/// Failures are logged along with the name of their source.
+	fn values() -> Vec<Result<u64, OffchainError>>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(8)]
+#[tuple_types_custom_trait_bound(DataSource<T, I>)]
+impl<T: Config<I>, I: 'static> DataSources<T, I> for Tuple {
+	fn values() -> Vec<Result<u64, OffchainError>> {
+		let mut values = Vec::new();
+		for_tuples!( #(
+			for value in Tuple::values() {
+				if let Err(e) = &value {
+					log::warn!(
+						target: logging::failure_target(e),
+						"{} failed to fetch from {}: {:?}",
+						Pallet::<T, I>::log_fields(None),
+						Tuple::NAME,
+						e
+					);
+				}
+				values.push(value);
+			}
+		)* );
+		values
+	}
+}
+
+/// The price in the responses of the endpoints of `OracleEndpoints`, or of the endpoint of
+/// `OcwConfig` if there are none, in the `ResponseFormat` of `OcwConfig`: by default at
+/// `Config::PriceField` of a JSON object.
+///
+/// Requests time out after `Config::HttpTimeoutMs` and responses are reused for
+/// `Config::CacheTtl` milliseconds, see `http::fetch_cached`. Responses larger than
+/// `Config::MaxOcwPayload` aren't read, those larger than the maximum payload size of
+/// `OcwConfig` are rejected. Each endpoint is requested at most every
+/// `Config::MinRequestIntervalMs`.
+pub struct HttpJson;
+
+impl<T: Config<I>, I: 'static> DataSource<T, I> for HttpJson {
+	const NAME: &'static str = "http-json";
+
+	fn fetch() -> Vec<Result<Vec<u8>, OffchainError>> {
+		let config = Pallet::<T, I>::effective_ocw_config();
+		let endpoints = <OracleEndpoints<T, I>>::get();
+		let bodies = if endpoints.is_empty() {
+			let url = match sp_std::str::from_utf8(&config.endpoint) {
+				Ok(url) if !url.is_empty() => url,
+				_ => T::HttpEndpoint::get(),
+			};
+			sp_std::vec![http::fetch_cached(
+				url,
+				T::HttpTimeoutMs::get(),
+				T::CacheTtl::get(),
+				T::MaxOcwPayload::get(),
+				T::MinRequestIntervalMs::get(),
+			)]
+		} else {
+			let urls = endpoints
+				.iter()
+				.filter_map(|url| sp_std::str::from_utf8(url).ok())
+				.collect::<Vec<_>>();
+			http::fetch_all_cached(
+				&urls,
+				T::HttpTimeoutMs::get(),
+				T::CacheTtl::get(),
+				T::MaxOcwPayload::get(),
+				T::MinRequestIntervalMs::get(),
+			)
+		};
+
+		let max_payload_size = config.max_payload_size as usize;
+		bodies
+			.into_iter()
+			.map(|body| match body {
+				Ok(body) if body.len() > max_payload_size => Err(OffchainError::BodyTooLarge),
+				body => body,
+			})
+			.collect()
+	}
+
+	fn parse(raw: &[u8]) -> Result<u64, OffchainError> {
+		let format = Pallet::<T, I>::effective_ocw_config().format;
+		logic::decode_response(raw, format, T::PriceField::get(), PRICE_DECIMALS)
+	}
+}
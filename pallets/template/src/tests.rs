@@ -1,5 +1,27 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	keys::{KeyDerivation, KeyKind},
+	mock::*,
+	AggregationStrategy, Anomaly, AnomalyReason, DataPayload, DepositInfo, DisputeOutcome, Error,
+	FailureReason, HeartbeatPayload, IndexedTask, InherentError, ReputationInfo, ResponseFormat,
+	RoundInfo, RoundPhase, Task, WorkItem,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+	BoundedVec,
+};
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	traits::{BadOrigin, BlakeTwo256, Hash, ValidateUnsigned},
+	transaction_validity::{InvalidTransaction, TransactionSource},
+	Perbill, RuntimeAppPublic,
+};
+
+// The mock runtime has two instances of the pallet, the tests are about the default one.
+type Call = crate::Call<Test>;
+type Event = crate::Event<Test>;
 
 #[test]
 fn it_works_for_default_value() {
@@ -9,12 +31,34 @@ fn it_works_for_default_value() {
 		// Dispatch a signed extrinsic.
 		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
 		// Read pallet storage and assert an expected result.
-		assert_eq!(TemplateModule::something(), Some(42));
+		assert_eq!(TemplateModule::values_by_block(1).into_inner(), vec![42]);
 		// Assert that the correct event was deposited
 		System::assert_last_event(Event::SomethingStored { something: 42, who: 1 }.into());
 	});
 }
 
+#[test]
+fn values_are_kept_per_block_and_listed_newest_first() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 1));
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(2), 2));
+		assert_noop!(
+			TemplateModule::do_something(RuntimeOrigin::signed(1), 3),
+			Error::<Test>::TooManyValues
+		);
+
+		System::set_block_number(5);
+		assert_ok!(TemplateModule::cause_error(RuntimeOrigin::signed(1)));
+		assert_eq!(TemplateModule::values_by_block(1).into_inner(), vec![1, 2]);
+		assert_eq!(TemplateModule::values_by_block(5).into_inner(), vec![3]);
+
+		assert_eq!(TemplateModule::latest_n(2), vec![(5, 3), (1, 2)]);
+		assert_eq!(TemplateModule::latest_n(10), vec![(5, 3), (1, 2), (1, 1)]);
+		assert!(TemplateModule::latest_n(0).is_empty());
+	});
+}
+
 #[test]
 fn correct_error_for_none_value() {
 	new_test_ext().execute_with(|| {
@@ -25,3 +69,4400 @@ fn correct_error_for_none_value() {
 		);
 	});
 }
+
+#[test]
+fn submit_data_signed_stores_value() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(1), 42));
+		assert_eq!(TemplateModule::latest_value(), Some(42));
+		System::assert_last_event(Event::DataSubmitted { value: 42, who: 1 }.into());
+	});
+}
+
+#[test]
+fn submit_number_unsigned_requires_none_origin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TemplateModule::submit_number_unsigned(RuntimeOrigin::signed(1), 1, 42),
+			BadOrigin
+		);
+		assert_ok!(TemplateModule::submit_number_unsigned(RuntimeOrigin::none(), 1, 42));
+		assert_eq!(TemplateModule::latest_value(), Some(42));
+		System::assert_last_event(
+			Event::DataSubmittedUnsigned { value: 42, block_number: 1 }.into(),
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_checks_block_number() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+		let validate = |block_number| {
+			let call = Call::submit_number_unsigned { block_number, number: 42 };
+			TemplateModule::validate_unsigned(TransactionSource::External, &call)
+		};
+
+		// `UnsignedPriority` is 100 in the mock, plus 100 as there is no value on chain yet and
+		// 10 for the blocks without a value.
+		let valid = validate(10).unwrap();
+		assert_eq!(valid.priority, 210);
+		assert_eq!(valid.longevity, crate::UNSIGNED_TX_LONGEVITY);
+		assert_eq!(validate(11), InvalidTransaction::Future.into());
+		assert_eq!(validate(4), InvalidTransaction::Stale.into());
+		assert_eq!(
+			TemplateModule::validate_unsigned(
+				TransactionSource::External,
+				&Call::submit_data_signed { value: 42 }
+			),
+			InvalidTransaction::Call.into()
+		);
+	});
+}
+
+#[test]
+fn fresher_and_larger_updates_have_a_higher_priority() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(1), 1_000));
+
+		// `UnsignedPriority` is 100 in the mock.
+		assert_eq!(TemplateModule::submission_priority(1_000), 100);
+		assert_eq!(TemplateModule::submission_priority(1_100), 110);
+		assert_eq!(TemplateModule::submission_priority(900), 110);
+		assert_eq!(TemplateModule::submission_priority(5_000), 200);
+
+		System::set_block_number(51);
+		assert_eq!(TemplateModule::submission_priority(1_000), 150);
+		System::set_block_number(500);
+		assert_eq!(TemplateModule::submission_priority(1_100), 210);
+	});
+}
+
+#[test]
+fn signed_payload_is_verified() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let public = UintAuthorityId(7);
+		let payload = DataPayload { block_number: 1, value: 42, public: public.clone() };
+		let signature = public.sign(&payload.encode()).unwrap();
+
+		let call = Call::submit_payload_unsigned_with_signed_payload {
+			payload: payload.clone(),
+			signature: signature.clone(),
+		};
+		assert_ok!(TemplateModule::validate_unsigned(TransactionSource::External, &call));
+
+		let forged = Call::submit_payload_unsigned_with_signed_payload {
+			payload: payload.clone(),
+			signature: TestSignature(8, payload.encode()),
+		};
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &forged),
+			InvalidTransaction::BadProof.into()
+		);
+
+		assert_ok!(TemplateModule::submit_payload_unsigned_with_signed_payload(
+			RuntimeOrigin::none(),
+			payload,
+			signature
+		));
+		assert_eq!(TemplateModule::latest_value(), Some(42));
+		System::assert_last_event(Event::DataSubmitted { value: 42, who: 7 }.into());
+	});
+}
+
+#[test]
+fn parse_value_reads_first_integer() {
+	use crate::http::{parse_value, OffchainError};
+
+	assert_eq!(parse_value(b"{\"USD\":12}"), Ok(12));
+	assert_eq!(parse_value(b"42"), Ok(42));
+	assert_eq!(parse_value(b"{\"USD\":null}"), Err(OffchainError::ParseFailed));
+	assert_eq!(parse_value(&[0xff, 0xfe]), Err(OffchainError::InvalidBody));
+	assert_eq!(parse_value(b"99999999999999999999"), Err(OffchainError::ParseFailed));
+}
+
+#[test]
+fn http_failures_are_told_apart() {
+	use crate::http::{HttpError, OffchainError};
+	use sp_runtime::offchain::http;
+
+	assert_eq!(HttpError::from(http::Error::DeadlineReached), HttpError::DeadlineReached);
+	assert_eq!(HttpError::from(http::Error::IoError), HttpError::IoError);
+	assert_eq!(HttpError::from(http::Error::Unknown), HttpError::IoError);
+	assert_eq!(
+		OffchainError::from(http::Error::DeadlineReached),
+		OffchainError::Http(HttpError::DeadlineReached)
+	);
+	assert_ne!(
+		OffchainError::Http(HttpError::Non200Status(500)).encode(),
+		OffchainError::Http(HttpError::Non200Status(503)).encode()
+	);
+}
+
+#[test]
+fn parse_price_reads_fixed_point() {
+	use crate::http::{parse_price, OffchainError};
+
+	assert_eq!(parse_price(b"{\"USD\":6.42}"), Ok(6_420_000));
+	assert_eq!(parse_price(b"7"), Ok(7_000_000));
+	assert_eq!(parse_price(b"1.1234567"), Ok(1_123_456));
+	assert_eq!(parse_price(b"1.2.3"), Err(OffchainError::ParseFailed));
+	assert_eq!(parse_price(b"99999999999999"), Err(OffchainError::ParseFailed));
+}
+
+#[test]
+fn prices_are_kept_in_a_ring_buffer() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(TemplateModule::average_price(), None);
+
+		for price in [10, 20, 30, 40, 50, 60] {
+			assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(1), price));
+		}
+
+		// `MaxPrices` is 4 in the mock, the two oldest prices were dropped.
+		assert_eq!(TemplateModule::price_history().into_inner(), vec![30, 40, 50, 60]);
+		assert_eq!(TemplateModule::average_price(), Some(45));
+		assert_eq!(TemplateModule::latest_value(), Some(60));
+	});
+}
+
+#[test]
+fn unsigned_submissions_respect_interval() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::submit_number_unsigned(RuntimeOrigin::none(), 2, 42));
+		// `UnsignedInterval` is 5 in the mock.
+		assert_eq!(TemplateModule::next_unsigned_at(), 7);
+
+		System::set_block_number(6);
+		let call = Call::submit_number_unsigned { block_number: 6, number: 43 };
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::Stale.into()
+		);
+
+		System::set_block_number(7);
+		let call = Call::submit_number_unsigned { block_number: 7, number: 43 };
+		assert_ok!(TemplateModule::validate_unsigned(TransactionSource::External, &call));
+	});
+}
+
+#[test]
+fn signed_transactions_of_the_worker_are_mortal() {
+	use sp_runtime::generic::Era;
+
+	let period = |era| match era {
+		Era::Mortal(period, _) => period,
+		Era::Immortal => 0,
+	};
+	TxLongevity::set(50);
+	assert_eq!(period(TemplateModule::tx_era(100)), 64);
+	assert!((101..=164).contains(&TemplateModule::tx_era(100).death(100)));
+	// `BlockHashCount` is 250 in the mock.
+	TxLongevity::set(1_000);
+	assert_eq!(period(TemplateModule::tx_era(100)), 128);
+	TxLongevity::set(0);
+	assert_eq!(period(TemplateModule::tx_era(100)), 4);
+}
+
+#[test]
+fn queued_tasks_are_committed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::write_key_to_ocs(RuntimeOrigin::signed(1), 42));
+		assert_noop!(
+			TemplateModule::queue_task(RuntimeOrigin::signed(1), Task::SubmitValue(42)),
+			Error::<Test>::DuplicateTask
+		);
+		assert_ok!(TemplateModule::queue_task(RuntimeOrigin::signed(1), Task::FetchPrice));
+		let key_hash = BlakeTwo256::hash(&TemplateModule::task_key(KeyKind::FetchPrice, 1, 1));
+		System::assert_last_event(Event::OffchainDataIndexed { block: 1, key_hash }.into());
+
+		let task = Task::SubmitValue(42).encode();
+		assert_eq!(TemplateModule::index_commitments(1).len(), 2);
+		assert_eq!(TemplateModule::index_commitments(1).first(), Some(&BlakeTwo256::hash(&task)));
+		assert!(TemplateModule::is_committed(1, 0, &task));
+		assert!(TemplateModule::is_committed(1, 1, &Task::FetchPrice.encode()));
+		assert!(!TemplateModule::is_committed(1, 1, &task));
+
+		// `MaxIndexedPerBlock` is 2 in the mock.
+		assert_noop!(
+			TemplateModule::write_key_to_ocs(RuntimeOrigin::signed(1), 44),
+			Error::<Test>::TooManyIndexedEntries
+		);
+	});
+}
+
+#[test]
+fn indexed_tasks_of_any_version_are_decoded() {
+	let decode = |bytes: &[u8]| IndexedTask::<u64>::decode_versioned(bytes);
+	let notify = Task::Notify(BoundedVec::truncate_from(b"hi".to_vec()));
+
+	// Bare tasks were indexed before the versions were introduced.
+	assert_eq!(
+		decode(&Task::VerifyPayload(7).encode()),
+		Ok(Some(IndexedTask::V1(Task::VerifyPayload(7))))
+	);
+	assert_eq!(decode(&notify.encode()), Ok(Some(IndexedTask::V1(notify.clone()))));
+	let v1 = IndexedTask::<u64>::V1(Task::FetchPrice);
+	assert_eq!(v1.encode()[0], crate::FIRST_TASK_VERSION);
+	assert_eq!(decode(&v1.encode()), Ok(Some(v1)));
+	let v2 = IndexedTask::V2 { task: notify.clone(), queued_by: 1u64 };
+	assert_eq!(decode(&v2.encode()), Ok(Some(v2.clone())));
+	assert_eq!(v2.task(), &notify);
+
+	// Fields appended by a later runtime are ignored, its versions are skipped.
+	let extended = [v2.encode(), 42u32.encode()].concat();
+	assert_eq!(decode(&extended), Ok(Some(v2)));
+	assert_eq!(decode(&[0x82, 1, 2, 3]), Ok(None));
+	assert!(decode(&[]).is_err());
+	assert!(decode(&[0x81, 3]).is_err());
+}
+
+#[test]
+fn queued_tasks_are_indexed_with_their_version() {
+	let (mut t, _offchain, _pool) = new_test_ext_with_offchain();
+	t.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::queue_task(RuntimeOrigin::signed(2), Task::FetchPrice));
+	});
+	t.persist_offchain_overlay();
+
+	t.execute_with(|| {
+		let key = TemplateModule::task_key(KeyKind::FetchPrice, 1, 0);
+		let bytes =
+			sp_io::offchain::local_storage_get(sp_core::offchain::StorageKind::PERSISTENT, &key)
+				.unwrap();
+		assert_eq!(
+			IndexedTask::decode_versioned(&bytes),
+			Ok(Some(IndexedTask::V2 { task: Task::FetchPrice, queued_by: 2u64 }))
+		);
+		// The commitment is of the task, whatever its version.
+		assert!(TemplateModule::is_committed(1, 0, &Task::FetchPrice.encode()));
+	});
+}
+
+#[test]
+fn indexed_keys_can_be_enumerated_and_decoded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::write_key_to_ocs(RuntimeOrigin::signed(1), 42));
+		System::set_block_number(3);
+		assert_ok!(TemplateModule::queue_task(RuntimeOrigin::signed(1), Task::FetchPrice));
+		assert_ok!(TemplateModule::queue_task(RuntimeOrigin::signed(2), Task::VerifyPayload(7)));
+		assert_eq!(
+			TemplateModule::indexed_keys_of(3).into_inner(),
+			vec![KeyKind::FetchPrice, KeyKind::VerifyPayload]
+		);
+
+		let keys = TemplateModule::indexed_keys(1, 3);
+		assert_eq!(
+			keys,
+			vec![
+				TemplateModule::task_key(KeyKind::SubmitValue, 1, 0),
+				TemplateModule::task_key(KeyKind::FetchPrice, 3, 0),
+				TemplateModule::task_key(KeyKind::VerifyPayload, 3, 1),
+			]
+		);
+		assert!(keys[0].starts_with(b"template::indexing::v1/submit-value/"));
+		assert_eq!(TemplateModule::indexed_keys(2, 2), Vec::<Vec<u8>>::new());
+
+		let decoded = TemplateModule::decode_key(&keys[2]).unwrap();
+		assert_eq!(
+			decoded,
+			KeyDerivation { kind: KeyKind::VerifyPayload, block_number: 3, index: 1 }
+		);
+		assert_eq!(TemplateModule::decode_key(&keys[2][..keys[2].len() - 1]), None);
+		assert_eq!(TemplateModule::decode_key(b"template::indexing::v0/notify/"), None);
+	});
+}
+
+#[test]
+fn on_idle_prunes_old_commitments() {
+	new_test_ext().execute_with(|| {
+		for block in 1..=3 {
+			System::set_block_number(block);
+			assert_ok!(TemplateModule::write_key_to_ocs(RuntimeOrigin::signed(1), block));
+		}
+
+		// `CommitmentRetention` is 10 in the mock.
+		TemplateModule::on_idle(12, Weight::MAX);
+		assert!(TemplateModule::index_commitments(1).is_empty());
+		assert!(TemplateModule::indexed_keys_of(1).is_empty());
+		assert_eq!(TemplateModule::index_commitments(2).len(), 1);
+		assert_eq!(TemplateModule::indexed_keys_of(2).len(), 1);
+
+		TemplateModule::on_idle(13, Weight::MAX);
+		assert!(TemplateModule::index_commitments(2).is_empty());
+		assert_eq!(TemplateModule::index_commitments(3).len(), 1);
+	});
+}
+
+#[test]
+fn old_values_are_pruned_on_idle_and_on_demand() {
+	new_test_ext().execute_with(|| {
+		for block in [1, 3, 5] {
+			System::set_block_number(block);
+			assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), block as u32));
+		}
+
+		// `HistoryDepth` is 20 in the mock.
+		TemplateModule::on_idle(22, Weight::MAX);
+		assert!(TemplateModule::values_by_block(1).is_empty());
+		assert_eq!(crate::HistoryCursor::<Test>::get(), 2);
+		assert_eq!(TemplateModule::latest_n(3), vec![(5, 5), (3, 3)]);
+
+		System::set_block_number(6);
+		assert_noop!(TemplateModule::prune_history(RuntimeOrigin::signed(1), 4), BadOrigin);
+		assert_noop!(
+			TemplateModule::prune_history(RuntimeOrigin::root(), 7),
+			Error::<Test>::PruneTooFar
+		);
+		// Only the weight of the blocks 2 and 3 is charged.
+		let pruned = TemplateModule::prune_history(RuntimeOrigin::root(), 4).unwrap();
+		assert_eq!(pruned.actual_weight, Some(<() as crate::WeightInfo>::prune_history(2)));
+		System::assert_last_event(Event::HistoryPruned { up_to: 4 }.into());
+		assert!(TemplateModule::values_by_block(3).is_empty());
+		assert_eq!(TemplateModule::latest_n(3), vec![(5, 5)]);
+
+		// `MaxHistoryPrune` is 5 in the mock.
+		System::set_block_number(20);
+		assert_ok!(TemplateModule::prune_history(RuntimeOrigin::root(), 20));
+		System::assert_last_event(Event::HistoryPruned { up_to: 9 }.into());
+		assert_eq!(TemplateModule::latest_values_at(), None);
+		assert_eq!(TemplateModule::latest_n(3), vec![]);
+		assert_eq!(
+			TemplateModule::pending_work().to_vec(),
+			vec![WorkItem::PruneHistory { up_to: 20 }]
+		);
+	});
+}
+
+#[test]
+fn pending_work_is_processed_on_idle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(30);
+		// `MaxHistoryPrune` is 5 and `MaxPendingWork` is 2 in the mock.
+		assert_ok!(TemplateModule::prune_history(RuntimeOrigin::root(), 10));
+		assert_ok!(TemplateModule::prune_history(RuntimeOrigin::root(), 30));
+		assert_noop!(
+			TemplateModule::prune_history(RuntimeOrigin::root(), 30),
+			Error::<Test>::TooMuchPendingWork
+		);
+		assert_eq!(
+			TemplateModule::pending_work().to_vec(),
+			vec![WorkItem::PruneHistory { up_to: 10 }, WorkItem::PruneHistory { up_to: 30 }]
+		);
+
+		TemplateModule::on_idle(30, Weight::MAX);
+		assert_eq!(crate::HistoryCursor::<Test>::get(), 30);
+		assert!(TemplateModule::pending_work().is_empty());
+	});
+}
+
+#[test]
+fn migration_to_v1_reshapes_something() {
+	use crate::migrations::v1::{Something, SomethingInfo};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<TemplateModule>();
+		frame_support::storage::unhashed::put(&Something::<Test>::hashed_key(), &42u32);
+		System::set_block_number(7);
+
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), 1);
+		assert_eq!(Something::<Test>::get(), Some(SomethingInfo { value: 42, updated_at: 7 }));
+
+		// Running it again is a no-op.
+		System::set_block_number(8);
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Something::<Test>::get(), Some(SomethingInfo { value: 42, updated_at: 7 }));
+	});
+}
+
+#[test]
+fn migration_to_v2_moves_something_into_the_history() {
+	use crate::migrations::v1::{Something, SomethingInfo};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<TemplateModule>();
+		Something::<Test>::put(SomethingInfo { value: 42, updated_at: 7 });
+		System::set_block_number(9);
+
+		crate::migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), 2);
+		assert!(!Something::<Test>::exists());
+		assert_eq!(TemplateModule::latest_n(1), vec![(7, 42)]);
+
+		// Running it again is a no-op.
+		crate::migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(TemplateModule::latest_n(2), vec![(7, 42)]);
+	});
+}
+
+type Endpoint = BoundedVec<u8, <Test as crate::Config>::MaxEndpointLen>;
+
+fn endpoints(urls: &[&[u8]]) -> BoundedVec<Endpoint, <Test as crate::Config>::MaxEndpoints> {
+	let urls: Vec<Endpoint> = urls.iter().map(|url| url.to_vec().try_into().unwrap()).collect();
+	urls.try_into().unwrap()
+}
+
+#[test]
+fn set_oracle_endpoints_requires_root() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let urls = endpoints(&[b"http://a", b"http://b"]);
+
+		assert_noop!(
+			TemplateModule::set_oracle_endpoints(RuntimeOrigin::signed(1), urls.clone()),
+			BadOrigin
+		);
+		assert_ok!(TemplateModule::set_oracle_endpoints(RuntimeOrigin::root(), urls.clone()));
+		assert_eq!(TemplateModule::oracle_endpoints(), urls);
+		System::assert_last_event(Event::OracleEndpointsSet { count: 2 }.into());
+
+		assert_noop!(
+			TemplateModule::set_oracle_endpoints(RuntimeOrigin::root(), endpoints(&[&[0xff]])),
+			Error::<Test>::InvalidEndpoint
+		);
+	});
+}
+
+#[test]
+fn median_ignores_outliers() {
+	assert_eq!(TemplateModule::median(&mut []), None);
+	assert_eq!(TemplateModule::median(&mut [5]), Some(5));
+	assert_eq!(TemplateModule::median(&mut [7, 1_000_000, 5]), Some(7));
+	assert_eq!(TemplateModule::median(&mut [4, 1, 6, 3]), Some(3));
+}
+
+#[test]
+fn aggregation_strategies_resist_a_minority_of_outliers() {
+	use crate::logic::aggregate_with;
+
+	let strategies = [
+		AggregationStrategy::Median,
+		AggregationStrategy::TrimmedMean,
+		AggregationStrategy::MadFiltered,
+	];
+	let honest = [100, 101, 99, 100, 102, 98, 100];
+	let adversarial = [
+		// Attackers pushing the same way.
+		[&honest[..], &[1_000_000, 1_000_000]].concat(),
+		// Attackers at both extremes.
+		[&honest[..], &[0, u64::MAX]].concat(),
+		// An attacker just past the spread of the honest values.
+		[&honest[..], &[110, 0]].concat(),
+	];
+	for values in adversarial {
+		for strategy in strategies {
+			assert_eq!(aggregate_with(&mut values.clone(), strategy), Some(100), "{:?}", strategy);
+		}
+	}
+
+	for strategy in strategies {
+		assert_eq!(aggregate_with(&mut [], strategy), None);
+		// A majority of equal values has no deviation, the others are all outliers.
+		assert_eq!(aggregate_with(&mut [5, 9, 5, 5], strategy), Some(5));
+		// Without outliers the trimmed and filtered means are means.
+		assert_eq!(aggregate_with(&mut [10, 40, 20, 30], strategy), Some(25));
+	}
+	assert_eq!(aggregate_with(&mut [10, 20, 60], AggregationStrategy::Median), Some(20));
+	assert_eq!(aggregate_with(&mut [10, 20, 60], AggregationStrategy::TrimmedMean), Some(30));
+	assert_eq!(aggregate_with(&mut [10, 20, 60], AggregationStrategy::MadFiltered), Some(15));
+}
+
+#[test]
+fn responses_are_decoded_in_their_format() {
+	use crate::{http::OffchainError, logic::decode_response};
+
+	let decode = |body: &[u8], format| decode_response(body, format, "USD", 6);
+	assert_eq!(decode(br#"{"USD": 5.12}"#, ResponseFormat::Json), Ok(5_120_000));
+	assert_eq!(decode(&5_120_000u64.encode(), ResponseFormat::Scale), Ok(5_120_000));
+	assert_eq!(
+		decode(&5_120_000u32.encode(), ResponseFormat::Scale),
+		Err(OffchainError::ParseFailed)
+	);
+	assert_eq!(
+		decode(&(5_120_000u64, 0u8).encode(), ResponseFormat::Scale),
+		Err(OffchainError::ParseFailed)
+	);
+	assert_eq!(decode(b"5120000\n", ResponseFormat::PlainTextU64), Ok(5_120_000));
+	assert_eq!(decode(b"5.12", ResponseFormat::PlainTextU64), Err(OffchainError::ParseFailed));
+	assert_eq!(decode(b"-1", ResponseFormat::PlainTextU64), Err(OffchainError::ParseFailed));
+	assert_eq!(decode(b"", ResponseFormat::PlainTextU64), Err(OffchainError::ParseFailed));
+	assert_eq!(decode(&[0xff], ResponseFormat::PlainTextU64), Err(OffchainError::InvalidBody));
+}
+
+#[test]
+fn leaders_take_turns_until_they_time_out() {
+	use crate::logic::choose_leader;
+
+	let candidates = || vec![3u64, 1, 2];
+	assert_eq!(choose_leader(candidates(), 10u64, 10, 0), None);
+	assert_eq!(choose_leader(Vec::<u64>::new(), 10u64, 10, 2), None);
+	let leaders = (10..18u64)
+		.map(|block| choose_leader(candidates(), 10, block, 2))
+		.collect::<Vec<_>>();
+	assert_eq!(leaders, [3, 3, 1, 1, 2, 2, 3, 3].into_iter().map(Some).collect::<Vec<_>>());
+	// A round can't have started after the current block, but if it did its first candidate
+	// would lead.
+	assert_eq!(choose_leader(candidates(), 10u64, 5, 2), Some(3));
+}
+
+#[test]
+fn values_are_checked_against_the_bounds_and_the_previous_value() {
+	use crate::logic::check_bounds;
+
+	let check =
+		|value, previous| check_bounds(value, 10, 1_000, Perbill::from_percent(50), previous);
+	assert_eq!(check(9, None), Err(AnomalyReason::BelowMin));
+	assert_eq!(check(1_001, None), Err(AnomalyReason::AboveMax));
+	assert_eq!(check(10, None), Ok(()));
+	assert_eq!(check(1_000, None), Ok(()));
+	assert_eq!(check(150, Some(100)), Ok(()));
+	assert_eq!(check(50, Some(100)), Ok(()));
+	assert_eq!(check(151, Some(100)), Err(AnomalyReason::TooLargeChange));
+	assert_eq!(check(49, Some(100)), Err(AnomalyReason::TooLargeChange));
+	assert_eq!(check_bounds(1_000, 10, 1_000, Perbill::zero(), Some(10)), Ok(()));
+}
+
+#[test]
+fn parse_u64_field_reads_integers() {
+	use crate::{http::OffchainError, json::parse_u64_field};
+
+	assert_eq!(parse_u64_field(br#"{"value": 42}"#, "value"), Ok(42));
+	assert_eq!(parse_u64_field(br#"{"other": 1, "value": 4e2}"#, "value"), Ok(400));
+	assert_eq!(parse_u64_field(br#"{"value": 1.5}"#, "value"), Err(OffchainError::ParseFailed));
+	assert_eq!(parse_u64_field(br#"{"value": -1}"#, "value"), Err(OffchainError::ParseFailed));
+	assert_eq!(parse_u64_field(br#"{"value": "42"}"#, "value"), Err(OffchainError::ParseFailed));
+	assert_eq!(parse_u64_field(br#"{"other": 42}"#, "value"), Err(OffchainError::ParseFailed));
+	assert_eq!(parse_u64_field(b"42", "value"), Err(OffchainError::InvalidBody));
+}
+
+#[test]
+fn parse_decimal_as_fixed_reads_decimals() {
+	use crate::{http::OffchainError, json::parse_decimal_as_fixed};
+
+	assert_eq!(parse_decimal_as_fixed(br#"{"USD": 12.345}"#, "USD", 6), Ok(12_345_000));
+	assert_eq!(parse_decimal_as_fixed(br#"{"USD": 7}"#, "USD", 6), Ok(7_000_000));
+	assert_eq!(parse_decimal_as_fixed(br#"{"USD": 0.1234567}"#, "USD", 6), Ok(123_456));
+	assert_eq!(parse_decimal_as_fixed(br#"{"USD": 1.5e-3}"#, "USD", 6), Ok(1_500));
+	assert_eq!(
+		parse_decimal_as_fixed(br#"{"USD": -0.5}"#, "USD", 6),
+		Err(OffchainError::ParseFailed)
+	);
+	assert_eq!(
+		parse_decimal_as_fixed(br#"{"USD": 1e30}"#, "USD", 6),
+		Err(OffchainError::ParseFailed)
+	);
+}
+
+#[test]
+fn retries_back_off_exponentially() {
+	assert_eq!(TemplateModule::retry_delay(1), 1);
+	assert_eq!(TemplateModule::retry_delay(2), 2);
+	assert_eq!(TemplateModule::retry_delay(4), 8);
+	assert_eq!(TemplateModule::retry_delay(40), u32::MAX as u64);
+}
+
+#[test]
+fn report_retries_exhausted_emits_event() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(TemplateModule::report_retries_exhausted(RuntimeOrigin::none(), 4), BadOrigin);
+		assert_ok!(TemplateModule::report_retries_exhausted(RuntimeOrigin::signed(1), 4));
+		System::assert_last_event(Event::RetriesExhausted { who: 1, attempts: 4 }.into());
+	});
+}
+
+#[test]
+fn admin_calls_can_be_delegated_to_another_origin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// The `AdminOrigin` of the mock is root or account 9, only root for `Weather`.
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::signed(9), 1));
+		System::assert_last_event(Event::AuthorityAdded { who: 1 }.into());
+		assert_ok!(TemplateModule::remove_authority(RuntimeOrigin::signed(9), 1));
+		assert_noop!(Weather::add_authority(RuntimeOrigin::signed(9), 1), BadOrigin);
+		assert_noop!(
+			TemplateModule::set_endpoint_template(RuntimeOrigin::signed(8), Default::default()),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn authorities_are_managed_by_root() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(TemplateModule::add_authority(RuntimeOrigin::signed(1), 1), BadOrigin);
+
+		// Account 3 has no funds for the bond.
+		assert!(TemplateModule::add_authority(RuntimeOrigin::root(), 3).is_err());
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::AuthorityAdded { who: 1 }.into());
+		// `AuthorityBond` is 10 in the mock.
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_noop!(
+			TemplateModule::add_authority(RuntimeOrigin::root(), 1),
+			Error::<Test>::AlreadyAuthority
+		);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 2));
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		// `MaxAuthorities` is 3 in the mock.
+		assert_noop!(
+			TemplateModule::add_authority(RuntimeOrigin::root(), 8),
+			Error::<Test>::TooManyAuthorities
+		);
+
+		assert_ok!(TemplateModule::remove_authority(RuntimeOrigin::root(), 2));
+		System::assert_last_event(Event::AuthorityRemoved { who: 2 }.into());
+		assert_eq!(TemplateModule::authorities().into_inner(), vec![1, 7]);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_noop!(
+			TemplateModule::remove_authority(RuntimeOrigin::root(), 2),
+			Error::<Test>::NotAuthority
+		);
+	});
+}
+
+#[cfg(feature = "session")]
+#[test]
+fn authorities_follow_the_validators_of_the_sessions() {
+	use frame_support::traits::OneSessionHandler;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 1));
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 2));
+		assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(1), 100));
+		assert_eq!(crate::Submissions::<Test>::get(0, 1), Some(100));
+
+		// The authorities are the accounts of the session keys, not of the validators.
+		let validators = [10, 11, 12, 13];
+		let session = |keys: &[u64]| {
+			validators
+				.iter()
+				.zip(keys.iter().map(|key| UintAuthorityId(*key)))
+				.collect::<Vec<_>>()
+		};
+		let keys = session(&[2, 7, 8, 9]);
+		TemplateModule::on_new_session(true, keys.clone().into_iter(), keys.into_iter());
+		// `MaxAuthorities` is 3 in the mock.
+		assert_eq!(TemplateModule::authorities().into_inner(), vec![2, 7, 8]);
+		System::assert_has_event(Event::AuthorityRemoved { who: 1 }.into());
+		System::assert_has_event(Event::AuthorityAdded { who: 8 }.into());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(crate::Submissions::<Test>::get(0, 1), None);
+		// The authorities staying keep their bond, the new ones have none.
+		assert_eq!(Balances::reserved_balance(2), 10);
+		assert_eq!(Balances::reserved_balance(7), 0);
+
+		// Nothing changes with an unchanged set.
+		let keys = session(&[9]);
+		TemplateModule::on_new_session(false, keys.clone().into_iter(), keys.into_iter());
+		assert_eq!(TemplateModule::authorities().into_inner(), vec![2, 7, 8]);
+		TemplateModule::on_genesis_session(session(&[9]).into_iter());
+		assert_eq!(TemplateModule::authorities().into_inner(), vec![9]);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn only_authorities_submit_data() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 1));
+
+		assert_noop!(
+			TemplateModule::submit_data_signed(RuntimeOrigin::signed(2), 42),
+			Error::<Test>::NotAuthority
+		);
+		assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(1), 42));
+
+		let signed_by = |id: u64| {
+			let payload = DataPayload { block_number: 6, value: 42, public: UintAuthorityId(id) };
+			let signature = UintAuthorityId(id).sign(&payload.encode()).unwrap();
+			Call::submit_payload_unsigned_with_signed_payload { payload, signature }
+		};
+		System::set_block_number(6);
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &signed_by(2)),
+			InvalidTransaction::BadSigner.into()
+		);
+		assert!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &signed_by(1)).is_ok()
+		);
+		assert_eq!(
+			TemplateModule::validate_unsigned(
+				TransactionSource::External,
+				&Call::submit_number_unsigned { block_number: 6, number: 42 }
+			),
+			InvalidTransaction::BadSigner.into()
+		);
+	});
+}
+
+#[test]
+fn report_submission_failure_emits_event() {
+	use crate::http::{HttpError, OffchainError};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let reason = FailureReason::Fetch(OffchainError::Http(HttpError::Non200Status(500)));
+		assert_ok!(TemplateModule::report_submission_failure(RuntimeOrigin::signed(1), reason));
+		System::assert_last_event(Event::OffchainSubmissionFailed { who: 1, reason }.into());
+
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 2));
+		assert_noop!(
+			TemplateModule::report_submission_failure(RuntimeOrigin::signed(1), reason),
+			Error::<Test>::NotAuthority
+		);
+	});
+}
+
+/// The calls of the transactions in the pool, along with whether they are signed.
+fn pool_calls(
+	pool: &parking_lot::RwLock<sp_core::offchain::testing::PoolState>,
+) -> Vec<(bool, RuntimeCall)> {
+	pool.read()
+		.transactions
+		.iter()
+		.map(|tx| {
+			let xt = Extrinsic::decode(&mut &tx[..]).unwrap();
+			(xt.signature.is_some(), xt.call)
+		})
+		.collect()
+}
+
+#[test]
+fn offchain_worker_submits_fetched_price_unsigned() {
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+	expect_price_request(&offchain, br#"{"value": 12.5}"#);
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		TemplateModule::offchain_worker(1);
+
+		assert_eq!(
+			pool_calls(&pool),
+			vec![(
+				false,
+				RuntimeCall::TemplateModule(Call::submit_number_unsigned {
+					block_number: 1,
+					number: 12_500_000
+				})
+			)]
+		);
+		assert_eq!(TemplateModule::fetched_value(), Some(12_500_000));
+	});
+}
+
+#[test]
+fn offchain_worker_keeps_metrics_in_local_storage() {
+	let (mut t, offchain, _pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+	expect_price_request(&offchain, br#"{"value": 12.5}"#);
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		TemplateModule::offchain_worker(1);
+
+		let metrics = TemplateModule::ocw_metrics();
+		assert_eq!(metrics.fetch_successes, 1);
+		assert_eq!(metrics.fetch_failures, 0);
+		assert_eq!(metrics.submissions, 1);
+		assert_eq!(metrics.last_processed_block, 1);
+	});
+}
+
+#[test]
+fn jitter_is_derived_from_the_seed_of_the_node() {
+	let (mut t, offchain, _pool) = new_test_ext_with_offchain();
+	MaxJitterMs::set(1_000);
+	offchain.write().seed = [1; 32];
+
+	t.execute_with(|| {
+		let jitters = (1..=8).map(TemplateModule::jitter_ms).collect::<Vec<_>>();
+		assert!(jitters.iter().all(|jitter| *jitter <= 1_000));
+		assert!(jitters.iter().any(|jitter| *jitter != jitters[0]));
+
+		// The seed is drawn once, the jitter of a block stays the same.
+		offchain.write().seed = [2; 32];
+		assert_eq!(TemplateModule::node_seed(), [1; 32]);
+		assert_eq!((1..=8).map(TemplateModule::jitter_ms).collect::<Vec<_>>(), jitters);
+	});
+}
+
+#[test]
+fn only_the_elected_authorities_fetch() {
+	let (mut t, offchain, _pool) = new_test_ext_with_offchain();
+	FetchersPerRound::set(1);
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		for who in [1, 2, 7] {
+			assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), who));
+		}
+		let seed = TemplateModule::current_round().seed;
+		let mut all = TemplateModule::elect(&seed, 3);
+		all.sort();
+		assert_eq!(all, vec![1, 2, 7]);
+		let fetchers = TemplateModule::elect(&seed, 1);
+		assert_eq!(fetchers.len(), 1);
+		assert_eq!(TemplateModule::elect(&seed, 1), fetchers);
+
+		// The worker would panic fetching the price without an expected request.
+		let idle = [1, 2, 7].into_iter().find(|who| !fetchers.contains(who)).unwrap();
+		UintAuthorityId::set_all_keys(vec![idle]);
+		TemplateModule::offchain_worker(1);
+		assert_eq!(TemplateModule::fetched_value(), None);
+
+		UintAuthorityId::set_all_keys(fetchers);
+		expect_price_request(&offchain, br#"{"value": 1}"#);
+		TemplateModule::offchain_worker(1);
+		assert_eq!(TemplateModule::fetched_value(), Some(1_000_000));
+	});
+}
+
+#[test]
+fn the_leader_of_the_round_submits_until_it_times_out() {
+	let (mut t, offchain, _pool) = new_test_ext_with_offchain();
+	LeaderTimeout::set(2);
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		for who in [1, 2, 7] {
+			assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), who));
+		}
+		let round = TemplateModule::current_round();
+		let order = TemplateModule::elect(&round.seed, 3);
+		// The round started at block 0, the leaders take turns every 2 blocks.
+		let leaders = (0..8)
+			.map(|block| TemplateModule::leader(&round, block).unwrap())
+			.collect::<Vec<_>>();
+		assert_eq!(
+			leaders,
+			vec![order[0], order[0], order[1], order[1], order[2], order[2], order[0], order[0]]
+		);
+
+		// The worker would panic fetching the price without an expected request.
+		UintAuthorityId::set_all_keys(vec![order[1]]);
+		TemplateModule::offchain_worker(1);
+		assert_eq!(TemplateModule::fetched_value(), None);
+
+		// The leader didn't submit in time, the next authority takes over.
+		System::set_block_number(2);
+		TemplateModule::on_initialize(2);
+		System::assert_last_event(Event::LeaderTimedOut { round: 0, who: order[0] }.into());
+		expect_price_request(&offchain, br#"{"value": 1}"#);
+		TemplateModule::offchain_worker(2);
+		assert_eq!(TemplateModule::fetched_value(), Some(1_000_000));
+
+		// Once the round has a value, nobody submits anymore.
+		assert_ok!(TemplateModule::submit_number_unsigned(RuntimeOrigin::none(), 2, 1_000_000));
+		assert!(!TemplateModule::is_leader(2));
+
+		// Nor is the leader timed out.
+		System::reset_events();
+		TemplateModule::on_initialize(2);
+		assert!(System::events().is_empty());
+	});
+}
+
+#[test]
+fn disabled_offchain_workers_only_log_what_they_would_do() {
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(vec![7u64]);
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		sp_io::offchain::local_storage_set(
+			sp_core::offchain::StorageKind::PERSISTENT,
+			crate::DISABLED_KEY,
+			b"1",
+		);
+		assert!(TemplateModule::is_disabled());
+
+		// The worker would panic fetching the price without an expected request.
+		TemplateModule::offchain_worker(1);
+		assert!(pool_calls(&pool).is_empty());
+		assert_eq!(TemplateModule::fetched_value(), None);
+
+		sp_io::offchain::local_storage_clear(
+			sp_core::offchain::StorageKind::PERSISTENT,
+			crate::DISABLED_KEY,
+		);
+		assert!(!TemplateModule::is_disabled());
+		expect_price_request(&offchain, br#"{"value": 1}"#);
+		TemplateModule::offchain_worker(1);
+		assert_eq!(TemplateModule::fetched_value(), Some(1_000_000));
+	});
+}
+
+#[test]
+fn fetched_values_outside_the_sanity_bounds_are_dropped() {
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+	MinValue::set(1_000_000);
+	MaxValue::set(100_000_000);
+	MaxChangePerRound::set(Perbill::from_percent(50));
+	expect_price_request(&offchain, br#"{"value": 0.5}"#);
+	expect_price_request(&offchain, br#"{"value": 20}"#);
+	expect_price_request(&offchain, br#"{"value": 14}"#);
+
+	t.execute_with(|| {
+		assert_eq!(TemplateModule::check_value(500_000), Err(AnomalyReason::BelowMin));
+		assert_eq!(TemplateModule::check_value(200_000_000), Err(AnomalyReason::AboveMax));
+		assert_eq!(TemplateModule::check_value(20_000_000), Ok(()));
+		crate::LatestRound::<Test>::put((3, 10_000_000));
+		assert_eq!(TemplateModule::check_value(20_000_000), Err(AnomalyReason::TooLargeChange));
+		assert_eq!(TemplateModule::check_value(5_000_000), Ok(()));
+
+		System::set_block_number(1);
+		TemplateModule::offchain_worker(1);
+		System::set_block_number(2);
+		TemplateModule::offchain_worker(2);
+		assert!(pool_calls(&pool).is_empty());
+		assert_eq!(
+			TemplateModule::anomalies(),
+			vec![
+				Anomaly { block_number: 1, value: 500_000, reason: AnomalyReason::BelowMin },
+				Anomaly {
+					block_number: 2,
+					value: 20_000_000,
+					reason: AnomalyReason::TooLargeChange
+				},
+			]
+		);
+
+		System::set_block_number(3);
+		TemplateModule::offchain_worker(3);
+		assert_eq!(
+			pool_calls(&pool),
+			vec![(
+				false,
+				RuntimeCall::TemplateModule(Call::submit_number_unsigned {
+					block_number: 3,
+					number: 14_000_000
+				})
+			)]
+		);
+	});
+}
+
+#[test]
+fn authorities_report_their_failures_on_chain() {
+	use sp_core::H256;
+
+	new_test_ext().execute_with(|| {
+		let context = |n: u8| H256::repeat_byte(n);
+		// Reports are rejected until there are authorities.
+		assert_noop!(
+			TemplateModule::report_failure(RuntimeOrigin::signed(1), 1, context(1)),
+			Error::<Test>::NotAuthority
+		);
+		for who in [1, 2, 7] {
+			assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), who));
+		}
+
+		System::set_block_number(1);
+		assert_noop!(
+			TemplateModule::report_failure(RuntimeOrigin::signed(8), 1, context(1)),
+			Error::<Test>::NotAuthority
+		);
+		assert_ok!(TemplateModule::report_failure(RuntimeOrigin::signed(1), 1, context(1)));
+		System::assert_last_event(
+			Event::FailureReported { who: 1, code: 1, context_hash: context(1) }.into(),
+		);
+
+		// `FailureReportInterval` is 5 in the mock.
+		System::set_block_number(5);
+		assert_noop!(
+			TemplateModule::report_failure(RuntimeOrigin::signed(1), 2, context(2)),
+			Error::<Test>::FailureReportTooSoon
+		);
+		assert_ok!(TemplateModule::report_failure(RuntimeOrigin::signed(2), 2, context(2)));
+		System::set_block_number(6);
+		assert_ok!(TemplateModule::report_failure(RuntimeOrigin::signed(1), 3, context(3)));
+
+		// `MaxFailureReports` is 2 in the mock, the oldest report is dropped.
+		let reports = TemplateModule::failure_reports();
+		assert_eq!(
+			reports.into_inner(),
+			vec![
+				crate::FailureReport { who: 2, code: 2, context_hash: context(2), reported_at: 5 },
+				crate::FailureReport { who: 1, code: 3, context_hash: context(3), reported_at: 6 },
+			]
+		);
+
+		// The authorities leaving the set leave no entry behind.
+		assert_ok!(TemplateModule::remove_authority(RuntimeOrigin::root(), 2));
+		assert_eq!(crate::LastFailureReports::<Test>::get(2), None);
+		assert_eq!(crate::LastFailureReports::<Test>::get(1), Some(6));
+	});
+}
+
+#[test]
+fn submissions_are_checked_without_changing_the_state() {
+	use crate::{SubmissionValidity, TransactionType::*};
+
+	MinValue::set(10);
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(TemplateModule::can_submit(&1, 0, 20, Raw), SubmissionValidity::Valid);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 1));
+		assert_eq!(
+			TemplateModule::can_submit(&1, 0, 20, Raw),
+			SubmissionValidity::NeedsSignedPayload
+		);
+
+		for kind in [Signed, UnsignedWithSignedPayload] {
+			assert_eq!(TemplateModule::can_submit(&1, 0, 20, kind), SubmissionValidity::Valid);
+			assert_eq!(
+				TemplateModule::can_submit(&2, 0, 20, kind),
+				SubmissionValidity::NotAuthority
+			);
+			assert_eq!(
+				TemplateModule::can_submit(&1, 1, 20, kind),
+				SubmissionValidity::WrongRound { current: 0 }
+			);
+		}
+		// The sanity checks of the offchain workers are applied once the chain accepts it.
+		assert_eq!(
+			TemplateModule::can_submit(&1, 0, 5, Signed),
+			SubmissionValidity::Anomalous(AnomalyReason::BelowMin)
+		);
+		assert_eq!(TemplateModule::can_submit(&2, 0, 5, Signed), SubmissionValidity::NotAuthority);
+		assert_eq!(crate::Submissions::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(TemplateModule::latest_value(), None);
+
+		// A single unsigned value is accepted per fetch interval.
+		crate::NextUnsignedAt::<Test>::put(2);
+		assert_eq!(
+			TemplateModule::can_submit(&1, 0, 20, UnsignedWithSignedPayload),
+			SubmissionValidity::TooEarly
+		);
+		assert_eq!(TemplateModule::can_submit(&1, 0, 20, Signed), SubmissionValidity::Valid);
+
+		// Only signed submissions are accepted once the round is closing.
+		System::set_block_number(3);
+		TemplateModule::on_initialize(3);
+		assert_eq!(
+			TemplateModule::can_submit(&1, 0, 20, UnsignedWithSignedPayload),
+			SubmissionValidity::Closing
+		);
+		assert_eq!(TemplateModule::can_submit(&1, 0, 20, Signed), SubmissionValidity::Valid);
+		TemplateModule::on_finalize(3);
+		for kind in [Signed, UnsignedWithSignedPayload] {
+			assert_eq!(TemplateModule::can_submit(&1, 0, 20, kind), SubmissionValidity::Finalized);
+		}
+
+		assert_ok!(TemplateModule::pause(RuntimeOrigin::root()));
+		assert_eq!(TemplateModule::can_submit(&1, 0, 20, Signed), SubmissionValidity::Paused);
+	});
+}
+
+#[test]
+fn the_pipeline_resumes_after_its_last_completed_stage() {
+	use crate::{PipelineState, PIPELINE_KEY};
+	use sp_runtime::offchain::storage::StorageValueRef;
+
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+	expect_price_request(&offchain, br#"{"value": 12.5}"#);
+	let submitted = |number| {
+		RuntimeCall::TemplateModule(Call::submit_number_unsigned { block_number: 2, number })
+	};
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		TemplateModule::offchain_worker(1);
+		assert_eq!(pool_calls(&pool).len(), 1);
+		assert_eq!(TemplateModule::pipeline_state(), Some(PipelineState::Submitted));
+
+		// A run fetched a price then crashed, the next one checks and submits it without
+		// fetching it again, the worker would panic fetching the price.
+		let key = TemplateModule::local_key(PIPELINE_KEY);
+		let checkpoint = StorageValueRef::persistent(&key);
+		checkpoint.set(&(0u32, 0u64, PipelineState::Fetched(13_000_000)));
+		pool.write().transactions.clear();
+		System::set_block_number(2);
+		TemplateModule::offchain_worker(2);
+		assert_eq!(pool_calls(&pool), vec![(false, submitted(13_000_000))]);
+		assert_eq!(TemplateModule::pipeline_state(), Some(PipelineState::Submitted));
+
+		// The state of another round, or of another interval, is ignored.
+		checkpoint.set(&(1u32, 0u64, PipelineState::Parsed(14_000_000)));
+		assert_eq!(TemplateModule::pipeline_state(), None);
+		checkpoint.set(&(0u32, 4u64, PipelineState::Parsed(14_000_000)));
+		assert_eq!(TemplateModule::pipeline_state(), None);
+		checkpoint.set(&(0u32, 0u64, PipelineState::Parsed(14_000_000)));
+		assert_eq!(TemplateModule::pipeline_state(), Some(PipelineState::Parsed(14_000_000)));
+	});
+}
+
+#[test]
+fn recorded_responses_of_real_apis_are_fetched() {
+	for path in fixtures() {
+		let (mut t, offchain, _pool) = new_test_ext_with_offchain();
+		UintAuthorityId::set_all_keys(Vec::<u64>::new());
+		let fixture = load_fixture(&offchain, &path);
+		PriceField::set(fixture.field);
+
+		t.execute_with(|| {
+			let endpoints = fixture
+				.urls
+				.iter()
+				.map(|url| BoundedVec::try_from(url.clone().into_bytes()).unwrap())
+				.collect::<Vec<_>>();
+			crate::OracleEndpoints::<Test>::put(BoundedVec::try_from(endpoints).unwrap());
+			System::set_block_number(1);
+			TemplateModule::offchain_worker(1);
+			assert_eq!(
+				TemplateModule::fetched_value(),
+				fixture.expected,
+				"{}: {}",
+				fixture.name,
+				fixture.description
+			);
+		});
+	}
+}
+
+#[test]
+fn offchain_worker_reuses_cached_responses() {
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+	CacheTtl::set(1_000);
+	expect_price_request(&offchain, br#"{"value": 1}"#);
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		TemplateModule::offchain_worker(1);
+
+		// No HTTP request is expected within the TTL, the worker would panic fetching the price.
+		System::set_block_number(2);
+		TemplateModule::offchain_worker(2);
+		assert_eq!(pool_calls(&pool).len(), 2);
+
+		offchain.write().timestamp = sp_core::offchain::Timestamp::from_unix_millis(1_000);
+		expect_price_request(&offchain, br#"{"value": 2}"#);
+		System::set_block_number(3);
+		TemplateModule::offchain_worker(3);
+		assert_eq!(TemplateModule::fetched_value(), Some(2_000_000));
+	});
+}
+
+#[test]
+fn offchain_worker_reports_finalized_rounds_once() {
+	use crate::http::to_hex;
+	use sp_core::offchain::testing;
+
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(vec![1u64]);
+	ReportEndpoint::set("http://localhost:8080/report");
+	let body = br#"{"round":3,"value":12500000,"decimals":6}"#.to_vec();
+	let key = UintAuthorityId(1);
+	let public = String::from_utf8(to_hex(&key.to_raw_vec())).unwrap();
+	let signature = String::from_utf8(to_hex(&key.sign(&body).unwrap().encode())).unwrap();
+	offchain.write().expect_request(testing::PendingRequest {
+		method: "POST".into(),
+		uri: "http://localhost:8080/report".into(),
+		headers: vec![
+			("Content-Type".into(), "application/json".into()),
+			("X-Public-Key".into(), public),
+			("X-Signature".into(), signature),
+		],
+		body,
+		response: Some(b"ok".to_vec()),
+		sent: true,
+		..Default::default()
+	});
+
+	t.execute_with(|| {
+		System::set_block_number(9);
+		crate::NextUnsignedAt::<Test>::put(100);
+		crate::LatestRound::<Test>::put((3, 12_500_000));
+		TemplateModule::offchain_worker(9);
+
+		// The round was reported, the worker would panic sending another request.
+		System::set_block_number(10);
+		TemplateModule::offchain_worker(10);
+		assert!(pool.read().transactions.is_empty());
+	});
+	assert_eq!(to_hex(&[0x01, 0xab]), b"0x01ab".to_vec());
+}
+
+#[test]
+fn snapshots_of_the_rounds_are_anchored_and_archived() {
+	use sp_core::offchain::testing;
+	use sp_runtime::offchain::storage::StorageValueRef;
+
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+	SnapshotPeriod::set(2);
+	ArchiveEndpoint::set("http://localhost:8080/archive");
+	let snapshot =
+		br#"{"round":1,"submissions":[{"account":"0x0700000000000000","value":10}]}"#.to_vec();
+
+	t.execute_with(|| {
+		System::set_block_number(3);
+		crate::Submissions::<Test>::insert(0, 7, 10);
+		TemplateModule::on_finalize(3);
+		// Only the last round of each period of 2 rounds is anchored.
+		assert_eq!(TemplateModule::snapshots(0), None);
+
+		System::set_block_number(4);
+		TemplateModule::on_initialize(4);
+		crate::Submissions::<Test>::insert(1, 7, 10);
+		System::set_block_number(7);
+		TemplateModule::on_finalize(7);
+		let hash = BlakeTwo256::hash(&snapshot);
+		System::assert_has_event(Event::SnapshotAnchored { round: 1, hash }.into());
+		assert!(TemplateModule::verify_snapshot(1, &snapshot));
+		assert!(!TemplateModule::verify_snapshot(1, b"{}"));
+	});
+	t.persist_offchain_overlay();
+
+	offchain.write().expect_request(testing::PendingRequest {
+		method: "POST".into(),
+		uri: "http://localhost:8080/archive".into(),
+		headers: vec![("Content-Type".into(), "application/json".into())],
+		body: snapshot,
+		response: Some(b"ok".to_vec()),
+		sent: true,
+		..Default::default()
+	});
+	t.execute_with(|| {
+		crate::NextUnsignedAt::<Test>::put(100);
+		TemplateModule::offchain_worker(7);
+		let key = TemplateModule::snapshot_key(1);
+		assert_eq!(StorageValueRef::persistent(&key).get::<Vec<u8>>().unwrap(), None);
+
+		// The snapshot was archived, the worker would panic sending another request.
+		System::set_block_number(8);
+		TemplateModule::on_initialize(8);
+		TemplateModule::offchain_worker(8);
+		assert!(pool.read().transactions.is_empty());
+	});
+}
+
+#[test]
+fn offchain_worker_follows_a_paginated_feed() {
+	use crate::FeedRecord;
+	use sp_core::offchain::testing;
+
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+	FeedEndpoint::set("http://localhost:8080/feed");
+	let expect = |since: u64, body: &[u8]| {
+		offchain.write().expect_request(testing::PendingRequest {
+			method: "GET".into(),
+			uri: format!("http://localhost:8080/feed?since={}", since),
+			response: Some(body.to_vec()),
+			sent: true,
+			..Default::default()
+		})
+	};
+	let record = |since, id, value| {
+		RuntimeCall::TemplateModule(Call::submit_record_unsigned {
+			record: FeedRecord { since, id, value },
+		})
+	};
+
+	t.execute_with(|| {
+		// The pipeline is not due, the worker only follows the feed.
+		crate::NextUnsignedAt::<Test>::put(100);
+		System::set_block_number(1);
+		expect(0, br#"{"records": [{"id": 3, "value": 1.5}, {"id": 2, "value": 2}]}"#);
+		TemplateModule::offchain_worker(1);
+		assert_eq!(
+			pool_calls(&pool),
+			vec![(false, record(0, 2, 2_000_000)), (false, record(2, 3, 1_500_000))]
+		);
+
+		// Only the records after the last one submitted are fetched.
+		pool.write().transactions.clear();
+		System::set_block_number(2);
+		expect(3, br#"{"records": []}"#);
+		TemplateModule::offchain_worker(2);
+		assert!(pool.read().transactions.is_empty());
+
+		// The records are accepted in the order of the feed.
+		let first = FeedRecord { since: 0, id: 2, value: 2_000_000 };
+		let second = FeedRecord { since: 2, id: 3, value: 1_500_000 };
+		let validity = TemplateModule::validate_unsigned(
+			TransactionSource::External,
+			&Call::submit_record_unsigned { record: second.clone() },
+		);
+		assert_eq!(validity.unwrap().requires, vec![("TemplateFeedRecord", 2u64).encode()]);
+		assert_noop!(
+			TemplateModule::submit_record_unsigned(RuntimeOrigin::none(), second.clone()),
+			Error::<Test>::OutOfOrderRecord
+		);
+		assert_ok!(TemplateModule::submit_record_unsigned(RuntimeOrigin::none(), first.clone()));
+		System::assert_last_event(Event::RecordSubmitted { id: 2, value: 2_000_000 }.into());
+		assert_eq!(TemplateModule::latest_record(), Some((2, 2_000_000)));
+		assert_eq!(
+			TemplateModule::validate_unsigned(
+				TransactionSource::External,
+				&Call::submit_record_unsigned { record: first }
+			),
+			InvalidTransaction::Stale.into()
+		);
+
+		// The second record didn't make it on chain in time, so the worker fetches it again.
+		System::set_block_number(8);
+		expect(2, br#"{"records": [{"id": 3, "value": 1.5}]}"#);
+		TemplateModule::offchain_worker(8);
+		assert_eq!(pool_calls(&pool), vec![(false, record(2, 3, 1_500_000))]);
+	});
+}
+
+#[test]
+fn feed_records_are_signed_by_the_authorities() {
+	use crate::{FeedRecord, RecordPayload};
+	use sp_core::offchain::testing;
+
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(vec![7u64]);
+	FeedEndpoint::set("http://localhost:8080/feed");
+	let record_call = |id: u64, record: FeedRecord| {
+		let payload = RecordPayload { record, public: UintAuthorityId(id) };
+		let signature = UintAuthorityId(id).sign(&payload.encode()).unwrap();
+		Call::submit_record_unsigned_with_signed_payload { payload, signature }
+	};
+	let validate = |call| TemplateModule::validate_unsigned(TransactionSource::External, &call);
+
+	t.execute_with(|| {
+		crate::NextUnsignedAt::<Test>::put(100);
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		offchain.write().expect_request(testing::PendingRequest {
+			method: "GET".into(),
+			uri: "http://localhost:8080/feed?since=0".into(),
+			response: Some(br#"{"records": [{"id": 2, "value": 2}]}"#.to_vec()),
+			sent: true,
+			..Default::default()
+		});
+		TemplateModule::offchain_worker(1);
+
+		// The worker keeps following the feed once there are authorities.
+		let record = FeedRecord { since: 0, id: 2, value: 2_000_000 };
+		let records = pool_calls(&pool)
+			.into_iter()
+			.filter(|(_, call)| {
+				matches!(
+					call,
+					RuntimeCall::TemplateModule(
+						Call::submit_record_unsigned_with_signed_payload { .. }
+					)
+				)
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(
+			records,
+			vec![(false, RuntimeCall::TemplateModule(record_call(7, record.clone())))]
+		);
+
+		assert!(validate(record_call(7, record.clone())).is_ok());
+		assert_eq!(validate(record_call(8, record.clone())), InvalidTransaction::BadSigner.into());
+		let Call::submit_record_unsigned_with_signed_payload { payload, .. } =
+			record_call(7, record.clone())
+		else {
+			unreachable!()
+		};
+		let forged = Call::submit_record_unsigned_with_signed_payload {
+			payload: payload.clone(),
+			signature: TestSignature(7, b"forged".to_vec()),
+		};
+		assert_eq!(validate(forged), InvalidTransaction::BadProof.into());
+		assert_eq!(
+			validate(Call::submit_record_unsigned { record: record.clone() }),
+			InvalidTransaction::BadSigner.into()
+		);
+
+		let signature = UintAuthorityId(7).sign(&payload.encode()).unwrap();
+		assert_ok!(TemplateModule::submit_record_unsigned_with_signed_payload(
+			RuntimeOrigin::none(),
+			payload,
+			signature
+		));
+		System::assert_last_event(Event::RecordSubmitted { id: 2, value: 2_000_000 }.into());
+		assert_eq!(TemplateModule::latest_record(), Some((2, 2_000_000)));
+		assert_eq!(validate(record_call(7, record)), InvalidTransaction::Stale.into());
+	});
+}
+
+#[test]
+fn cids_are_parsed_and_their_content_verified() {
+	use crate::ipfs::{self, CidError};
+
+	let digest = sp_io::hashing::sha2_256(b"hello ipfs");
+	assert_eq!(
+		ipfs::cid_digest(b"bafkreichphvmdj4uyj3x4bwnmmaor6vdqanqlencshsyd4wwuigbt7jy3i"),
+		Ok(digest)
+	);
+	assert_eq!(ipfs::cid_digest(b"QmT9fUoLK3juU5drvgSomPxNDiJZuzmH4sYpYhVyDNFefB"), Ok(digest));
+	assert!(ipfs::verify(&digest, b"hello ipfs"));
+	assert!(!ipfs::verify(&digest, b"hello ipns"));
+
+	assert_eq!(
+		ipfs::cid_digest(b"QmT9fUoLK3juU5drvgSomPxNDiJZuzmH4sYpYhVyDNFef0"),
+		Err(CidError::InvalidEncoding)
+	);
+	assert_eq!(ipfs::cid_digest(b"bafkrei"), Err(CidError::InvalidEncoding));
+	assert_eq!(ipfs::cid_digest(b"zdj7W"), Err(CidError::UnsupportedVersion));
+	// A CIDv1 of an identity multihash.
+	assert_eq!(ipfs::cid_digest(b"bafkqaaa"), Err(CidError::UnsupportedHash));
+}
+
+#[test]
+fn offchain_worker_anchors_verified_documents() {
+	use sp_core::offchain::testing;
+
+	const CID_V1: &[u8] = b"bafkreichphvmdj4uyj3x4bwnmmaor6vdqanqlencshsyd4wwuigbt7jy3i";
+	const CID_V0: &[u8] = b"QmT9fUoLK3juU5drvgSomPxNDiJZuzmH4sYpYhVyDNFefB";
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(vec![7u64]);
+	IpfsGateway::set("http://localhost:8080/");
+	let expect = |cid: &[u8], body: &[u8]| {
+		offchain.write().expect_request(testing::PendingRequest {
+			method: "GET".into(),
+			uri: format!("http://localhost:8080/ipfs/{}?format=raw", String::from_utf8_lossy(cid)),
+			response: Some(body.to_vec()),
+			sent: true,
+			..Default::default()
+		})
+	};
+	let cid = |cid: &[u8]| BoundedVec::truncate_from(cid.to_vec());
+	let hash = BlakeTwo256::hash(b"hello ipfs");
+	let anchor = |c| RuntimeCall::TemplateModule(Call::anchor_document { cid: cid(c), hash });
+
+	t.execute_with(|| {
+		// The pipeline is not due, the worker only anchors the documents.
+		crate::NextUnsignedAt::<Test>::put(100);
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		assert_noop!(
+			TemplateModule::request_document(RuntimeOrigin::root(), cid(b"bafkqaaa")),
+			Error::<Test>::InvalidCid
+		);
+		assert_ok!(TemplateModule::request_document(RuntimeOrigin::root(), cid(CID_V1)));
+		System::assert_last_event(Event::DocumentRequested { cid: cid(CID_V1) }.into());
+		assert_ok!(TemplateModule::request_document(RuntimeOrigin::root(), cid(CID_V0)));
+		assert_noop!(
+			TemplateModule::request_document(RuntimeOrigin::root(), cid(CID_V0)),
+			Error::<Test>::DocumentAlreadyRequested
+		);
+
+		// The gateway serves the wrong content for the CIDv0, which is not anchored.
+		expect(CID_V1, b"hello ipfs");
+		expect(CID_V0, b"hello ipns");
+		TemplateModule::offchain_worker(1);
+		let calls = pool_calls(&pool);
+		assert!(calls.contains(&(true, anchor(CID_V1))));
+		assert!(!calls.iter().any(|(_, call)| *call == anchor(CID_V0)));
+
+		// Only the document which failed its verification is fetched again.
+		pool.write().transactions.clear();
+		System::set_block_number(2);
+		expect(CID_V0, b"hello ipfs");
+		TemplateModule::offchain_worker(2);
+		let calls = pool_calls(&pool);
+		assert!(calls.contains(&(true, anchor(CID_V0))));
+		assert!(!calls.iter().any(|(_, call)| *call == anchor(CID_V1)));
+
+		assert_noop!(
+			TemplateModule::anchor_document(RuntimeOrigin::signed(8), cid(CID_V1), hash),
+			Error::<Test>::NotAuthority
+		);
+		assert_ok!(TemplateModule::anchor_document(RuntimeOrigin::signed(7), cid(CID_V1), hash));
+		System::assert_last_event(
+			Event::DocumentAnchored { cid: cid(CID_V1), hash, who: 7 }.into(),
+		);
+		assert_eq!(TemplateModule::documents(cid(CID_V1)), Some(hash));
+		assert_eq!(TemplateModule::document_requests(cid(CID_V1)), None);
+		assert_noop!(
+			TemplateModule::anchor_document(RuntimeOrigin::signed(7), cid(CID_V1), hash),
+			Error::<Test>::UnknownDocument
+		);
+		assert_noop!(
+			TemplateModule::request_document(RuntimeOrigin::root(), cid(CID_V1)),
+			Error::<Test>::DocumentAlreadyRequested
+		);
+	});
+}
+
+#[test]
+fn requests_are_fulfilled_by_the_offchain_workers() {
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(vec![7u64]);
+	QueryEndpoint::set("http://localhost:8080/query?q={QUERY}");
+	let query = |query: &[u8]| BoundedVec::truncate_from(query.to_vec());
+	let fulfill = |id, answer| RuntimeCall::TemplateModule(Call::fulfill_request { id, answer });
+	let fulfillments = || {
+		pool_calls(&pool)
+			.into_iter()
+			.filter(|(_, call)| {
+				matches!(call, RuntimeCall::TemplateModule(Call::fulfill_request { .. }))
+			})
+			.collect::<Vec<_>>()
+	};
+
+	t.execute_with(|| {
+		// The pipeline is not due, the worker only answers the requests.
+		crate::NextUnsignedAt::<Test>::put(100);
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		assert_ok!(TemplateModule::request_value(
+			RuntimeOrigin::signed(1),
+			query(b"DOT/USD"),
+			30,
+			None
+		));
+		System::assert_last_event(Event::ValueRequested { id: 0, who: 1, fee: 30 }.into());
+		assert_eq!(Balances::free_balance(1), 70);
+		assert_eq!(Balances::free_balance(TemplateModule::escrow_account()), 30);
+		assert_noop!(
+			TemplateModule::request_value(RuntimeOrigin::signed(2), query(b"KSM"), 0, None),
+			Error::<Test>::FeeTooLow
+		);
+		assert_ok!(TemplateModule::request_value(RuntimeOrigin::signed(2), query(b"KSM"), 5, None));
+		assert_noop!(
+			TemplateModule::request_value(RuntimeOrigin::signed(2), query(b"ETH"), 5, None),
+			Error::<Test>::TooManyRequests
+		);
+
+		// The query is percent-encoded into the URL.
+		mock_http_get(
+			&offchain,
+			"http://localhost:8080/query?q=DOT%2FUSD",
+			200,
+			br#"{"value": 4.5}"#,
+		);
+		mock_http_get(&offchain, "http://localhost:8080/query?q=KSM", 200, br#"{"value": 30}"#);
+		TemplateModule::offchain_worker(1);
+		let calls = fulfillments();
+		assert_eq!(calls.len(), 2);
+		assert!(calls.contains(&(true, fulfill(0, 4_500_000))));
+		assert!(calls.contains(&(true, fulfill(1, 30_000_000))));
+
+		// The answers are not sent again while their transactions may still be included.
+		pool.write().transactions.clear();
+		System::set_block_number(2);
+		TemplateModule::offchain_worker(2);
+		assert!(fulfillments().is_empty());
+
+		assert_noop!(
+			TemplateModule::fulfill_request(RuntimeOrigin::signed(8), 0, 4_500_000),
+			Error::<Test>::NotAuthority
+		);
+		assert_ok!(TemplateModule::fulfill_request(RuntimeOrigin::signed(7), 0, 4_500_000));
+		System::assert_last_event(Event::RequestFulfilled { id: 0, answer: 4_500_000 }.into());
+		assert_eq!(TemplateModule::requests(0), None);
+		assert_eq!(Balances::free_balance(7), 120);
+		assert_eq!(Balances::free_balance(TemplateModule::escrow_account()), 5);
+		assert_noop!(
+			TemplateModule::fulfill_request(RuntimeOrigin::signed(7), 0, 4_500_000),
+			Error::<Test>::UnknownRequest
+		);
+
+		// A request can be made again once another one is fulfilled.
+		assert_ok!(TemplateModule::request_value(RuntimeOrigin::signed(2), query(b"ETH"), 5, None));
+		System::assert_last_event(Event::ValueRequested { id: 2, who: 2, fee: 5 }.into());
+
+		// The request of block 1 times out after `RequestTtl` blocks, and is refunded.
+		assert_noop!(
+			TemplateModule::refund_request(RuntimeOrigin::signed(8), 1),
+			Error::<Test>::RequestNotExpired
+		);
+		System::set_block_number(11);
+		mock_http_get(&offchain, "http://localhost:8080/query?q=ETH", 200, br#"{"value": 1}"#);
+		TemplateModule::offchain_worker(11);
+		assert_eq!(fulfillments(), vec![(true, fulfill(2, 1_000_000))]);
+		assert_noop!(
+			TemplateModule::fulfill_request(RuntimeOrigin::signed(7), 1, 30_000_000),
+			Error::<Test>::RequestExpired
+		);
+		assert_ok!(TemplateModule::refund_request(RuntimeOrigin::signed(8), 1));
+		System::assert_last_event(Event::RequestRefunded { id: 1, who: 2, fee: 5 }.into());
+		assert_eq!(Balances::free_balance(2), 95);
+		assert_eq!(TemplateModule::requests(1), None);
+	});
+}
+
+#[test]
+fn timed_out_requests_are_refunded_by_the_hooks() {
+	new_test_ext().execute_with(|| {
+		let query = |query: &[u8]| BoundedVec::truncate_from(query.to_vec());
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::request_value(
+			RuntimeOrigin::signed(1),
+			query(b"DOT"),
+			30,
+			None
+		));
+		assert_ok!(TemplateModule::request_value(RuntimeOrigin::signed(2), query(b"KSM"), 5, None));
+		assert_eq!(TemplateModule::requests_by_expiry(11).into_inner(), vec![0, 1]);
+
+		// A fulfilled request is removed from the index.
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::request_value(RuntimeOrigin::signed(8), query(b"ETH"), 5, None));
+		assert_ok!(TemplateModule::fulfill_request(RuntimeOrigin::signed(7), 2, 1));
+		assert!(TemplateModule::requests_by_expiry(12).is_empty());
+
+		// Only `MaxExpiriesPerBlock` requests are refunded in `on_initialize`, the other one is
+		// left over for the next block.
+		System::set_block_number(11);
+		TemplateModule::on_initialize(11);
+		System::assert_last_event(Event::RequestExpired { id: 0, who: 1, fee: 30 }.into());
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(TemplateModule::requests(0), None);
+		assert!(TemplateModule::requests(1).is_some());
+		assert_eq!(TemplateModule::requests_by_expiry(12).into_inner(), vec![1]);
+
+		// It is refunded in `on_idle` if the block has weight left.
+		TemplateModule::on_idle(11, Weight::zero());
+		assert!(TemplateModule::requests(1).is_some());
+		TemplateModule::on_idle(11, Weight::MAX);
+		System::assert_last_event(Event::RequestExpired { id: 1, who: 2, fee: 5 }.into());
+		assert_eq!(Balances::free_balance(2), 100);
+		assert!(TemplateModule::requests_by_expiry(12).is_empty());
+		assert_eq!(Balances::free_balance(TemplateModule::escrow_account()), 0);
+	});
+}
+
+#[test]
+fn callbacks_are_dispatched_with_the_answer_of_their_request() {
+	new_test_ext().execute_with(|| {
+		// A callback is a call without its last 16 bytes, the id and the answer of the request.
+		let callback = |call: RuntimeCall| {
+			let encoded = call.encode();
+			BoundedVec::truncate_from(encoded[..encoded.len() - 16].to_vec())
+		};
+		let query = |query: &[u8]| BoundedVec::truncate_from(query.to_vec());
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		assert_noop!(
+			TemplateModule::request_value(
+				RuntimeOrigin::signed(1),
+				query(b"DOT"),
+				30,
+				Some(BoundedVec::truncate_from(vec![0xff]))
+			),
+			Error::<Test>::InvalidCallback
+		);
+		let fulfill = Call::fulfill_request { id: 0, answer: 0 };
+		assert_noop!(
+			TemplateModule::request_value(
+				RuntimeOrigin::signed(1),
+				query(b"DOT"),
+				30,
+				Some(callback(RuntimeCall::TemplateModule(fulfill)))
+			),
+			Error::<Test>::CallbackTooHeavy
+		);
+
+		// A remark of 16 bytes is made of the id and the answer.
+		let remark = frame_system::Call::remark_with_event { remark: vec![0; 16] };
+		assert_ok!(TemplateModule::request_value(
+			RuntimeOrigin::signed(1),
+			query(b"DOT"),
+			30,
+			Some(callback(RuntimeCall::System(remark)))
+		));
+		assert_ok!(TemplateModule::fulfill_request(RuntimeOrigin::signed(7), 0, 4_500_000));
+		System::assert_has_event(Event::RequestFulfilled { id: 0, answer: 4_500_000 }.into());
+		let hash = BlakeTwo256::hash(&(0u64, 4_500_000u64).encode());
+		System::assert_has_event(frame_system::Event::Remarked { sender: 1, hash }.into());
+		System::assert_last_event(Event::CallbackDispatched { id: 0 }.into());
+
+		// A failed callback leaves the request fulfilled.
+		let unreserve = pallet_balances::Call::force_unreserve { who: 0, amount: 0 };
+		assert_ok!(TemplateModule::request_value(
+			RuntimeOrigin::signed(2),
+			query(b"KSM"),
+			5,
+			Some(callback(RuntimeCall::Balances(unreserve)))
+		));
+		assert_ok!(TemplateModule::fulfill_request(RuntimeOrigin::signed(7), 1, 1));
+		System::assert_last_event(Event::CallbackFailed { id: 1, error: BadOrigin.into() }.into());
+		assert_eq!(TemplateModule::requests(1), None);
+		assert_eq!(Balances::free_balance(7), 125);
+	});
+}
+
+#[test]
+fn payloads_larger_than_max_ocw_payload_are_rejected() {
+	use crate::{
+		http::{self, OffchainError},
+		Attestation, AttesterKey,
+	};
+	use sp_core::{ed25519, Pair};
+
+	let (mut t, offchain, _pool) = new_test_ext_with_offchain();
+	MaxOcwPayload::set(16);
+	t.execute_with(|| {
+		let fetch = || http::fetch(HttpEndpoint::get(), 2_000, MaxOcwPayload::get(), 0);
+		expect_price_request(&offchain, br#"{"value": 12.5}"#);
+		assert_eq!(fetch(), Ok(br#"{"value": 12.5}"#.to_vec()));
+		expect_price_request(&offchain, br#"{"value": 12.125}"#);
+		assert_eq!(fetch(), Err(OffchainError::ResponseTooLarge));
+
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		let pair = ed25519::Pair::from_seed(&[1; 32]);
+		let signer = AttesterKey::Ed25519(pair.public());
+		assert_ok!(TemplateModule::set_attester(RuntimeOrigin::root(), signer.clone(), true));
+		let data: BoundedVec<u8, _> = BoundedVec::truncate_from(b"price=42".to_vec());
+		let signature = Attestation::Ed25519(pair.sign(&data));
+		MaxOcwPayload::set(7);
+		assert_noop!(
+			TemplateModule::submit_attested_data(RuntimeOrigin::signed(7), data, signature, signer),
+			Error::<Test>::PayloadTooLarge
+		);
+	});
+}
+
+#[test]
+fn endpoints_are_requested_at_most_every_min_request_interval() {
+	use crate::http::{self, OffchainError};
+
+	let (mut t, offchain, _pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+	MinRequestIntervalMs::set(1_000);
+	expect_price_request(&offchain, br#"{"value": 1}"#);
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		TemplateModule::offchain_worker(1);
+		assert_eq!(TemplateModule::fetched_value(), Some(1_000_000));
+
+		// No request is sent within the interval, the worker would panic fetching the price.
+		System::set_block_number(2);
+		TemplateModule::offchain_worker(2);
+		assert_eq!(TemplateModule::fetched_value(), Some(1_000_000));
+		assert_eq!(
+			http::fetch(HttpEndpoint::get(), 2_000, MaxOcwPayload::get(), 1_000),
+			Err(OffchainError::RateLimited)
+		);
+
+		offchain.write().timestamp = sp_core::offchain::Timestamp::from_unix_millis(1_000);
+		expect_price_request(&offchain, br#"{"value": 2}"#);
+		System::set_block_number(3);
+		TemplateModule::offchain_worker(3);
+		assert_eq!(TemplateModule::fetched_value(), Some(2_000_000));
+	});
+}
+
+#[test]
+fn attested_data_is_verified_against_a_trusted_signer() {
+	use crate::{Attestation, AttesterKey};
+	use sp_core::{ecdsa, ed25519, Pair};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		let data: BoundedVec<u8, _> = BoundedVec::truncate_from(b"price=42".to_vec());
+		let ed25519 = ed25519::Pair::from_seed(&[1; 32]);
+		let ecdsa = ecdsa::Pair::from_seed(&[2; 32]);
+		let (ed25519_key, ecdsa_key) =
+			(AttesterKey::Ed25519(ed25519.public()), AttesterKey::Ecdsa(ecdsa.public()));
+		let ed25519_signature = Attestation::Ed25519(ed25519.sign(&data));
+		let ecdsa_signature = Attestation::Ecdsa(ecdsa.sign(&data));
+		let submit = |who, signature: &Attestation, signer: &AttesterKey| {
+			TemplateModule::submit_attested_data(
+				RuntimeOrigin::signed(who),
+				data.clone(),
+				signature.clone(),
+				signer.clone(),
+			)
+		};
+
+		assert_noop!(
+			TemplateModule::set_attester(RuntimeOrigin::signed(7), ed25519_key.clone(), true),
+			BadOrigin
+		);
+		assert_noop!(submit(7, &ed25519_signature, &ed25519_key), Error::<Test>::UntrustedAttester);
+		assert_ok!(TemplateModule::set_attester(RuntimeOrigin::root(), ed25519_key.clone(), true));
+		System::assert_last_event(
+			Event::AttesterSet { signer: ed25519_key.clone(), trusted: true }.into(),
+		);
+		assert_ok!(TemplateModule::set_attester(RuntimeOrigin::root(), ecdsa_key.clone(), true));
+
+		// Only the authorities submit attested data.
+		assert_noop!(submit(8, &ed25519_signature, &ed25519_key), Error::<Test>::NotAuthority);
+		// A signature by another signer, of other data or of the other scheme is rejected.
+		assert_noop!(submit(7, &ecdsa_signature, &ed25519_key), Error::<Test>::BadAttestation);
+		assert_noop!(
+			submit(7, &Attestation::Ed25519(ed25519.sign(b"price=43")), &ed25519_key),
+			Error::<Test>::BadAttestation
+		);
+		assert_noop!(
+			submit(
+				7,
+				&Attestation::Ecdsa(ecdsa::Pair::from_seed(&[3; 32]).sign(&data)),
+				&ecdsa_key
+			),
+			Error::<Test>::BadAttestation
+		);
+
+		assert_ok!(submit(7, &ed25519_signature, &ed25519_key));
+		System::assert_last_event(
+			Event::DataAttested { signer: ed25519_key.clone(), who: 7, len: 8 }.into(),
+		);
+		assert_ok!(submit(7, &ecdsa_signature, &ecdsa_key));
+		assert_eq!(TemplateModule::attested_data(&ed25519_key), Some((data.clone(), 1)));
+		assert_eq!(TemplateModule::attested_data(&ecdsa_key), Some((data.clone(), 1)));
+
+		// The data attested by a signer no longer trusted is kept.
+		assert_ok!(TemplateModule::set_attester(RuntimeOrigin::root(), ecdsa_key.clone(), false));
+		assert_noop!(submit(7, &ecdsa_signature, &ecdsa_key), Error::<Test>::UntrustedAttester);
+		assert_eq!(TemplateModule::attested_data(&ecdsa_key), Some((data.clone(), 1)));
+	});
+}
+
+#[test]
+fn offchain_worker_alternates_signed_transactions_and_signed_payloads() {
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(vec![7u64]);
+	expect_price_request(&offchain, br#"{"value": 3}"#);
+	expect_price_request(&offchain, br#"{"value": 4}"#);
+
+	t.execute_with(|| {
+		System::set_block_number(2);
+		TemplateModule::offchain_worker(2);
+		assert_eq!(
+			pool_calls(&pool),
+			vec![(
+				true,
+				RuntimeCall::TemplateModule(Call::submit_data_signed { value: 3_000_000 })
+			)]
+		);
+
+		pool.write().transactions.clear();
+		System::set_block_number(3);
+		TemplateModule::offchain_worker(3);
+		let calls = pool_calls(&pool);
+		assert_eq!(calls.len(), 1);
+		match &calls[0] {
+			(
+				false,
+				RuntimeCall::TemplateModule(Call::submit_payload_unsigned_with_signed_payload {
+					payload,
+					signature,
+				}),
+			) => {
+				assert_eq!(
+					payload,
+					&DataPayload { block_number: 3, value: 4_000_000, public: UintAuthorityId(7) }
+				);
+				assert_eq!(signature, &TestSignature(7, payload.encode()));
+			},
+			other => panic!("unexpected transaction: {:?}", other),
+		}
+	});
+}
+
+#[test]
+fn signed_values_are_submitted_once_per_round_and_account() {
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(vec![7u64]);
+	expect_price_request(&offchain, br#"{"value": 3}"#);
+	expect_price_request(&offchain, br#"{"value": 4}"#);
+	expect_price_request(&offchain, br#"{"value": 5}"#);
+
+	t.execute_with(|| {
+		System::set_block_number(2);
+		TemplateModule::offchain_worker(2);
+		assert_eq!(pool_calls(&pool).len(), 1);
+
+		// The worker of a later block of the same round doesn't submit again.
+		pool.write().transactions.clear();
+		System::set_block_number(4);
+		TemplateModule::offchain_worker(4);
+		assert!(pool.read().transactions.is_empty());
+
+		crate::CurrentRound::<Test>::mutate(|round| round.index = 1);
+		System::set_block_number(6);
+		TemplateModule::offchain_worker(6);
+		assert_eq!(
+			pool_calls(&pool),
+			vec![(
+				true,
+				RuntimeCall::TemplateModule(Call::submit_data_signed { value: 5_000_000 })
+			)]
+		);
+
+		// Only one of two concurrent workers claims the submission of an account.
+		assert!(!TemplateModule::claim_submission(&7, 1));
+		assert!(TemplateModule::claim_submission(&8, 1));
+		assert!(!TemplateModule::claim_submission(&8, 1));
+		assert!(!TemplateModule::claim_submission(&8, 0));
+	});
+}
+
+#[test]
+fn offchain_worker_submits_queued_value_instead_of_fetching() {
+	let (mut t, _offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::write_key_to_ocs(RuntimeOrigin::signed(1), 42));
+	});
+	t.persist_offchain_overlay();
+
+	t.execute_with(|| {
+		// No HTTP request is expected, the worker would panic fetching the price.
+		TemplateModule::offchain_worker(1);
+		assert_eq!(
+			pool_calls(&pool),
+			vec![(
+				false,
+				RuntimeCall::TemplateModule(Call::submit_number_unsigned {
+					block_number: 1,
+					number: 42
+				})
+			)]
+		);
+	});
+}
+
+#[test]
+fn batches_are_indexed_and_read_back() {
+	let (mut t, _offchain, _pool) = new_test_ext_with_offchain();
+	let entry = |label: &[u8], value| (BoundedVec::try_from(label.to_vec()).unwrap(), value);
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		let batch =
+			BoundedVec::try_from(vec![entry(b"a", 1), entry(b"b", 2), entry(b"c", 3)]).unwrap();
+		assert_ok!(TemplateModule::write_batch_to_ocs(RuntimeOrigin::signed(1), batch));
+		System::assert_last_event(Event::BatchIndexed { who: 1, block: 1, count: 3 }.into());
+		let batch = BoundedVec::try_from(vec![entry(b"d", 4)]).unwrap();
+		assert_ok!(TemplateModule::write_batch_to_ocs(RuntimeOrigin::signed(2), batch));
+
+		// `MaxBatch` is 4 in the mock.
+		let batch = BoundedVec::try_from(vec![entry(b"e", 5)]).unwrap();
+		assert_noop!(
+			TemplateModule::write_batch_to_ocs(RuntimeOrigin::signed(1), batch),
+			Error::<Test>::TooManyBatchEntries
+		);
+		assert_eq!(TemplateModule::batch_commitments(1).len(), 4);
+		assert_eq!(
+			TemplateModule::indexed_keys(1, 1)[3],
+			TemplateModule::task_key(KeyKind::BatchEntry, 1, 3)
+		);
+	});
+	t.persist_offchain_overlay();
+
+	t.execute_with(|| {
+		assert_eq!(
+			TemplateModule::read_batch(1),
+			vec![entry(b"a", 1), entry(b"b", 2), entry(b"c", 3), entry(b"d", 4)]
+		);
+		// The entries are cleared once read.
+		assert!(TemplateModule::read_batch(1).is_empty());
+	});
+}
+
+#[test]
+fn tasks_under_legacy_keys_are_found_and_migrated() {
+	use sp_core::offchain::StorageKind;
+	let (mut t, _offchain, _pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+	LegacyKeysUntil::set(2);
+
+	t.execute_with(|| {
+		for block in 1..=2 {
+			System::set_block_number(block);
+			assert_ok!(TemplateModule::queue_task(
+				RuntimeOrigin::signed(1),
+				Task::SubmitValue(block)
+			));
+		}
+		// Keep the pipeline from fetching the price.
+		crate::NextUnsignedAt::<Test>::put(100);
+	});
+	t.persist_offchain_overlay();
+
+	let get = |key: &[u8]| sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, key);
+	let legacy = |block: u64| crate::keys::legacy_key(b"", &block, 0);
+	let current = |block| TemplateModule::task_key(KeyKind::SubmitValue, block, 0);
+	t.execute_with(|| {
+		assert_eq!(
+			legacy(1),
+			[b"template::indexing::/".as_slice(), &[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]].concat()
+		);
+		// The tasks were indexed under the legacy keys by a node of an earlier version.
+		for block in 1..=2 {
+			let bytes = get(&current(block)).unwrap();
+			sp_io::offchain::local_storage_clear(StorageKind::PERSISTENT, &current(block));
+			sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, &legacy(block), &bytes);
+		}
+
+		// Only the tasks of the blocks before `LegacyKeysUntil` are looked up under both keys.
+		let (key, bytes) = TemplateModule::find_task_entry(KeyKind::SubmitValue, 1, 0);
+		assert_eq!(key, legacy(1));
+		assert!(bytes.is_some());
+		assert_eq!(TemplateModule::find_task_entry(KeyKind::SubmitValue, 2, 0), (current(2), None));
+
+		System::set_block_number(3);
+		TemplateModule::offchain_worker(3);
+		assert!(get(&current(1)).is_some());
+		assert!(get(&legacy(1)).is_none());
+		assert!(get(&legacy(2)).is_some());
+		assert_eq!(TemplateModule::find_task_entry(KeyKind::SubmitValue, 1, 0).0, current(1));
+	});
+}
+
+#[test]
+fn indexed_entries_can_be_cleared() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::queue_task(RuntimeOrigin::signed(1), Task::FetchPrice));
+		let batch =
+			BoundedVec::try_from(vec![(BoundedVec::try_from(b"a".to_vec()).unwrap(), 1)]).unwrap();
+		assert_ok!(TemplateModule::write_batch_to_ocs(RuntimeOrigin::signed(1), batch));
+
+		assert_noop!(TemplateModule::clear_ocs_key(RuntimeOrigin::signed(1), 1), BadOrigin);
+		assert_noop!(
+			TemplateModule::clear_ocs_key(RuntimeOrigin::root(), 2),
+			Error::<Test>::NothingToClear
+		);
+		assert_ok!(TemplateModule::clear_ocs_key(RuntimeOrigin::root(), 1));
+		System::assert_last_event(
+			Event::OffchainEntriesCleared { block_number: 1, count: 2 }.into(),
+		);
+		assert!(TemplateModule::indexed_keys(1, 1).is_empty());
+		assert_eq!(TemplateModule::index_commitments(1).len(), 1);
+	});
+}
+
+#[test]
+fn offchain_worker_collects_expired_entries() {
+	let (mut t, _offchain, _pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+	let batch =
+		|| BoundedVec::try_from(vec![(BoundedVec::try_from(b"a".to_vec()).unwrap(), 1)]).unwrap();
+
+	t.execute_with(|| {
+		for block in 1..=2 {
+			System::set_block_number(block);
+			assert_ok!(TemplateModule::write_batch_to_ocs(RuntimeOrigin::signed(1), batch()));
+		}
+		// Keep the pipeline from fetching the price.
+		crate::NextUnsignedAt::<Test>::put(100);
+	});
+	t.persist_offchain_overlay();
+
+	let indexed = |block| {
+		let key = TemplateModule::task_key(KeyKind::BatchEntry, block, 0);
+		sp_io::offchain::local_storage_get(sp_core::offchain::StorageKind::PERSISTENT, &key)
+			.is_some()
+	};
+	t.execute_with(|| {
+		// `RetentionBlocks` is 4 in the mock, blocks before 2 have expired at block 6.
+		System::set_block_number(6);
+		TemplateModule::offchain_worker(6);
+		assert!(!indexed(1));
+		assert!(indexed(2));
+
+		System::set_block_number(7);
+		TemplateModule::offchain_worker(7);
+		assert!(!indexed(2));
+	});
+}
+
+#[test]
+fn offchain_worker_reports_failures_and_backs_off() {
+	use crate::http::OffchainError;
+
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(vec![7u64]);
+	expect_price_request(&offchain, br#"{"price": 1}"#);
+	expect_price_request(&offchain, br#"{"price": 1}"#);
+
+	t.execute_with(|| {
+		System::set_block_number(2);
+		TemplateModule::offchain_worker(2);
+		assert_eq!(
+			pool_calls(&pool),
+			vec![(
+				true,
+				RuntimeCall::TemplateModule(Call::report_submission_failure {
+					reason: FailureReason::Fetch(OffchainError::ParseFailed)
+				})
+			)]
+		);
+
+		// The worker retries on the next block, then backs off for two blocks.
+		pool.write().transactions.clear();
+		System::set_block_number(3);
+		TemplateModule::offchain_worker(3);
+		assert_eq!(pool_calls(&pool).len(), 1);
+
+		pool.write().transactions.clear();
+		System::set_block_number(4);
+		TemplateModule::offchain_worker(4);
+		assert!(pool_calls(&pool).is_empty());
+	});
+}
+
+#[test]
+fn offchain_worker_waits_for_unsigned_interval() {
+	let (mut t, _offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(1), 42));
+
+		// No HTTP request is expected before block 6.
+		System::set_block_number(5);
+		TemplateModule::offchain_worker(5);
+		assert!(pool_calls(&pool).is_empty());
+	});
+}
+
+#[test]
+fn submit_data_reserves_a_deposit() {
+	use frame_support::traits::ReservableCurrency;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let data = |len: usize| BoundedVec::try_from(vec![7u8; len]).unwrap();
+
+		// The deposit is 10 plus 1 per byte in the mock.
+		assert_ok!(TemplateModule::submit_data(RuntimeOrigin::signed(1), data(5)));
+		assert_eq!(Balances::reserved_balance(1), 15);
+		System::assert_last_event(Event::DataStored { who: 1, len: 5, deposit: 15 }.into());
+
+		assert_ok!(TemplateModule::submit_data(RuntimeOrigin::signed(1), data(16)));
+		assert_eq!(Balances::reserved_balance(1), 26);
+		assert_ok!(TemplateModule::submit_data(RuntimeOrigin::signed(1), data(2)));
+		assert_eq!(Balances::reserved_balance(1), 12);
+		assert_eq!(TemplateModule::submitted_data(1).unwrap().data, data(2));
+
+		// Account 3 has no funds.
+		assert!(TemplateModule::submit_data(RuntimeOrigin::signed(3), data(1)).is_err());
+
+		assert_ok!(TemplateModule::clear_data(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		System::assert_last_event(Event::DataCleared { who: 1, deposit: 12 }.into());
+		assert_noop!(TemplateModule::clear_data(RuntimeOrigin::signed(1)), Error::<Test>::NoData);
+	});
+}
+
+#[test]
+fn queued_task_deposits_are_refunded_on_confirmation() {
+	use frame_support::traits::ReservableCurrency;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// The deposit is 5 per task in the mock.
+		assert_ok!(TemplateModule::write_key_to_ocs(RuntimeOrigin::signed(1), 42));
+		assert_ok!(TemplateModule::queue_task(RuntimeOrigin::signed(2), Task::FetchPrice));
+		assert_eq!(Balances::reserved_balance(1), 5);
+		assert_eq!(Balances::reserved_balance(2), 5);
+		assert_eq!(TemplateModule::index_deposits(1).len(), 2);
+
+		// Account 3 has no funds.
+		assert!(TemplateModule::queue_task(RuntimeOrigin::signed(3), Task::FetchPrice).is_err());
+
+		assert_noop!(
+			TemplateModule::confirm_tasks(RuntimeOrigin::signed(7), 1),
+			Error::<Test>::TooEarly
+		);
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::confirm_tasks(RuntimeOrigin::signed(7), 1));
+		System::assert_last_event(
+			Event::TasksConfirmed { who: 7, block_number: 1, count: 2 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_noop!(
+			TemplateModule::confirm_tasks(RuntimeOrigin::signed(7), 1),
+			Error::<Test>::NothingToConfirm
+		);
+	});
+}
+
+#[test]
+fn deposits_of_pruned_tasks_are_reclaimed() {
+	use frame_support::traits::ReservableCurrency;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let data = BoundedVec::try_from(vec![7u8; 5]).unwrap();
+		// The deposit is 5 per task, and 10 plus 1 per byte of data in the mock.
+		assert_ok!(TemplateModule::write_key_to_ocs(RuntimeOrigin::signed(1), 42));
+		assert_ok!(TemplateModule::submit_data(RuntimeOrigin::signed(1), data));
+		assert_eq!(TemplateModule::deposits(1), DepositInfo { reserved: 20, reclaimable: 0 });
+		assert_noop!(
+			TemplateModule::reclaim_deposit(RuntimeOrigin::signed(1)),
+			Error::<Test>::NothingToReclaim
+		);
+
+		// `CommitmentRetention` is 10 in the mock, and the task was never confirmed.
+		TemplateModule::on_idle(12, Weight::MAX);
+		assert!(TemplateModule::index_deposits(1).is_empty());
+		assert_eq!(TemplateModule::deposits(1), DepositInfo { reserved: 20, reclaimable: 5 });
+
+		assert_ok!(TemplateModule::reclaim_deposit(RuntimeOrigin::signed(1)));
+		System::assert_last_event(Event::DepositReclaimed { who: 1, amount: 5 }.into());
+		assert_eq!(Balances::reserved_balance(1), 15);
+		assert_eq!(TemplateModule::deposits(1), DepositInfo { reserved: 15, reclaimable: 0 });
+
+		assert_ok!(TemplateModule::clear_data(RuntimeOrigin::signed(1)));
+		assert!(!crate::Deposits::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn pending_tasks_are_listed_until_confirmed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		assert_ok!(TemplateModule::queue_task(RuntimeOrigin::signed(1), Task::FetchPrice));
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::write_key_to_ocs(RuntimeOrigin::signed(1), 42));
+		assert_ok!(TemplateModule::queue_task(RuntimeOrigin::signed(2), Task::FetchPrice));
+		assert_eq!(TemplateModule::pending_tasks(), vec![(1, 2), (3, 1)]);
+
+		System::set_block_number(4);
+		assert_ok!(TemplateModule::confirm_tasks(RuntimeOrigin::signed(7), 1));
+		assert_eq!(TemplateModule::pending_tasks(), vec![(3, 1)]);
+		// `MaxIndexedPerBlock` is 2 in the mock, the weight of the missing task is refunded.
+		let confirmed = TemplateModule::confirm_tasks(RuntimeOrigin::signed(7), 3).unwrap();
+		assert_eq!(confirmed.actual_weight, Some(<() as crate::WeightInfo>::confirm_tasks(1)));
+		assert_eq!(TemplateModule::pending_tasks(), vec![]);
+	});
+}
+
+#[test]
+fn scheduled_tasks_are_run_by_the_worker_of_their_block() {
+	let (mut t, _offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TemplateModule::schedule_task(RuntimeOrigin::signed(1), 2, Task::SubmitValue(42)),
+			BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::schedule_task(RuntimeOrigin::root(), 1, Task::SubmitValue(42)),
+			Error::<Test>::BlockInThePast
+		);
+		assert_ok!(TemplateModule::schedule_task(RuntimeOrigin::root(), 2, Task::SubmitValue(42)));
+		System::assert_last_event(
+			Event::TaskScheduled { at: 2, task: Task::SubmitValue(42) }.into(),
+		);
+		// `MaxIndexedPerBlock` is 2 in the mock.
+		let notify = Task::Notify(BoundedVec::truncate_from(b"hello".to_vec()));
+		assert_ok!(TemplateModule::schedule_task(RuntimeOrigin::signed(9), 2, notify));
+		assert_noop!(
+			TemplateModule::schedule_task(RuntimeOrigin::root(), 2, Task::FetchPrice),
+			Error::<Test>::TooManyIndexedEntries
+		);
+
+		// The worker of block 1 has nothing to run, it would panic fetching the price.
+		crate::NextUnsignedAt::<Test>::put(2);
+		TemplateModule::offchain_worker(1);
+		assert!(pool.read().transactions.is_empty());
+
+		// The scheduled value is submitted, scheduled tasks need no confirmation.
+		System::set_block_number(2);
+		TemplateModule::offchain_worker(2);
+		assert_eq!(
+			pool_calls(&pool),
+			vec![(
+				false,
+				RuntimeCall::TemplateModule(Call::submit_number_unsigned {
+					block_number: 2,
+					number: 42
+				})
+			)]
+		);
+
+		System::set_block_number(3);
+		TemplateModule::on_initialize(3);
+		assert!(TemplateModule::scheduled_tasks(2).is_empty());
+	});
+}
+
+#[test]
+fn the_scheduler_seeds_the_work_of_the_current_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_noop!(
+			TemplateModule::execute_task(RuntimeOrigin::signed(9), Task::FetchPrice),
+			BadOrigin
+		);
+		assert_ok!(TemplateModule::execute_task(RuntimeOrigin::root(), Task::FetchPrice));
+		System::assert_last_event(Event::TaskScheduled { at: 5, task: Task::FetchPrice }.into());
+		assert_eq!(TemplateModule::scheduled_tasks(5).into_inner(), vec![Task::FetchPrice]);
+	});
+}
+
+#[test]
+fn heartbeats_are_validated() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(12);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		let heartbeat_call = |id: u64, block_number: u64| {
+			let heartbeat = HeartbeatPayload { block_number, public: UintAuthorityId(id) };
+			let signature = UintAuthorityId(id).sign(&heartbeat.encode()).unwrap();
+			Call::heartbeat { heartbeat, signature }
+		};
+		let validate = |call| TemplateModule::validate_unsigned(TransactionSource::External, &call);
+
+		assert!(validate(heartbeat_call(7, 12)).is_ok());
+		assert_eq!(validate(heartbeat_call(8, 12)), InvalidTransaction::BadSigner.into());
+		assert_eq!(validate(heartbeat_call(7, 13)), InvalidTransaction::Future.into());
+		// `HeartbeatPeriod` is 10 in the mock.
+		assert_eq!(validate(heartbeat_call(7, 2)), InvalidTransaction::Stale.into());
+
+		let Call::heartbeat { heartbeat, signature } = heartbeat_call(7, 12) else {
+			unreachable!()
+		};
+		assert_ok!(TemplateModule::heartbeat(RuntimeOrigin::none(), heartbeat, signature));
+		assert_eq!(TemplateModule::last_seen(7), Some(12));
+		System::assert_last_event(Event::HeartbeatReceived { who: 7, block_number: 12 }.into());
+
+		// A single heartbeat is accepted per period.
+		System::set_block_number(15);
+		assert_eq!(validate(heartbeat_call(7, 15)), InvalidTransaction::Stale.into());
+	});
+}
+
+#[test]
+fn commitments_expire_after_the_reveal_window() {
+	new_test_ext().execute_with(|| {
+		RevealWindow::set(3);
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 1));
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 2));
+		let salt = [7; 32];
+		let hash = TemplateModule::reveal_hash(42, &salt);
+		assert_ok!(TemplateModule::commit_value(RuntimeOrigin::signed(1), hash));
+		assert_ok!(TemplateModule::commit_value(RuntimeOrigin::signed(2), hash));
+
+		// `RevealDelay` is 2 in the mock, the values can be revealed up to block 6.
+		assert_eq!(TemplateModule::commitment_expiry(1), Some(7));
+		System::set_block_number(6);
+		assert_ok!(TemplateModule::reveal_value(RuntimeOrigin::signed(1), 42, salt));
+		// The authority commits again before its first commitment expires.
+		assert_ok!(TemplateModule::commit_value(RuntimeOrigin::signed(1), hash));
+
+		System::set_block_number(7);
+		TemplateModule::on_initialize(7);
+		System::assert_has_event(Event::CommitmentExpired { who: 2, slashed: 20 }.into());
+		assert_eq!(TemplateModule::commitments(2), None);
+		assert_eq!(Balances::reserved_balance(2), 10);
+		assert!(TemplateModule::commitments(1).is_some());
+		assert_eq!(Balances::reserved_balance(1), 30);
+		assert_noop!(
+			TemplateModule::reveal_value(RuntimeOrigin::signed(2), 42, salt),
+			Error::<Test>::NoCommitment
+		);
+	});
+}
+
+#[test]
+fn a_paused_pallet_accepts_no_values_and_its_workers_stop() {
+	let (mut t, _offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(vec![7u64]);
+
+	t.execute_with(|| {
+		RevealWindow::set(3);
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		let salt = [7; 32];
+		assert_ok!(TemplateModule::commit_value(
+			RuntimeOrigin::signed(7),
+			TemplateModule::reveal_hash(42, &salt)
+		));
+
+		assert_noop!(TemplateModule::pause(RuntimeOrigin::signed(7)), BadOrigin);
+		assert_ok!(TemplateModule::pause(RuntimeOrigin::root()));
+		System::assert_last_event(Event::Paused.into());
+		assert!(TemplateModule::is_paused());
+
+		assert_noop!(
+			TemplateModule::submit_data_signed(RuntimeOrigin::signed(7), 42),
+			Error::<Test>::FeedPaused
+		);
+		assert_noop!(
+			TemplateModule::submit_number_unsigned(RuntimeOrigin::none(), 1, 42),
+			Error::<Test>::FeedPaused
+		);
+		assert_noop!(
+			TemplateModule::reveal_value(RuntimeOrigin::signed(7), 42, salt),
+			Error::<Test>::FeedPaused
+		);
+		assert_eq!(
+			TemplateModule::validate_unsigned(
+				TransactionSource::External,
+				&Call::submit_number_unsigned { block_number: 1, number: 42 }
+			),
+			InvalidTransaction::Call.into()
+		);
+
+		// The worker of the authority would send a heartbeat at least.
+		TemplateModule::offchain_worker(1);
+		assert!(pool.read().transactions.is_empty());
+
+		// The commitment can't be revealed, so its deposit is refunded rather than slashed.
+		System::set_block_number(7);
+		TemplateModule::on_initialize(7);
+		System::assert_has_event(Event::CommitmentExpired { who: 7, slashed: 0 }.into());
+		assert_eq!(Balances::reserved_balance(7), 10);
+
+		assert_ok!(TemplateModule::unpause(RuntimeOrigin::root()));
+		System::assert_last_event(Event::Unpaused.into());
+		assert!(!TemplateModule::is_paused());
+		assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(7), 42));
+	});
+}
+
+#[test]
+fn the_worker_leaves_its_remaining_stages_to_the_next_block_once_out_of_time() {
+	use crate::{WorkerStage, STAGE_KEY};
+	use sp_core::offchain::testing;
+	use sp_runtime::offchain::storage::StorageValueRef;
+
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+	offchain.write().seed = [1; 32];
+	MaxJitterMs::set(1_000);
+	WorkerBudgetMs::set(1);
+	FeedEndpoint::set("http://localhost:8080/feed");
+	expect_price_request(&offchain, br#"{"value": 12.5}"#);
+
+	t.execute_with(|| {
+		// The jitter of the pipeline uses up the budget, the feed is not fetched.
+		assert!(TemplateModule::jitter_ms(1) > 0);
+		System::set_block_number(1);
+		TemplateModule::offchain_worker(1);
+		assert_eq!(pool.read().transactions.len(), 1);
+		let key = TemplateModule::local_key(STAGE_KEY);
+		let stage = StorageValueRef::persistent(&key);
+		assert_eq!(stage.get::<WorkerStage>().unwrap(), Some(WorkerStage::CommitReveal));
+
+		// The next run starts where this one stopped, and goes through all the stages.
+		crate::NextUnsignedAt::<Test>::put(100);
+		WorkerBudgetMs::set(0);
+		offchain.write().expect_request(testing::PendingRequest {
+			method: "GET".into(),
+			uri: "http://localhost:8080/feed?since=0".into(),
+			response: Some(br#"{"records": []}"#.to_vec()),
+			sent: true,
+			..Default::default()
+		});
+		System::set_block_number(2);
+		TemplateModule::offchain_worker(2);
+		assert_eq!(stage.get::<WorkerStage>().unwrap(), None);
+	});
+}
+
+#[test]
+fn values_are_committed_then_revealed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 1));
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 2));
+		let salt = [7; 32];
+		let hash = TemplateModule::reveal_hash(42, &salt);
+
+		assert_noop!(
+			TemplateModule::commit_value(RuntimeOrigin::signed(3), hash),
+			Error::<Test>::NotAuthority
+		);
+		assert_noop!(
+			TemplateModule::reveal_value(RuntimeOrigin::signed(1), 42, salt),
+			Error::<Test>::NoCommitment
+		);
+		assert_ok!(TemplateModule::commit_value(RuntimeOrigin::signed(1), hash));
+		System::assert_last_event(Event::ValueCommitted { who: 1, hash }.into());
+		// `CommitDeposit` is 20 in the mock, on top of the bond of 10.
+		assert_eq!(Balances::reserved_balance(1), 30);
+		assert_noop!(
+			TemplateModule::commit_value(RuntimeOrigin::signed(1), hash),
+			Error::<Test>::AlreadyCommitted
+		);
+		assert_ok!(TemplateModule::commit_value(RuntimeOrigin::signed(2), hash));
+
+		// `RevealDelay` is 2 in the mock.
+		System::set_block_number(2);
+		assert_noop!(
+			TemplateModule::reveal_value(RuntimeOrigin::signed(1), 42, salt),
+			Error::<Test>::RevealTooEarly
+		);
+
+		System::set_block_number(3);
+		assert_ok!(TemplateModule::reveal_value(RuntimeOrigin::signed(1), 42, salt));
+		System::assert_last_event(Event::ValueRevealed { who: 1, value: 42 }.into());
+		assert_eq!(TemplateModule::latest_value(), Some(42));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(Balances::free_balance(1), 90);
+		assert_eq!(TemplateModule::commitments(1), None);
+
+		// A mismatched reveal slashes the deposit.
+		assert_ok!(TemplateModule::reveal_value(RuntimeOrigin::signed(2), 43, salt));
+		System::assert_last_event(Event::RevealMismatched { who: 2, slashed: 20 }.into());
+		assert_eq!(Balances::reserved_balance(2), 10);
+		assert_eq!(Balances::free_balance(2), 70);
+		assert_eq!(TemplateModule::latest_value(), Some(42));
+	});
+}
+
+#[test]
+fn offchain_worker_commits_then_reveals_the_fetched_value() {
+	let (mut t, _offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(vec![7u64]);
+	let signed_calls = |pool: &parking_lot::RwLock<sp_core::offchain::testing::PoolState>| {
+		pool_calls(pool)
+			.into_iter()
+			.filter(|(signed, _)| *signed)
+			.map(|(_, call)| call)
+			.collect::<Vec<_>>()
+	};
+
+	t.execute_with(|| {
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		// Keep the pipeline from fetching the price, the value of an earlier fetch is used.
+		crate::NextUnsignedAt::<Test>::put(100);
+		sp_io::offchain::local_storage_set(
+			sp_core::offchain::StorageKind::PERSISTENT,
+			crate::FETCHED_VALUE_KEY,
+			&5u64.encode(),
+		);
+
+		// The test offchain extension has an all-zero random seed.
+		let hash = TemplateModule::reveal_hash(5, &[0; 32]);
+		TemplateModule::offchain_worker(2);
+		assert_eq!(
+			signed_calls(&pool),
+			vec![RuntimeCall::TemplateModule(Call::commit_value { hash })]
+		);
+
+		// Nothing is sent until the commitment landed and `RevealDelay` blocks passed.
+		pool.write().transactions.clear();
+		System::set_block_number(3);
+		TemplateModule::offchain_worker(3);
+		crate::Commitments::<Test>::insert(
+			7,
+			crate::ValueCommitment { hash, committed_at: 3, deposit: 20 },
+		);
+		System::set_block_number(4);
+		TemplateModule::offchain_worker(4);
+		assert!(signed_calls(&pool).is_empty());
+
+		System::set_block_number(5);
+		TemplateModule::offchain_worker(5);
+		assert_eq!(
+			signed_calls(&pool),
+			vec![RuntimeCall::TemplateModule(Call::reveal_value { value: 5, salt: [0; 32] })]
+		);
+	});
+}
+
+#[test]
+fn the_twap_weights_the_medians_by_time_within_the_window() {
+	use crate::logic::time_weighted_average;
+
+	assert_eq!(time_weighted_average(&[], 10, 5), None);
+	assert_eq!(time_weighted_average(&[(10, 7)], 10, 5), Some(7));
+	// The price before the window only counts from its start.
+	assert_eq!(time_weighted_average(&[(0, 10), (8, 40)], 10, 4), Some(25));
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 1));
+		// `RoundLength` is 4 in the mock, the round `index` is finalized at `now` milliseconds.
+		let finalize_round = |index: u64, value, now| {
+			System::set_block_number(4 * index);
+			TemplateModule::on_initialize(4 * index);
+			assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(1), value));
+			Now::set(now);
+			TemplateModule::on_finalize(4 * index + 3);
+		};
+		assert_eq!(TemplateModule::twap(), None);
+
+		finalize_round(0, 100, 1_000);
+		assert_eq!(TemplateModule::twap(), Some(100));
+		finalize_round(1, 200, 5_000);
+		Now::set(7_000);
+		assert_eq!(TemplateModule::twap(), Some((100 * 4_000 + 200 * 2_000) / 6_000));
+
+		// `TwapWindow` is 10 seconds in the mock.
+		finalize_round(2, 400, 13_000);
+		assert_eq!(TemplateModule::twap(), Some((100 * 2_000 + 200 * 8_000) / 10_000));
+
+		// The first median fell out of the window, the second is the price at its start.
+		finalize_round(3, 300, 16_000);
+		assert_eq!(
+			TemplateModule::twap_observations().into_inner(),
+			vec![(5_000, 200), (13_000, 400), (16_000, 300)]
+		);
+		assert_eq!(TemplateModule::twap(), Some((200 * 7_000 + 400 * 3_000) / 10_000));
+	});
+}
+
+#[test]
+fn rounds_are_finalized_with_the_median_of_the_authorities() {
+	new_test_ext().execute_with(|| {
+		for who in [1, 2, 7] {
+			assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), who));
+		}
+
+		// `RoundLength` is 4 in the mock, so round 0 spans blocks 0 to 3.
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(1), 50));
+		assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(1), 10));
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(2), 30));
+		assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(7), 20));
+
+		TemplateModule::on_finalize(2);
+		assert_eq!(TemplateModule::latest_round(), None);
+
+		System::set_block_number(3);
+		TemplateModule::on_finalize(3);
+		System::assert_last_event(
+			Event::RoundFinalized { round: 0, median: 20, num_submissions: 3 }.into(),
+		);
+		assert_eq!(TemplateModule::latest_round(), Some((0, 20)));
+		assert_eq!(crate::Submissions::<Test>::iter_prefix(0).count(), 0);
+
+		// Rounds without submissions are not finalized.
+		System::reset_events();
+		System::set_block_number(7);
+		TemplateModule::on_finalize(7);
+		assert!(System::events().is_empty());
+		assert_eq!(TemplateModule::latest_round(), Some((0, 20)));
+	});
+}
+
+#[test]
+fn rounds_are_aggregated_with_the_strategy_of_the_config() {
+	new_test_ext().execute_with(|| {
+		for who in [1, 2, 7] {
+			assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), who));
+		}
+		assert_ok!(TemplateModule::set_ocw_config(
+			RuntimeOrigin::root(),
+			Some(crate::OcwConfigInfo {
+				aggregation: AggregationStrategy::TrimmedMean,
+				..TemplateModule::runtime_ocw_config()
+			})
+		));
+
+		System::set_block_number(1);
+		for (who, value) in [(1, 10), (2, 20), (7, 60)] {
+			assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(who), value));
+		}
+		System::set_block_number(3);
+		TemplateModule::on_finalize(3);
+		System::assert_has_event(
+			Event::RoundFinalized { round: 0, median: 30, num_submissions: 3 }.into(),
+		);
+		assert_eq!(TemplateModule::latest_round(), Some((0, 30)));
+	});
+}
+
+#[test]
+fn deviating_authorities_are_slashed_and_removed() {
+	new_test_ext().execute_with(|| {
+		for who in [1, 2, 7] {
+			assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), who));
+		}
+		let run_round = |start: u64, values: [u64; 3]| {
+			System::set_block_number(start);
+			TemplateModule::on_initialize(start);
+			for (who, value) in [1, 2, 7].into_iter().zip(values) {
+				assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(who), value));
+			}
+			// `RoundLength` is 4 in the mock.
+			System::set_block_number(start + 3);
+			TemplateModule::on_initialize(start + 3);
+			TemplateModule::on_finalize(start + 3);
+		};
+
+		// `MaxDeviation` is 10% in the mock: 7 deviates by 100% from the median of 100.
+		run_round(0, [95, 100, 200]);
+		System::assert_has_event(
+			Event::SubmissionDeviated { who: 7, round: 0, deviation: Perbill::one() }.into(),
+		);
+		assert_eq!(TemplateModule::reputation(7), ReputationInfo { rounds: 1, strikes: 1 });
+		assert_eq!(TemplateModule::reputation(2), ReputationInfo { rounds: 1, strikes: 0 });
+
+		// `MaxStrikes` is 2 in the mock.
+		run_round(4, [100, 100, 50]);
+		System::assert_has_event(Event::AuthoritySlashed { who: 7, amount: 10 }.into());
+		System::assert_last_event(Event::AuthorityRemoved { who: 7 }.into());
+		assert_eq!(TemplateModule::authorities().into_inner(), vec![1, 2]);
+		assert_eq!(Balances::reserved_balance(7), 0);
+		assert_eq!(Balances::free_balance(7), 90);
+		assert_eq!(TemplateModule::reputation(1), ReputationInfo { rounds: 2, strikes: 0 });
+	});
+}
+
+#[test]
+fn authorities_slashed_by_a_dispute_leave_the_current_round() {
+	new_test_ext().execute_with(|| {
+		for who in [2, 7, 8] {
+			assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), who));
+		}
+		System::set_block_number(0);
+		TemplateModule::on_initialize(0);
+		for (who, value) in [(2, 100), (7, 110), (8, 1000)] {
+			assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(who), value));
+		}
+		System::set_block_number(3);
+		TemplateModule::on_initialize(3);
+		TemplateModule::on_finalize(3);
+
+		// The dispute of the previous round is resolved while the next one is open.
+		System::set_block_number(4);
+		TemplateModule::on_initialize(4);
+		for (who, value) in [(2, 100), (8, 1000)] {
+			assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(who), value));
+		}
+		assert_ok!(TemplateModule::raise_dispute(RuntimeOrigin::signed(1), 0, 50));
+		assert_ok!(TemplateModule::resolve_dispute(
+			RuntimeOrigin::root(),
+			0,
+			DisputeOutcome::Upheld { value: 100 }
+		));
+
+		assert_eq!(crate::Submissions::<Test>::get(1, 8), None);
+		assert_eq!(crate::Submissions::<Test>::get(1, 2), Some(100));
+		assert_ok!(TemplateModule::do_try_state(4));
+	});
+}
+
+#[test]
+fn disputed_rounds_are_invalidated_or_the_dispute_bond_slashed() {
+	new_test_ext().execute_with(|| {
+		for who in [2, 7, 8] {
+			assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), who));
+		}
+		let run_round = |start: u64, submissions: &[(u64, u64)]| {
+			System::set_block_number(start);
+			TemplateModule::on_initialize(start);
+			for &(who, value) in submissions {
+				assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(who), value));
+			}
+			System::set_block_number(start + 3);
+			TemplateModule::on_initialize(start + 3);
+			TemplateModule::on_finalize(start + 3);
+		};
+
+		run_round(0, &[(2, 100), (7, 110), (8, 1000)]);
+		assert_eq!(TemplateModule::latest_round(), Some((0, 110)));
+
+		// `MinDisputeBond` is 50 in the mock.
+		assert_noop!(
+			TemplateModule::raise_dispute(RuntimeOrigin::signed(1), 0, 49),
+			Error::DisputeBondTooLow
+		);
+		assert_noop!(
+			TemplateModule::raise_dispute(RuntimeOrigin::signed(1), 1, 50),
+			Error::NotDisputable
+		);
+		assert_noop!(
+			TemplateModule::resolve_dispute(RuntimeOrigin::root(), 0, DisputeOutcome::Rejected),
+			Error::NoDispute
+		);
+		assert_ok!(TemplateModule::raise_dispute(RuntimeOrigin::signed(1), 0, 50));
+		System::assert_last_event(Event::DisputeRaised { who: 1, round: 0, bond: 50 }.into());
+		assert_noop!(
+			TemplateModule::raise_dispute(RuntimeOrigin::signed(2), 0, 50),
+			Error::AlreadyDisputed
+		);
+		assert_eq!(Balances::reserved_balance(1), 50);
+		assert_eq!(TemplateModule::deposits(1), DepositInfo { reserved: 50, reclaimable: 0 });
+		assert_noop!(
+			TemplateModule::resolve_dispute(RuntimeOrigin::signed(1), 0, DisputeOutcome::Rejected),
+			BadOrigin
+		);
+
+		// `MaxDeviation` is 10% in the mock: only 8 deviates from the right value of 100.
+		assert_ok!(TemplateModule::resolve_dispute(
+			RuntimeOrigin::root(),
+			0,
+			DisputeOutcome::Upheld { value: 100 }
+		));
+		System::assert_has_event(Event::AuthoritySlashed { who: 8, amount: 10 }.into());
+		System::assert_last_event(Event::RoundInvalidated { round: 0, value: 100 }.into());
+		assert_eq!(TemplateModule::authorities().into_inner(), vec![2, 7]);
+		assert_eq!(TemplateModule::latest_round(), None);
+		assert_eq!(TemplateModule::disputes(0), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(TemplateModule::deposits(1), DepositInfo::default());
+
+		// A rejected dispute loses its bond.
+		run_round(4, &[(2, 100), (7, 120)]);
+		assert_ok!(TemplateModule::raise_dispute(RuntimeOrigin::signed(1), 1, 50));
+		assert_ok!(TemplateModule::resolve_dispute(
+			RuntimeOrigin::root(),
+			1,
+			DisputeOutcome::Rejected
+		));
+		System::assert_last_event(Event::DisputeRejected { round: 1, slashed: 50 }.into());
+		assert_eq!(TemplateModule::latest_round(), Some((1, 110)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 50);
+	});
+}
+
+#[test]
+fn rounds_close_early_once_enough_authorities_agree() {
+	Finalization::set(crate::FinalizationMode::Threshold {
+		threshold: 3,
+		tolerance: Perbill::from_percent(2),
+	});
+	new_test_ext().execute_with(|| {
+		for who in [1, 2, 7, 8] {
+			assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), who));
+		}
+
+		System::set_block_number(1);
+		for (who, value) in [(1, 100), (2, 101), (7, 99)] {
+			assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(who), value));
+		}
+		System::set_block_number(2);
+		TemplateModule::on_initialize(2);
+		System::assert_has_event(Event::RoundClosedEarly { round: 0, agreeing: 3 }.into());
+		System::assert_has_event(
+			Event::RoundFinalized { round: 0, median: 100, num_submissions: 3 }.into(),
+		);
+		assert_eq!(TemplateModule::latest_round(), Some((0, 100)));
+
+		// The round is over until the next one starts, at block 4.
+		System::reset_events();
+		assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(8), 150));
+		assert_eq!(crate::Submissions::<Test>::iter_prefix(0).count(), 0);
+		System::set_block_number(3);
+		TemplateModule::on_initialize(3);
+		TemplateModule::on_finalize(3);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::TemplateModule(Event::RoundFinalized { .. })
+		)));
+		assert_eq!(TemplateModule::latest_round(), Some((0, 100)));
+	});
+}
+
+#[test]
+fn rounds_without_enough_agreeing_authorities_are_finalized_at_their_end() {
+	Finalization::set(crate::FinalizationMode::Threshold {
+		threshold: 3,
+		tolerance: Perbill::from_percent(2),
+	});
+	new_test_ext().execute_with(|| {
+		for who in [1, 2, 7, 8] {
+			assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), who));
+		}
+
+		// Two pairs of agreeing authorities tie below the threshold.
+		System::set_block_number(1);
+		for (who, value) in [(1, 100), (2, 100), (7, 200), (8, 200)] {
+			assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(who), value));
+		}
+		System::set_block_number(2);
+		TemplateModule::on_initialize(2);
+		assert_eq!(TemplateModule::latest_round(), None);
+
+		System::set_block_number(3);
+		TemplateModule::on_initialize(3);
+		assert_eq!(TemplateModule::latest_round(), None);
+		TemplateModule::on_finalize(3);
+		// The median of the tie is between the pairs, so all the submissions deviate from it.
+		System::assert_has_event(
+			Event::RoundFinalized { round: 0, median: 150, num_submissions: 4 }.into(),
+		);
+		System::assert_has_event(
+			Event::SubmissionDeviated {
+				who: 1,
+				round: 0,
+				deviation: Perbill::from_rational(50u64, 150),
+			}
+			.into(),
+		);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::TemplateModule(Event::RoundClosedEarly { .. })
+		)));
+	});
+}
+
+#[test]
+fn agreeing_values_are_counted_within_the_tolerance() {
+	use crate::logic::agreeing;
+
+	let tolerance = Perbill::from_percent(2);
+	assert_eq!(agreeing(&[], tolerance), 0);
+	assert_eq!(agreeing(&[100, 101, 99, 120], tolerance), 3);
+	assert_eq!(agreeing(&[100, 100, 200, 200], tolerance), 2);
+	assert_eq!(agreeing(&[100, 103], tolerance), 1);
+	assert_eq!(agreeing(&[5, 5, 5], Perbill::zero()), 3);
+}
+
+#[test]
+fn rounds_and_indexed_keys_follow_the_block_number_provider() {
+	new_test_ext().execute_with(|| {
+		// The default instance counts in the blocks of the relay chain in the mock.
+		System::set_block_number(1);
+		RelayBlockNumber::set(Some(100));
+		TemplateModule::on_initialize(1);
+		Weather::on_initialize(1);
+		let round = |index, started_at, phase| RoundInfo {
+			index,
+			started_at,
+			phase,
+			seed: TemplateModule::round_seed(index),
+		};
+		assert_eq!(TemplateModule::current_round(), round(1, 100, RoundPhase::Open));
+		assert_eq!(Weather::current_round(), round(0, 0, RoundPhase::Open));
+
+		assert_ok!(TemplateModule::queue_task(RuntimeOrigin::signed(1), Task::FetchPrice));
+		assert_eq!(TemplateModule::indexed_keys_of(100).into_inner(), vec![KeyKind::FetchPrice]);
+		assert!(TemplateModule::indexed_keys_of(1).is_empty());
+		assert_noop!(
+			TemplateModule::confirm_tasks(RuntimeOrigin::signed(7), 100),
+			Error::<Test>::TooEarly
+		);
+
+		// `RoundLength` is 4 in the mock, the relay chain skipped blocks 101 and 102.
+		System::set_block_number(2);
+		RelayBlockNumber::set(Some(103));
+		TemplateModule::on_initialize(2);
+		assert_eq!(TemplateModule::current_round(), round(1, 100, RoundPhase::Closing));
+		TemplateModule::on_finalize(2);
+		assert_eq!(TemplateModule::current_round(), round(1, 100, RoundPhase::Finalized));
+		assert_ok!(TemplateModule::do_try_state(103));
+	});
+}
+
+#[test]
+fn authors_set_values_close_to_the_median_with_an_inherent() {
+	use frame_support::inherent::{InherentData, ProvideInherent};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let data = |value: u64| {
+			let mut data = InherentData::new();
+			data.put_data(crate::INHERENT_IDENTIFIER, &value).unwrap();
+			data
+		};
+		let check = |value| {
+			let call = Call::set_inherent_value { value };
+			TemplateModule::check_inherent(&call, &InherentData::new())
+		};
+
+		// The mock doesn't accept values from inherents by default.
+		assert_eq!(TemplateModule::create_inherent(&data(105)), None);
+		assert_eq!(check(105), Err(InherentError::Disabled));
+		AcceptInherentValues::set(true);
+		assert_eq!(TemplateModule::create_inherent(&InherentData::new()), None);
+		assert_eq!(check(105), Err(InherentError::NoMedian));
+
+		// `MaxDeviation` is 10% in the mock.
+		crate::LatestRound::<Test>::put((0, 100));
+		assert_eq!(TemplateModule::create_inherent(&data(111)), None);
+		assert_eq!(check(111), Err(InherentError::TooFarFromMedian));
+		let call = Call::set_inherent_value { value: 105 };
+		assert_eq!(TemplateModule::create_inherent(&data(105)), Some(call.clone()));
+		assert!(TemplateModule::is_inherent(&call));
+		assert_eq!(check(90), Ok(()));
+
+		// The inherent is dispatched with the block, but never accepted into the pool.
+		assert_ok!(TemplateModule::pre_dispatch(&call));
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::Call.into()
+		);
+
+		assert_noop!(TemplateModule::set_inherent_value(RuntimeOrigin::signed(1), 105), BadOrigin);
+		assert_noop!(
+			TemplateModule::set_inherent_value(RuntimeOrigin::none(), 111),
+			Error::InherentValueRejected
+		);
+		assert_ok!(TemplateModule::set_inherent_value(RuntimeOrigin::none(), 105));
+		System::assert_last_event(Event::InherentValueSet { value: 105 }.into());
+		assert_eq!(TemplateModule::latest_value(), Some(105));
+		assert_noop!(
+			TemplateModule::set_inherent_value(RuntimeOrigin::none(), 100),
+			Error::InherentValueAlreadySet
+		);
+
+		// Once per block.
+		TemplateModule::on_finalize(1);
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::set_inherent_value(RuntimeOrigin::none(), 100));
+		assert_eq!(TemplateModule::latest_value(), Some(100));
+
+		assert_ok!(TemplateModule::pause(RuntimeOrigin::root()));
+		assert_eq!(check(100), Err(InherentError::Paused));
+	});
+}
+
+#[test]
+fn rounds_advance_through_their_phases() {
+	new_test_ext().execute_with(|| {
+		let validate = |block_number| {
+			let call = Call::submit_number_unsigned { block_number, number: 1 };
+			TemplateModule::validate_unsigned(TransactionSource::External, &call)
+		};
+		let round = |index, started_at, phase| RoundInfo {
+			index,
+			started_at,
+			phase,
+			seed: TemplateModule::round_seed(index),
+		};
+
+		// `RoundLength` is 4 and `ClosingPeriod` is 1 in the mock.
+		for block in 1..=2 {
+			System::set_block_number(block);
+			TemplateModule::on_initialize(block);
+		}
+		assert_eq!(TemplateModule::current_round(), round(0, 0, RoundPhase::Open));
+		assert!(validate(2).is_ok());
+
+		System::set_block_number(3);
+		TemplateModule::on_initialize(3);
+		assert_eq!(TemplateModule::current_round(), round(0, 0, RoundPhase::Closing));
+		assert_eq!(validate(3), InvalidTransaction::Stale.into());
+		TemplateModule::on_finalize(3);
+		assert_eq!(TemplateModule::current_round(), round(0, 0, RoundPhase::Finalized));
+
+		System::set_block_number(4);
+		TemplateModule::on_initialize(4);
+		assert_eq!(TemplateModule::current_round(), round(1, 4, RoundPhase::Open));
+		// Values computed in the previous round are out of round.
+		assert_eq!(validate(3), InvalidTransaction::Stale.into());
+		assert!(validate(4).is_ok());
+	});
+}
+
+#[test]
+fn rounds_are_seeded_by_the_block_which_opens_them() {
+	use sp_core::H256;
+
+	new_test_ext().execute_with(|| {
+		let seed = |index: u32, parent_hash: H256| {
+			sp_io::hashing::blake2_256(&(index, parent_hash).encode())
+		};
+
+		System::set_block_number(4);
+		System::set_parent_hash(H256::repeat_byte(1));
+		TemplateModule::on_initialize(4);
+		assert_eq!(TemplateModule::current_round().index, 1);
+		assert_eq!(TemplateModule::current_round().seed, seed(1, H256::repeat_byte(1)));
+
+		// The seed stays that of the block which opened the round.
+		System::set_block_number(5);
+		System::set_parent_hash(H256::repeat_byte(2));
+		TemplateModule::on_initialize(5);
+		assert_eq!(TemplateModule::current_round().seed, seed(1, H256::repeat_byte(1)));
+	});
+}
+
+#[test]
+fn on_initialize_reports_offline_authorities() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 8));
+		crate::LastSeen::<Test>::insert(7, 15);
+
+		System::reset_events();
+		System::set_block_number(19);
+		TemplateModule::on_initialize(19);
+		assert!(System::events().is_empty());
+
+		System::set_block_number(20);
+		TemplateModule::on_initialize(20);
+		System::assert_has_event(Event::OffchainWorkerOffline { who: 8 }.into());
+		assert_eq!(System::events().len(), 1);
+	});
+}
+
+#[test]
+fn offchain_worker_sends_heartbeats_of_authorities() {
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(vec![7u64]);
+	expect_price_request(&offchain, br#"{"value": 3}"#);
+
+	t.execute_with(|| {
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		TemplateModule::offchain_worker(2);
+
+		let heartbeats = pool_calls(&pool)
+			.into_iter()
+			.filter(|(signed, call)| {
+				!signed && matches!(call, RuntimeCall::TemplateModule(Call::heartbeat { .. }))
+			})
+			.count();
+		assert_eq!(heartbeats, 1);
+	});
+}
+
+#[test]
+fn subscriptions_reserve_deposits_and_are_bounded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let topic = |t: &[u8]| crate::Topic::<Test>::try_from(t.to_vec()).unwrap();
+
+		assert_ok!(TemplateModule::subscribe(RuntimeOrigin::signed(1), topic(b"EUR")));
+		System::assert_last_event(Event::Subscribed { who: 1, topic: topic(b"EUR") }.into());
+		assert_ok!(TemplateModule::subscribe(RuntimeOrigin::signed(2), topic(b"EUR")));
+		assert_noop!(
+			TemplateModule::subscribe(RuntimeOrigin::signed(1), topic(b"EUR")),
+			Error::<Test>::AlreadySubscribed
+		);
+		assert_noop!(
+			TemplateModule::subscribe(RuntimeOrigin::signed(1), topic(&[0xff])),
+			Error::<Test>::InvalidTopic
+		);
+		assert_ok!(TemplateModule::subscribe(RuntimeOrigin::signed(1), topic(b"GBP")));
+		assert_noop!(
+			TemplateModule::subscribe(RuntimeOrigin::signed(1), topic(b"JPY")),
+			Error::<Test>::TooManyTopics
+		);
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(crate::Topics::<Test>::get(topic(b"EUR")), 2);
+
+		assert_ok!(TemplateModule::unsubscribe(RuntimeOrigin::signed(1), topic(b"GBP")));
+		System::assert_last_event(Event::Unsubscribed { who: 1, topic: topic(b"GBP") }.into());
+		assert_noop!(
+			TemplateModule::unsubscribe(RuntimeOrigin::signed(1), topic(b"GBP")),
+			Error::<Test>::NotSubscribed
+		);
+		assert_eq!(Balances::reserved_balance(1), 5);
+		assert_eq!(crate::Topics::<Test>::count(), 1);
+		// The topic freed up by the unsubscription can be taken.
+		assert_ok!(TemplateModule::subscribe(RuntimeOrigin::signed(1), topic(b"JPY")));
+	});
+}
+
+#[test]
+fn offchain_worker_updates_subscribed_topics() {
+	use sp_core::offchain::testing;
+
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(vec![7u64]);
+	offchain.write().expect_request(testing::PendingRequest {
+		method: "GET".into(),
+		uri: "http://localhost:8080/topics?symbol=EUR".into(),
+		response: Some(br#"{"EUR": 4.25}"#.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		let eur = crate::Topic::<Test>::try_from(b"EUR".to_vec()).unwrap();
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		assert_ok!(TemplateModule::subscribe(RuntimeOrigin::signed(1), eur.clone()));
+		crate::NextUnsignedAt::<Test>::put(100);
+
+		// Topics are only updated every `TopicPeriod` blocks.
+		TemplateModule::offchain_worker(1);
+		System::set_block_number(2);
+		TemplateModule::offchain_worker(2);
+
+		let updates = BoundedVec::truncate_from(vec![(eur.clone(), 4_250_000)]);
+		let update = RuntimeCall::TemplateModule(Call::update_topics { updates: updates.clone() });
+		assert!(pool_calls(&pool).contains(&(true, update)));
+
+		assert_noop!(
+			TemplateModule::update_topics(RuntimeOrigin::signed(1), updates.clone()),
+			Error::<Test>::NotAuthority
+		);
+		assert_ok!(TemplateModule::update_topics(RuntimeOrigin::signed(7), updates));
+		System::assert_last_event(Event::TopicUpdated { topic: eur, value: 4_250_000 }.into());
+
+		let unknown = BoundedVec::truncate_from(vec![(b"GBP".to_vec().try_into().unwrap(), 1)]);
+		assert_noop!(
+			TemplateModule::update_topics(RuntimeOrigin::signed(7), unknown),
+			Error::<Test>::UnknownTopic
+		);
+	});
+}
+
+#[test]
+fn ocw_config_overrides_the_runtime_defaults() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let config = crate::OcwConfigOf::<Test> {
+			endpoint: b"http://localhost:8080/other".to_vec().try_into().unwrap(),
+			fetch_interval: 2,
+			max_payload_size: 16,
+			max_retries: 1,
+		};
+		assert_noop!(
+			TemplateModule::set_ocw_config(RuntimeOrigin::signed(1), Some(config.clone())),
+			BadOrigin
+		);
+		let invalid =
+			crate::OcwConfigInfo { endpoint: vec![0xff].try_into().unwrap(), ..config.clone() };
+		assert_noop!(
+			TemplateModule::set_ocw_config(RuntimeOrigin::root(), Some(invalid)),
+			Error::<Test>::InvalidEndpoint
+		);
+
+		assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(1), 1));
+		assert_eq!(TemplateModule::next_unsigned_at(), 6);
+
+		assert_ok!(TemplateModule::set_ocw_config(RuntimeOrigin::root(), Some(config.clone())));
+		System::assert_last_event(Event::OcwConfigSet { config: Some(config.clone()) }.into());
+		assert_eq!(TemplateModule::effective_ocw_config(), config);
+		assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(1), 2));
+		assert_eq!(TemplateModule::next_unsigned_at(), 3);
+
+		assert_ok!(TemplateModule::set_ocw_config(RuntimeOrigin::root(), None));
+		assert_eq!(TemplateModule::effective_ocw_config(), TemplateModule::runtime_ocw_config());
+	});
+}
+
+#[test]
+fn offchain_worker_reads_its_config_on_each_run() {
+	use sp_core::offchain::testing;
+
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(Vec::<u64>::new());
+	let expect = |body: &[u8]| {
+		offchain.write().expect_request(testing::PendingRequest {
+			method: "GET".into(),
+			uri: "http://localhost:8080/other".into(),
+			response: Some(body.to_vec()),
+			sent: true,
+			..Default::default()
+		})
+	};
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		crate::OcwConfig::<Test>::put(crate::OcwConfigInfo {
+			endpoint: b"http://localhost:8080/other".to_vec().try_into().unwrap(),
+			max_payload_size: 16,
+			..TemplateModule::runtime_ocw_config()
+		});
+		expect(br#"{"value": 1}"#);
+		TemplateModule::offchain_worker(1);
+		assert_eq!(TemplateModule::fetched_value(), Some(1_000_000));
+
+		// Bodies larger than the maximum payload size are rejected.
+		System::set_block_number(2);
+		expect(br#"{"value": 2, "padding": true}"#);
+		TemplateModule::offchain_worker(2);
+		assert_eq!(TemplateModule::fetched_value(), Some(1_000_000));
+		assert_eq!(pool_calls(&pool).len(), 1);
+
+		// The responses are decoded in the format of the config.
+		crate::OcwConfig::<Test>::mutate(|config| {
+			config.as_mut().unwrap().format = ResponseFormat::Scale
+		});
+		System::set_block_number(3);
+		expect(&3_000_000u64.encode());
+		TemplateModule::offchain_worker(3);
+		assert_eq!(TemplateModule::fetched_value(), Some(3_000_000));
+	});
+}
+
+#[test]
+fn genesis_config_bootstraps_the_oracle() {
+	use frame_support::traits::{BuildGenesisConfig, ReservableCurrency};
+
+	new_test_ext().execute_with(|| {
+		crate::GenesisConfig::<Test> {
+			authorities: vec![7, 8],
+			endpoints: vec![b"https://a".to_vec()],
+			round: 5,
+			..Default::default()
+		}
+		.build();
+
+		assert_eq!(TemplateModule::authorities().into_inner(), vec![7, 8]);
+		assert_eq!(TemplateModule::bonds(7), 10);
+		assert_eq!(Balances::reserved_balance(8), 10);
+		assert_eq!(TemplateModule::oracle_endpoints().into_inner(), vec![b"https://a".to_vec()]);
+		assert_eq!(
+			TemplateModule::current_round(),
+			RoundInfo {
+				index: 5,
+				started_at: 0,
+				phase: RoundPhase::Open,
+				seed: TemplateModule::round_seed(5)
+			}
+		);
+	});
+}
+
+#[test]
+fn try_state_checks_the_invariants() {
+	use frame_support::traits::ReservableCurrency;
+
+	new_test_ext().execute_with(|| {
+		for who in [1, 2] {
+			assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), who));
+		}
+		System::set_block_number(1);
+		TemplateModule::on_initialize(1);
+		assert_ok!(TemplateModule::submit_data_signed(RuntimeOrigin::signed(1), 10));
+		assert_ok!(TemplateModule::submit_data(
+			RuntimeOrigin::signed(2),
+			BoundedVec::try_from(b"data".to_vec()).unwrap()
+		));
+		assert_ok!(TemplateModule::do_try_state(1));
+
+		// Removed authorities don't keep their submission.
+		assert_ok!(TemplateModule::remove_authority(RuntimeOrigin::root(), 1));
+		assert_ok!(TemplateModule::do_try_state(1));
+
+		// `RoundLength` is 4 and `ClosingPeriod` is 1 in the mock.
+		assert!(TemplateModule::do_try_state(3).is_err());
+		System::set_block_number(3);
+		TemplateModule::on_initialize(3);
+		TemplateModule::on_finalize(3);
+		assert_ok!(TemplateModule::do_try_state(3));
+
+		Balances::unreserve(&2, 1);
+		assert!(TemplateModule::do_try_state(3).is_err());
+	});
+}
+
+type AssetEndpoint = BoundedVec<u8, <Test as crate::Config>::MaxEndpointLen>;
+
+fn asset_endpoint(url: &[u8]) -> AssetEndpoint {
+	url.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn assets_are_registered_by_root_and_bounded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let endpoint = asset_endpoint(b"http://localhost:8080/dot");
+		let field = asset_endpoint(b"USD");
+		assert_noop!(
+			TemplateModule::register_asset(
+				RuntimeOrigin::signed(1),
+				1,
+				endpoint.clone(),
+				field.clone()
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::register_asset(
+				RuntimeOrigin::root(),
+				1,
+				endpoint.clone(),
+				asset_endpoint(&[0xff])
+			),
+			Error::<Test>::InvalidField
+		);
+
+		assert_ok!(TemplateModule::register_asset(
+			RuntimeOrigin::root(),
+			1,
+			endpoint.clone(),
+			field.clone()
+		));
+		System::assert_last_event(
+			Event::AssetRegistered { asset: 1, endpoint: endpoint.clone() }.into(),
+		);
+		assert_noop!(
+			TemplateModule::register_asset(
+				RuntimeOrigin::root(),
+				1,
+				endpoint.clone(),
+				field.clone()
+			),
+			Error::<Test>::AssetAlreadyRegistered
+		);
+
+		// `MaxAssets` is 2 in the mock.
+		assert_ok!(TemplateModule::register_asset(
+			RuntimeOrigin::root(),
+			2,
+			endpoint.clone(),
+			field.clone()
+		));
+		assert_noop!(
+			TemplateModule::register_asset(RuntimeOrigin::root(), 3, endpoint, field),
+			Error::<Test>::TooManyAssets
+		);
+
+		assert_ok!(TemplateModule::deregister_asset(RuntimeOrigin::root(), 2));
+		System::assert_last_event(Event::AssetDeregistered { asset: 2 }.into());
+		assert_noop!(
+			TemplateModule::deregister_asset(RuntimeOrigin::root(), 2),
+			Error::<Test>::UnknownAsset
+		);
+		assert_eq!(crate::Assets::<Test>::count(), 1);
+	});
+}
+
+#[test]
+fn the_force_origin_overrides_the_price_of_an_asset() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TemplateModule::force_set_value(RuntimeOrigin::root(), 1, 42),
+			Error::<Test>::UnknownAsset
+		);
+		assert_ok!(TemplateModule::register_asset(
+			RuntimeOrigin::root(),
+			1,
+			asset_endpoint(b"http://localhost:8080/dot"),
+			asset_endpoint(b"USD")
+		));
+		assert_noop!(TemplateModule::force_set_value(RuntimeOrigin::signed(1), 1, 42), BadOrigin);
+
+		Now::set(6_000);
+		assert_ok!(TemplateModule::force_set_value(RuntimeOrigin::root(), 1, 42));
+		System::assert_last_event(Event::ValueForced { asset: 1, value: 42 }.into());
+		assert_eq!(
+			TemplateModule::prices(1),
+			Some(crate::PriceInfo { value: 42, round: 0, updated_at: 1 })
+		);
+		assert_eq!(TemplateModule::price_timestamps(1), Some(6_000));
+		assert_eq!(NewValues::get(), vec![(1, 42)]);
+	});
+}
+
+#[test]
+fn asset_prices_are_finalized_per_asset() {
+	new_test_ext().execute_with(|| {
+		for who in [1, 2, 7] {
+			assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), who));
+		}
+		for asset in [1, 2] {
+			assert_ok!(TemplateModule::register_asset(
+				RuntimeOrigin::root(),
+				asset,
+				asset_endpoint(b"http://localhost:8080/prices"),
+				asset_endpoint(b"USD")
+			));
+		}
+		let prices = |prices: Vec<(u32, u64)>| BoundedVec::truncate_from(prices);
+
+		// `RoundLength` is 4 in the mock, so round 0 spans blocks 0 to 3.
+		System::set_block_number(1);
+		assert_noop!(
+			TemplateModule::submit_asset_prices(RuntimeOrigin::signed(8), prices(vec![(1, 10)])),
+			Error::<Test>::NotAuthority
+		);
+		assert_noop!(
+			TemplateModule::submit_asset_prices(RuntimeOrigin::signed(1), prices(vec![(3, 10)])),
+			Error::<Test>::UnknownAsset
+		);
+		assert_ok!(TemplateModule::submit_asset_prices(
+			RuntimeOrigin::signed(1),
+			prices(vec![(1, 10), (2, 100)])
+		));
+		System::assert_last_event(Event::AssetPricesSubmitted { who: 1, count: 2 }.into());
+		assert_ok!(TemplateModule::submit_asset_prices(
+			RuntimeOrigin::signed(2),
+			prices(vec![(1, 30)])
+		));
+		assert_ok!(TemplateModule::submit_asset_prices(
+			RuntimeOrigin::signed(7),
+			prices(vec![(1, 20)])
+		));
+
+		System::set_block_number(3);
+		TemplateModule::on_initialize(3);
+		TemplateModule::on_finalize(3);
+		System::assert_has_event(
+			Event::AssetRoundFinalized { asset: 1, round: 0, median: 20, num_submissions: 3 }
+				.into(),
+		);
+		assert_eq!(
+			TemplateModule::prices(1),
+			Some(crate::PriceInfo { value: 20, round: 0, updated_at: 3 })
+		);
+		assert_eq!(
+			TemplateModule::prices(2),
+			Some(crate::PriceInfo { value: 100, round: 0, updated_at: 3 })
+		);
+		// The assets are finalized in the order of their hashes.
+		let mut new_values = NewValues::get();
+		new_values.sort_unstable();
+		assert_eq!(new_values, vec![(1, 20), (2, 100)]);
+		assert_noop!(
+			TemplateModule::submit_asset_prices(RuntimeOrigin::signed(1), prices(vec![(1, 10)])),
+			Error::<Test>::RoundAlreadyFinalized
+		);
+
+		// Each asset counts its own rounds, and keeps its price without submissions.
+		System::set_block_number(4);
+		TemplateModule::on_initialize(4);
+		assert_ok!(TemplateModule::submit_asset_prices(
+			RuntimeOrigin::signed(1),
+			prices(vec![(1, 40)])
+		));
+		System::set_block_number(7);
+		TemplateModule::on_initialize(7);
+		TemplateModule::on_finalize(7);
+		assert_eq!(
+			TemplateModule::prices(1),
+			Some(crate::PriceInfo { value: 40, round: 1, updated_at: 7 })
+		);
+		assert_eq!(
+			TemplateModule::prices(2),
+			Some(crate::PriceInfo { value: 100, round: 0, updated_at: 3 })
+		);
+
+		// Only the assets with submissions are passed to `OnNewValue`.
+		assert_eq!(NewValues::get().len(), 3);
+		assert_eq!(NewValues::get().last(), Some(&(1, 40)));
+
+		assert_ok!(TemplateModule::deregister_asset(RuntimeOrigin::root(), 1));
+		assert_eq!(TemplateModule::prices(1), None);
+		assert_eq!(TemplateModule::price_timestamps(1), None);
+	});
+}
+
+#[test]
+fn asset_prices_of_the_authorities_leaving_the_set_are_dropped() {
+	new_test_ext().execute_with(|| {
+		for who in [1, 2] {
+			assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), who));
+		}
+		assert_ok!(TemplateModule::register_asset(
+			RuntimeOrigin::root(),
+			1,
+			asset_endpoint(b"http://localhost:8080/prices"),
+			asset_endpoint(b"USD")
+		));
+		System::set_block_number(1);
+		for (who, price) in [(1, 10), (2, 1000)] {
+			assert_ok!(TemplateModule::submit_asset_prices(
+				RuntimeOrigin::signed(who),
+				BoundedVec::truncate_from(vec![(1, price)])
+			));
+		}
+
+		assert_ok!(TemplateModule::remove_authority(RuntimeOrigin::root(), 2));
+		assert_eq!(crate::AssetSubmissions::<Test>::get(1, 2), None);
+		assert_ok!(TemplateModule::do_try_state(1));
+
+		System::set_block_number(3);
+		TemplateModule::on_initialize(3);
+		TemplateModule::on_finalize(3);
+		System::assert_has_event(
+			Event::AssetRoundFinalized { asset: 1, round: 0, median: 10, num_submissions: 1 }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn significant_changes_of_the_prices_are_announced() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 1));
+		for asset in [1, 2] {
+			assert_ok!(TemplateModule::register_asset(
+				RuntimeOrigin::root(),
+				asset,
+				asset_endpoint(b"http://localhost:8080/prices"),
+				asset_endpoint(b"USD")
+			));
+		}
+		assert_noop!(
+			TemplateModule::set_change_threshold(RuntimeOrigin::signed(1), 2, Some(100)),
+			BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::set_change_threshold(RuntimeOrigin::root(), 3, Some(100)),
+			Error::<Test>::UnknownAsset
+		);
+		assert_ok!(TemplateModule::set_change_threshold(RuntimeOrigin::root(), 2, Some(100)));
+		System::assert_last_event(
+			Event::ChangeThresholdSet { asset: 2, threshold: Some(100) }.into(),
+		);
+		assert_eq!(TemplateModule::change_threshold(1), 1_000);
+		assert_eq!(TemplateModule::change_threshold(2), 100);
+
+		// Submit `prices` in the round starting at `start`, then finalize it.
+		let finalize_round = |start: u64, prices: Vec<(u32, u64)>| {
+			System::set_block_number(start);
+			TemplateModule::on_initialize(start);
+			System::reset_events();
+			assert_ok!(TemplateModule::submit_asset_prices(
+				RuntimeOrigin::signed(1),
+				BoundedVec::truncate_from(prices)
+			));
+			System::set_block_number(start + 3);
+			TemplateModule::on_initialize(start + 3);
+			TemplateModule::on_finalize(start + 3);
+			System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::TemplateModule(event @ Event::SignificantChange { .. }) =>
+						Some(event),
+					_ => None,
+				})
+				.collect::<Vec<_>>()
+		};
+
+		// The first prices of the assets are no changes.
+		assert_eq!(finalize_round(4, vec![(1, 1_000), (2, 1_000)]), vec![]);
+		// 5% is below the 10% of `ChangeThresholdBps`, but above the 1% of asset 2.
+		assert_eq!(
+			finalize_round(8, vec![(1, 1_050), (2, 1_050)]),
+			vec![Event::SignificantChange { asset: 2, old: 1_000, new: 1_050, delta_bps: 500 }]
+		);
+		assert_eq!(
+			finalize_round(12, vec![(1, 840), (2, 1_050)]),
+			vec![Event::SignificantChange { asset: 1, old: 1_050, new: 840, delta_bps: 2_000 }]
+		);
+
+		assert_ok!(TemplateModule::set_change_threshold(RuntimeOrigin::root(), 2, None));
+		assert_eq!(TemplateModule::change_threshold(2), 1_000);
+		assert_ok!(TemplateModule::deregister_asset(RuntimeOrigin::root(), 1));
+		assert_eq!(TemplateModule::change_thresholds(1), None);
+	});
+}
+
+#[test]
+fn changes_are_measured_in_basis_points_of_the_old_value() {
+	use crate::logic::change_bps;
+
+	assert_eq!(change_bps(1_000, 975), 250);
+	assert_eq!(change_bps(1_000, 1_025), 250);
+	assert_eq!(change_bps(1_000, 1_000), 0);
+	assert_eq!(change_bps(3, 4), 3_333);
+	assert_eq!(change_bps(0, 0), 0);
+	assert_eq!(change_bps(0, 1), u32::MAX);
+	assert_eq!(change_bps(1, u64::MAX), u32::MAX);
+}
+
+#[test]
+fn other_pallets_read_the_prices_through_the_oracle_trait() {
+	use crate::traits::ProvideOracleValue;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for who in [1, 2] {
+			assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), who));
+		}
+		assert_ok!(TemplateModule::register_asset(
+			RuntimeOrigin::root(),
+			1,
+			asset_endpoint(b"http://localhost:8080/prices"),
+			asset_endpoint(b"USD")
+		));
+		assert_eq!(<TemplateModule as ProvideOracleValue<u32, u64>>::get(&1), None);
+
+		assert_ok!(TemplateModule::submit_asset_prices(
+			RuntimeOrigin::signed(1),
+			BoundedVec::truncate_from(vec![(1, 10)])
+		));
+		System::set_block_number(3);
+		TemplateModule::on_initialize(3);
+		TemplateModule::on_finalize(3);
+		assert_eq!(<TemplateModule as ProvideOracleValue<u32, u64>>::get(&1), Some((10, 3)));
+		assert_eq!(<TemplateModule as ProvideOracleValue<u32, u64>>::get(&2), None);
+	});
+}
+
+#[test]
+fn prices_are_received_from_the_oracles_of_other_chains() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TemplateModule::receive_remote_value(RuntimeOrigin::root(), 1, 42),
+			Error::<Test>::UnknownAsset
+		);
+		assert_ok!(TemplateModule::register_asset(
+			RuntimeOrigin::root(),
+			1,
+			asset_endpoint(b"http://localhost:8080/prices"),
+			asset_endpoint(b"USD")
+		));
+		assert_noop!(
+			TemplateModule::receive_remote_value(RuntimeOrigin::signed(1), 1, 42),
+			BadOrigin
+		);
+
+		assert_ok!(TemplateModule::receive_remote_value(RuntimeOrigin::root(), 1, 42));
+		System::assert_last_event(Event::RemoteValueReceived { asset: 1, value: 42 }.into());
+		assert_eq!(
+			TemplateModule::prices(1),
+			Some(crate::PriceInfo { value: 42, round: 0, updated_at: 1 })
+		);
+		// The price isn't sent on to other chains.
+		assert!(NewValues::get().is_empty());
+	});
+}
+
+#[cfg(feature = "xcm")]
+#[test]
+fn the_calls_sent_to_other_chains_decode_as_receive_remote_value() {
+	use crate::remote::remote_call;
+	use frame_support::traits::PalletInfoAccess;
+
+	let index = <TemplateModule as PalletInfoAccess>::index() as u8;
+	let call = RuntimeCall::decode(&mut &remote_call(index, &1u32, 42)[..]).unwrap();
+	assert_eq!(
+		call,
+		RuntimeCall::TemplateModule(Call::receive_remote_value { asset: 1, value: 42 })
+	);
+}
+
+#[test]
+fn stale_prices_are_refused_and_warned_about() {
+	new_test_ext().execute_with(|| {
+		StalenessThreshold::set(4);
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 1));
+		assert_ok!(TemplateModule::register_asset(
+			RuntimeOrigin::root(),
+			1,
+			asset_endpoint(b"http://localhost:8080/prices"),
+			asset_endpoint(b"USD")
+		));
+		assert_noop!(TemplateModule::ensure_fresh(&1, 10), Error::<Test>::StalePrice);
+
+		assert_ok!(TemplateModule::submit_asset_prices(
+			RuntimeOrigin::signed(1),
+			BoundedVec::truncate_from(vec![(1, 10)])
+		));
+		System::set_block_number(3);
+		TemplateModule::on_initialize(3);
+		TemplateModule::on_finalize(3);
+		assert_ok!(TemplateModule::ensure_fresh(&1, 0));
+
+		System::set_block_number(6);
+		assert_noop!(TemplateModule::ensure_fresh(&1, 2), Error::<Test>::StalePrice);
+		assert_ok!(TemplateModule::ensure_fresh(&1, 3));
+
+		// The warning is emitted once, in the block the price becomes stale.
+		System::reset_events();
+		TemplateModule::on_initialize(6);
+		assert!(System::events().is_empty());
+		System::set_block_number(7);
+		TemplateModule::on_initialize(7);
+		System::assert_has_event(Event::ValueStaleWarning { asset: 1, updated_at: 3 }.into());
+		System::reset_events();
+		System::set_block_number(8);
+		TemplateModule::on_initialize(8);
+		assert!(System::events().is_empty());
+	});
+}
+
+#[test]
+fn offchain_worker_submits_the_prices_of_the_assets() {
+	use sp_core::offchain::testing;
+
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(vec![7u64]);
+	offchain.write().expect_request(testing::PendingRequest {
+		method: "GET".into(),
+		uri: "http://localhost:8080/dot".into(),
+		response: Some(br#"{"USD": 4.25}"#.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		assert_ok!(TemplateModule::register_asset(
+			RuntimeOrigin::root(),
+			1,
+			asset_endpoint(b"http://localhost:8080/dot"),
+			asset_endpoint(b"USD")
+		));
+		crate::NextUnsignedAt::<Test>::put(100);
+
+		// The prices are only fetched once per round.
+		TemplateModule::offchain_worker(1);
+		System::set_block_number(2);
+		TemplateModule::offchain_worker(2);
+
+		let prices = BoundedVec::truncate_from(vec![(1, 4_250_000)]);
+		let submit = RuntimeCall::TemplateModule(Call::submit_asset_prices { prices });
+		assert_eq!(
+			pool_calls(&pool).iter().filter(|call| **call == (true, submit.clone())).count(),
+			1
+		);
+	});
+}
+
+#[test]
+fn migration_to_v3_moves_the_prices_into_the_history() {
+	use crate::migrations::v3::Prices;
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<TemplateModule>();
+		Prices::<Test>::put(BoundedVec::truncate_from(vec![1, 2]));
+
+		crate::migrations::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), 3);
+		assert!(!Prices::<Test>::exists());
+		assert_eq!(TemplateModule::price_history().into_inner(), vec![1, 2]);
+
+		// Running it again is a no-op.
+		crate::migrations::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+		assert_eq!(TemplateModule::price_history().into_inner(), vec![1, 2]);
+	});
+}
+
+#[test]
+fn migration_to_v4_adds_the_response_format() {
+	use crate::migrations::v3::{OcwConfig, OcwConfigInfoV3};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(3).put::<TemplateModule>();
+		let endpoint = BoundedVec::truncate_from(b"http://localhost:8080/other".to_vec());
+		OcwConfig::<Test>::put(OcwConfigInfoV3 {
+			endpoint: endpoint.clone(),
+			fetch_interval: 3,
+			max_payload_size: 16,
+			max_retries: 2,
+		});
+
+		crate::migrations::v4::MigrateToV4::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), 4);
+		assert_eq!(
+			crate::migrations::v4::OcwConfig::<Test>::get(),
+			Some(crate::migrations::v4::OcwConfigInfoV4 {
+				endpoint,
+				fetch_interval: 3,
+				max_payload_size: 16,
+				max_retries: 2,
+				format: ResponseFormat::Json,
+			})
+		);
+	});
+}
+
+#[test]
+fn migration_to_v5_adds_the_aggregation_strategy() {
+	use crate::migrations::v4::{OcwConfig, OcwConfigInfoV4};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(4).put::<TemplateModule>();
+		let endpoint = BoundedVec::truncate_from(b"http://localhost:8080/other".to_vec());
+		OcwConfig::<Test>::put(OcwConfigInfoV4 {
+			endpoint: endpoint.clone(),
+			fetch_interval: 3,
+			max_payload_size: 16,
+			max_retries: 2,
+			format: ResponseFormat::Scale,
+		});
+
+		crate::migrations::v5::MigrateToV5::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), 5);
+		assert_eq!(
+			TemplateModule::ocw_config(),
+			Some(crate::OcwConfigInfo {
+				endpoint,
+				fetch_interval: 3,
+				max_payload_size: 16,
+				max_retries: 2,
+				format: ResponseFormat::Scale,
+				aggregation: AggregationStrategy::Median,
+			})
+		);
+	});
+}
+
+#[test]
+fn migration_to_v6_accounts_for_the_reserved_deposits() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let data = BoundedVec::try_from(vec![7u8; 5]).unwrap();
+		assert_ok!(TemplateModule::write_key_to_ocs(RuntimeOrigin::signed(1), 42));
+		assert_ok!(TemplateModule::submit_data(RuntimeOrigin::signed(1), data));
+		assert_ok!(TemplateModule::queue_task(RuntimeOrigin::signed(2), Task::FetchPrice));
+		// Deposits reserved before version 6 weren't accounted for.
+		crate::Deposits::<Test>::remove_all(None);
+		StorageVersion::new(5).put::<TemplateModule>();
+
+		crate::migrations::v6::MigrateToV6::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), 6);
+		assert_eq!(TemplateModule::deposits(1), DepositInfo { reserved: 20, reclaimable: 0 });
+		assert_eq!(TemplateModule::deposits(2), DepositInfo { reserved: 5, reclaimable: 0 });
+		assert_ok!(TemplateModule::do_try_state(1));
+	});
+}
+
+#[test]
+fn migration_to_v7_seeds_the_current_round() {
+	use crate::migrations::v7::{CurrentRound, RoundInfoV6};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(6).put::<TemplateModule>();
+		CurrentRound::<Test>::put(RoundInfoV6 {
+			index: 3,
+			started_at: 12,
+			phase: RoundPhase::Closing,
+		});
+
+		crate::migrations::v7::MigrateToV7::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), 7);
+		assert_eq!(
+			TemplateModule::current_round(),
+			RoundInfo {
+				index: 3,
+				started_at: 12,
+				phase: RoundPhase::Closing,
+				seed: TemplateModule::round_seed(3)
+			}
+		);
+	});
+}
+
+#[test]
+fn sources_of_the_tuple_are_all_queried() {
+	use crate::{
+		http::OffchainError,
+		sources::{DataSource, DataSources},
+	};
+
+	struct Fixed;
+	impl DataSource<Test> for Fixed {
+		const NAME: &'static str = "fixed";
+
+		fn fetch() -> Vec<Result<Vec<u8>, OffchainError>> {
+			vec![Ok(br#"{"value": 42}"#.to_vec()), Err(OffchainError::BodyTooLarge)]
+		}
+
+		fn parse(raw: &[u8]) -> Result<u64, OffchainError> {
+			crate::json::parse_decimal_as_fixed(raw, "value", 0)
+		}
+	}
+
+	struct Failing;
+	impl DataSource<Test> for Failing {
+		const NAME: &'static str = "failing";
+
+		fn fetch() -> Vec<Result<Vec<u8>, OffchainError>> {
+			vec![Ok(Vec::new())]
+		}
+
+		fn parse(_: &[u8]) -> Result<u64, OffchainError> {
+			Err(OffchainError::ParseFailed)
+		}
+	}
+
+	assert_eq!(
+		<(Fixed, Failing) as DataSources<Test>>::values(),
+		vec![Ok(42), Err(OffchainError::BodyTooLarge), Err(OffchainError::ParseFailed)]
+	);
+	assert!(<() as DataSources<Test>>::values().is_empty());
+}
+
+#[test]
+fn instances_are_independent() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Weather::add_authority(RuntimeOrigin::root(), 7));
+		System::assert_last_event(
+			crate::Event::<Test, crate::Instance1>::AuthorityAdded { who: 7 }.into(),
+		);
+		assert_eq!(Weather::authorities().into_inner(), vec![7]);
+		assert!(TemplateModule::authorities().is_empty());
+		assert_eq!(Balances::reserved_balance(7), 10);
+
+		assert_ok!(Weather::do_something(RuntimeOrigin::signed(1), 42));
+		assert_eq!(Weather::values_by_block(1).into_inner(), vec![42]);
+		assert!(TemplateModule::values_by_block(1).is_empty());
+
+		// The offchain keys of the default instance are left as they are.
+		assert_eq!(TemplateModule::local_key(b"key"), b"key".to_vec());
+		assert_eq!(Weather::local_key(b"key"), b"Weather::key".to_vec());
+		assert!(Weather::task_key(KeyKind::Notify, 1, 0).starts_with(b"Weather::"));
+	});
+}
+
+#[test]
+fn merkle_proofs_prove_the_inclusion_of_each_leaf() {
+	use crate::merkle;
+
+	for count in 1..=9u8 {
+		let data = (0..count).collect::<Vec<_>>();
+		let leaves = merkle::leaves::<BlakeTwo256>(&data, 1);
+		let root = merkle::root::<BlakeTwo256>(&leaves).unwrap();
+		for index in 0..count as u32 {
+			let proof = merkle::proof::<BlakeTwo256>(&leaves, index).unwrap();
+			assert!(merkle::verify::<BlakeTwo256>(&root, &proof, &[index as u8]));
+			// Another chunk, or the same chunk at another index, is not proven.
+			assert!(!merkle::verify::<BlakeTwo256>(&root, &proof, &[count]));
+			let moved = merkle::MerkleProof { index: (index + 1) % count as u32, ..proof.clone() };
+			assert!(count == 1 || !merkle::verify::<BlakeTwo256>(&root, &moved, &[index as u8]));
+			// A sibling too many or too few doesn't match the shape of the tree.
+			let mut longer = proof.clone();
+			longer.siblings.push(root);
+			assert_eq!(
+				merkle::root_from_proof::<BlakeTwo256>(&longer, leaves[index as usize]),
+				None
+			);
+			if !proof.siblings.is_empty() {
+				let mut shorter = proof;
+				shorter.siblings.pop();
+				assert_eq!(
+					merkle::root_from_proof::<BlakeTwo256>(&shorter, leaves[index as usize]),
+					None
+				);
+			}
+		}
+		assert_eq!(merkle::proof::<BlakeTwo256>(&leaves, count as u32), None);
+	}
+
+	assert_eq!(merkle::root::<BlakeTwo256>(&[]), None);
+	// A single leaf is its own root, an inner node is not a leaf.
+	let leaves = merkle::leaves::<BlakeTwo256>(b"ab", 1);
+	assert_eq!(merkle::root::<BlakeTwo256>(&leaves[..1]), Some(leaves[0]));
+	let root = merkle::root::<BlakeTwo256>(&leaves).unwrap();
+	let proof = merkle::MerkleProof { index: 0, leaf_count: 1, siblings: Vec::new() };
+	assert!(!merkle::verify::<BlakeTwo256>(
+		&root,
+		&proof,
+		&[leaves[0].as_ref(), leaves[1].as_ref()].concat()
+	));
+}
+
+#[test]
+fn chunks_of_indexed_payloads_are_verified() {
+	use crate::{merkle, PAYLOAD_CHUNK_LEN};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// Three chunks, the last one shorter.
+		let payload = (0..600u32).map(|i| i as u8).collect::<Vec<_>>();
+		assert_ok!(TemplateModule::index_payload(
+			RuntimeOrigin::signed(1),
+			payload.clone().try_into().unwrap()
+		));
+		let leaves = merkle::leaves::<BlakeTwo256>(&payload, PAYLOAD_CHUNK_LEN as usize);
+		let root = merkle::root::<BlakeTwo256>(&leaves).unwrap();
+		System::assert_last_event(Event::PayloadIndexed { who: 1, root, chunks: 3 }.into());
+		assert_eq!(TemplateModule::payload_roots(root), Some((1, 3)));
+		assert_noop!(
+			TemplateModule::index_payload(
+				RuntimeOrigin::signed(2),
+				payload.clone().try_into().unwrap()
+			),
+			Error::<Test>::PayloadAlreadyIndexed
+		);
+		assert_noop!(
+			TemplateModule::index_payload(RuntimeOrigin::signed(1), Default::default()),
+			Error::<Test>::EmptyPayload
+		);
+
+		let chunk = |index: usize| {
+			BoundedVec::try_from(
+				payload.chunks(PAYLOAD_CHUNK_LEN as usize).nth(index).unwrap().to_vec(),
+			)
+			.unwrap()
+		};
+		let proof = merkle::proof::<BlakeTwo256>(&leaves, 2).unwrap();
+		assert_ok!(TemplateModule::verify_chunk(RuntimeOrigin::signed(3), proof.clone(), chunk(2)));
+		System::assert_last_event(Event::ChunkVerified { root, index: 2 }.into());
+		assert_noop!(
+			TemplateModule::verify_chunk(RuntimeOrigin::signed(3), proof, chunk(1)),
+			Error::<Test>::InvalidProof
+		);
+		// The root of a sub-tree is not the root of an indexed payload.
+		let proof = merkle::MerkleProof { index: 0, leaf_count: 2, siblings: vec![leaves[1]] };
+		assert_noop!(
+			TemplateModule::verify_chunk(RuntimeOrigin::signed(3), proof, chunk(0)),
+			Error::<Test>::InvalidProof
+		);
+	});
+}
+
+#[test]
+fn payloads_are_stored_in_chunks_and_reassembled() {
+	use crate::{blob, compression, keys::payload_key, merkle, PAYLOAD_CHUNK_LEN};
+	use sp_core::H256;
+	use sp_runtime::offchain::storage::StorageValueRef;
+
+	let (mut t, _offchain, _pool) = new_test_ext_with_offchain();
+	let payload = (0..600u32).map(|i| i as u8).collect::<Vec<_>>();
+	let leaves = merkle::leaves::<BlakeTwo256>(&payload, PAYLOAD_CHUNK_LEN as usize);
+	let root = merkle::root::<BlakeTwo256>(&leaves).unwrap();
+	let key = payload_key(&[], &root);
+	let stored = compression::encode(&payload);
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::index_payload(
+			RuntimeOrigin::signed(1),
+			payload.clone().try_into().unwrap()
+		));
+	});
+	t.persist_offchain_overlay();
+
+	t.execute_with(|| {
+		// `ChunkSize` is 128 in the mock.
+		let chunks = stored.chunks(128).collect::<Vec<_>>();
+		let manifest = StorageValueRef::persistent(&key).get::<blob::Manifest<H256>>().unwrap();
+		assert_eq!(
+			manifest,
+			Some(blob::Manifest {
+				chunk_count: chunks.len() as u32,
+				len: stored.len() as u32,
+				hash: BlakeTwo256::hash(&stored)
+			})
+		);
+		let last = blob::chunk_key(&key, chunks.len() as u32 - 1);
+		assert_eq!(
+			StorageValueRef::persistent(&last).get::<Vec<u8>>().unwrap(),
+			chunks.last().map(|chunk| chunk.to_vec())
+		);
+		assert_eq!(TemplateModule::read_payload(&root), Some(payload.clone()));
+
+		// A tampered or a missing chunk fails the reassembly.
+		let first = blob::chunk_key(&key, 0);
+		let mut chunk = StorageValueRef::persistent(&first);
+		chunk.set(&vec![0u8; 128]);
+		assert_eq!(TemplateModule::read_payload(&root), None);
+		chunk.clear();
+		assert_eq!(TemplateModule::read_payload(&root), None);
+	});
+}
+
+#[test]
+fn payloads_are_encoded_with_their_format() {
+	use crate::compression::{decode, encode, LZ4, RAW};
+
+	let json = br#"{"symbol": "DOT", "price": 5.0}"#.repeat(20);
+	let encoded = encode(&json);
+	assert_eq!(decode(&encoded, 1024), Some(json.clone()));
+	if cfg!(feature = "compression") {
+		assert_eq!(encoded[0], LZ4);
+		assert!(encoded.len() * 5 < json.len());
+		// The length is checked before decompressing.
+		assert_eq!(decode(&encoded, json.len() as u32 - 1), None);
+	} else {
+		assert_eq!(encoded, [&[RAW][..], &json].concat());
+	}
+
+	// A payload compression doesn't shorten is stored as is.
+	assert_eq!(encode(b"ab"), vec![RAW, b'a', b'b']);
+	assert_eq!(decode(&[RAW], 0), Some(Vec::new()));
+	assert_eq!(decode(&[], 1024), None);
+	assert_eq!(decode(&[2, b'a'], 1024), None);
+}
+
+#[test]
+fn ocw_calls_are_rate_limited_per_account_and_block() {
+	use crate::CheckOcwRateLimit;
+	use frame_support::dispatch::DispatchInfo;
+	use sp_runtime::traits::SignedExtension;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let info = DispatchInfo::default();
+		let call: RuntimeCall = Call::write_key_to_ocs { number: 1 }.into();
+		let ext = CheckOcwRateLimit::<Test>::new();
+
+		// `MaxCallsPerBlock` is 2 in the mock.
+		for _ in 0..2 {
+			assert_ok!(ext.validate(&1, &call, &info, 0));
+			assert_ok!(ext.clone().pre_dispatch(&1, &call, &info, 0));
+		}
+		assert_eq!(TemplateModule::ocw_calls(&1), 2);
+		assert_eq!(
+			ext.validate(&1, &call, &info, 0),
+			Err(InvalidTransaction::ExhaustsResources.into())
+		);
+		assert_eq!(
+			ext.clone().pre_dispatch(&1, &call, &info, 0),
+			Err(InvalidTransaction::ExhaustsResources.into())
+		);
+
+		// Other calls and other accounts are not limited.
+		let other: RuntimeCall = Call::do_something { something: 1 }.into();
+		assert_ok!(ext.validate(&1, &other, &info, 0));
+		assert_ok!(ext.validate(&2, &call, &info, 0));
+
+		// The count starts over in the next block.
+		System::set_block_number(2);
+		assert_eq!(TemplateModule::ocw_calls(&1), 0);
+		assert_ok!(ext.validate(&1, &call, &info, 0));
+	});
+}
+
+#[test]
+fn urls_are_built_from_templates() {
+	use crate::url::{substitute, TemplateError, ASSET};
+
+	let params: &[(&[u8], &[u8])] = &[(ASSET, b"DOT")];
+	assert_eq!(
+		substitute(b"https://api.example.com/price?symbol={ASSET}", params),
+		Ok(b"https://api.example.com/price?symbol=DOT".to_vec())
+	);
+	assert_eq!(
+		substitute(b"https://{ASSET}.example.com/{ASSET}", params),
+		Ok(b"https://DOT.example.com/DOT".to_vec())
+	);
+	assert_eq!(
+		substitute(b"https://api.example.com", &[]),
+		Ok(b"https://api.example.com".to_vec())
+	);
+	// Values can't change the structure of the URL.
+	assert_eq!(
+		substitute(b"https://api.example.com/price?symbol={ASSET}", &[(ASSET, b"DOT&to=x/y z")]),
+		Ok(b"https://api.example.com/price?symbol=DOT%26to%3Dx%2Fy%20z".to_vec())
+	);
+	assert_eq!(substitute(b"https://{HOST}/price", params), Err(TemplateError::UnknownPlaceholder));
+	assert_eq!(
+		substitute(b"https://api.example.com/{ASSET", params),
+		Err(TemplateError::UnbalancedBrace)
+	);
+	assert_eq!(
+		substitute(b"https://api.example.com/ASSET}", params),
+		Err(TemplateError::UnbalancedBrace)
+	);
+}
+
+#[test]
+fn assets_without_endpoint_are_fetched_from_the_template() {
+	use sp_core::offchain::testing;
+
+	let (mut t, offchain, pool) = new_test_ext_with_offchain();
+	UintAuthorityId::set_all_keys(vec![7u64]);
+	offchain.write().expect_request(testing::PendingRequest {
+		method: "GET".into(),
+		uri: "http://localhost:8080/price?symbol=DOT".into(),
+		response: Some(br#"{"DOT": 4.25}"#.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+
+	t.execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TemplateModule::set_endpoint_template(
+				RuntimeOrigin::root(),
+				asset_endpoint(b"http://localhost:8080/{SYMBOL}")
+			),
+			Error::<Test>::InvalidEndpoint
+		);
+		let template = asset_endpoint(b"http://localhost:8080/price?symbol={ASSET}");
+		assert_ok!(TemplateModule::set_endpoint_template(RuntimeOrigin::root(), template.clone()));
+		System::assert_last_event(Event::EndpointTemplateSet { template }.into());
+
+		assert_ok!(TemplateModule::add_authority(RuntimeOrigin::root(), 7));
+		assert_ok!(TemplateModule::register_asset(
+			RuntimeOrigin::root(),
+			1,
+			asset_endpoint(b""),
+			asset_endpoint(b"DOT")
+		));
+		assert_eq!(
+			TemplateModule::asset_url(&TemplateModule::assets(1).unwrap()),
+			Ok(b"http://localhost:8080/price?symbol=DOT".to_vec())
+		);
+		crate::NextUnsignedAt::<Test>::put(100);
+		TemplateModule::offchain_worker(1);
+
+		let prices = BoundedVec::truncate_from(vec![(1, 4_250_000)]);
+		let submit = RuntimeCall::TemplateModule(Call::submit_asset_prices { prices });
+		assert!(pool_calls(&pool).contains(&(true, submit)));
+	});
+}
+
+// Properties of the offchain code paths, checked against generated inputs.
+proptest::proptest! {
+	#[test]
+	fn derived_keys_round_trip(kind in 0..KeyKind::ALL.len(), block_number: u64, index: u32) {
+		let kind = KeyKind::ALL[kind];
+		let key = TemplateModule::task_key(kind, block_number, index);
+		proptest::prop_assert!(key.starts_with(&TemplateModule::derived_key(kind, block_number)));
+		proptest::prop_assert_eq!(
+			TemplateModule::decode_key(&key),
+			Some(KeyDerivation { kind, block_number, index })
+		);
+		proptest::prop_assert_eq!(TemplateModule::decode_key(&key[..key.len() - 1]), None);
+		// The keys of an instance are not the keys of another.
+		proptest::prop_assert_eq!(Weather::decode_key(&key), None);
+		let key = Weather::task_key(kind, block_number, index);
+		proptest::prop_assert_eq!(TemplateModule::decode_key(&key), None);
+		proptest::prop_assert_eq!(
+			Weather::decode_key(&key),
+			Some(KeyDerivation { kind, block_number, index })
+		);
+	}
+
+	#[test]
+	fn the_json_parser_never_panics(body: Vec<u8>, decimals in 0..40u32) {
+		let _ = crate::json::parse_u64_field(&body, "USD");
+		let _ = crate::json::parse_decimal_as_fixed(&body, "USD", decimals);
+	}
+
+	#[test]
+	fn the_json_parser_rejects_truncated_responses(integer: u32, fraction in 0..1_000u32) {
+		let body = format!(r#"{{"USD": {}.{:03}}}"#, integer, fraction).into_bytes();
+		let value = integer as u64 * 1_000_000 + fraction as u64 * 1_000;
+		proptest::prop_assert_eq!(crate::json::parse_decimal_as_fixed(&body, "USD", 6), Ok(value));
+		for len in 0..body.len() {
+			proptest::prop_assert!(
+				crate::json::parse_decimal_as_fixed(&body[..len], "USD", 6).is_err()
+			);
+		}
+	}
+
+	#[test]
+	fn the_median_is_between_the_middle_values(mut values: Vec<u64>) {
+		let Some(median) = TemplateModule::median(&mut values.clone()) else {
+			proptest::prop_assert!(values.is_empty());
+			return Ok(())
+		};
+		values.sort_unstable();
+		let mid = values.len() / 2;
+		let low = values[(values.len() - 1) / 2];
+		proptest::prop_assert!(low <= median && median <= values[mid]);
+		proptest::prop_assert!(values.iter().filter(|value| **value <= median).count() >= mid);
+		proptest::prop_assert!(values.iter().filter(|value| **value >= median).count() >= mid);
+		// The order of the values doesn't matter.
+		values.reverse();
+		proptest::prop_assert_eq!(TemplateModule::median(&mut values), Some(median));
+	}
+}
+
+#[test]
+fn storage_of_both_instances_is_bounded() {
+	use frame_support::traits::StorageInfoTrait;
+
+	// Every item has a maximum encoded size, so that the proof of a block is bounded.
+	let infos = TemplateModule::storage_info().into_iter().chain(Weather::storage_info());
+	for info in infos {
+		assert!(
+			info.max_size.is_some(),
+			"{} is unbounded",
+			String::from_utf8_lossy(&info.storage_name)
+		);
+	}
+}
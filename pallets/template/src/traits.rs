@@ -0,0 +1,42 @@
+//! Traits through which other pallets of the runtime interact with the oracle.
+
+use crate::RequestId;
+use codec::{DecodeAll, Encode};
+
+/// A handler of the values the oracle finalizes, see `Config::OnNewValue`.
+///
+/// It is called for each asset whose round finalized with submissions, from `on_finalize`, so
+/// it should be cheap.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait OnNewValue<AssetId, Value> {
+	/// `value` is the new value of `asset`.
+	fn on_new_value(asset: &AssetId, value: &Value);
+}
+
+/// A provider of the latest values of the assets, so that other pallets can read them without
+/// depending on the storage of the oracle. The pallet implements it for its `Prices`.
+pub trait ProvideOracleValue<AssetId, Value> {
+	/// The block number the values are finalized in.
+	type BlockNumber;
+
+	/// The latest value of `asset` and the block it was finalized in, from which its freshness
+	/// can be judged. `None` if the asset has no value.
+	fn get(asset: &AssetId) -> Option<(Value, Self::BlockNumber)>;
+}
+
+/// The interface of the callbacks of the requests, see `Pallet::request_value`.
+///
+/// A callback is the encoding of a call without its last parameters, which must be the id of
+/// the request and its answer, a `RequestId` and a `u64`: they are appended to it once the
+/// request is fulfilled. It is implemented for all the calls which can be decoded.
+pub trait WithAnswer: Sized {
+	/// The call of `callback` with `id` and `answer` appended, `None` if it decodes to no call.
+	fn with_answer(callback: &[u8], id: RequestId, answer: u64) -> Option<Self>;
+}
+
+impl<Call: DecodeAll> WithAnswer for Call {
+	fn with_answer(callback: &[u8], id: RequestId, answer: u64) -> Option<Self> {
+		let encoded = [callback, &(id, answer).encode()[..]].concat();
+		Call::decode_all(&mut &encoded[..]).ok()
+	}
+}
@@ -0,0 +1,82 @@
+//! Construction of the URLs the offchain worker fetches from templates stored on chain, see
+//! `EndpointTemplate`.
+//!
+//! A template is a URL with placeholders, names in braces like in
+//! `https://api.example.com/price?symbol={ASSET}`. The values substituted for the placeholders
+//! are percent-encoded, so that they can't change the structure of the URL, e.g. add a query
+//! parameter or point it to another host.
+
+use frame_support::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// The placeholder of the field of an asset, see `AssetInfo`.
+pub const ASSET: &[u8] = b"ASSET";
+
+/// The placeholder of the query of a request, see `PendingRequest`.
+pub const QUERY: &[u8] = b"QUERY";
+
+/// The ways the substitution of the parameters of a template can fail.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum TemplateError {
+	/// A brace of the template is not part of a placeholder.
+	UnbalancedBrace,
+	/// No parameter is named after a placeholder of the template.
+	UnknownPlaceholder,
+}
+
+/// The URL of `template` with the values of `params`, pairs of a name and a value, substituted
+/// for the placeholders of the same name.
+pub fn substitute(template: &[u8], params: &[(&[u8], &[u8])]) -> Result<Vec<u8>, TemplateError> {
+	let mut url = Vec::with_capacity(template.len());
+	let mut rest = template;
+	while let Some(start) = rest.iter().position(|byte| *byte == b'{' || *byte == b'}') {
+		if rest[start] == b'}' {
+			return Err(TemplateError::UnbalancedBrace)
+		}
+		url.extend_from_slice(&rest[..start]);
+		let len = rest[start + 1..]
+			.iter()
+			.position(|byte| *byte == b'}')
+			.ok_or(TemplateError::UnbalancedBrace)?;
+		let name = &rest[start + 1..start + 1 + len];
+		let (_, value) = params
+			.iter()
+			.find(|(param, _)| *param == name)
+			.ok_or(TemplateError::UnknownPlaceholder)?;
+		percent_encode(value, &mut url);
+		rest = &rest[start + len + 2..];
+	}
+	url.extend_from_slice(rest);
+	Ok(url)
+}
+
+/// `url` with the query parameter `name` set to the decimal `value`, e.g.
+/// `https://api.example.com/feed?since=12`.
+pub fn with_query(url: &[u8], name: &[u8], value: u64) -> Vec<u8> {
+	let separator = if url.contains(&b'?') { b'&' } else { b'?' };
+	let mut url = [url, &[separator], name, b"="].concat();
+	let mut digits = Vec::new();
+	let mut rest = value;
+	loop {
+		digits.push(b'0' + (rest % 10) as u8);
+		rest /= 10;
+		if rest == 0 {
+			break
+		}
+	}
+	url.extend(digits.into_iter().rev());
+	url
+}
+
+/// Append `value` to `url`, with the bytes other than the unreserved characters of RFC 3986
+/// percent-encoded.
+fn percent_encode(value: &[u8], url: &mut Vec<u8>) {
+	const HEX: &[u8; 16] = b"0123456789ABCDEF";
+	for byte in value {
+		if byte.is_ascii_alphanumeric() || b"-._~".contains(byte) {
+			url.push(*byte);
+		} else {
+			url.extend_from_slice(&[b'%', HEX[(byte >> 4) as usize], HEX[(byte & 0xf) as usize]]);
+		}
+	}
+}
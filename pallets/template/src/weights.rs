@@ -36,55 +36,1999 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn do_something() -> Weight;
 	fn cause_error() -> Weight;
+	fn write_key_to_ocs() -> Weight;
+	fn submit_data_signed() -> Weight;
+	fn submit_number_unsigned() -> Weight;
+	fn submit_payload_unsigned_with_signed_payload() -> Weight;
+	fn queue_task() -> Weight;
+	fn set_oracle_endpoints() -> Weight;
+	fn report_retries_exhausted() -> Weight;
+	fn add_authority() -> Weight;
+	fn remove_authority() -> Weight;
+	fn report_submission_failure() -> Weight;
+	fn submit_data() -> Weight;
+	fn clear_data() -> Weight;
+	fn confirm_tasks(n: u32) -> Weight;
+	fn heartbeat() -> Weight;
+	fn write_batch_to_ocs(n: u32) -> Weight;
+	fn clear_ocs_key() -> Weight;
+	fn commit_value() -> Weight;
+	fn reveal_value() -> Weight;
+	fn subscribe() -> Weight;
+	fn unsubscribe() -> Weight;
+	fn update_topics(n: u32) -> Weight;
+	fn set_ocw_config() -> Weight;
+	fn register_asset() -> Weight;
+	fn deregister_asset() -> Weight;
+	fn submit_asset_prices(n: u32) -> Weight;
+	fn index_payload(l: u32) -> Weight;
+	fn verify_chunk() -> Weight;
+	fn set_endpoint_template() -> Weight;
+	fn prune_history(n: u32) -> Weight;
+	fn force_set_value() -> Weight;
+	fn submit_record_unsigned() -> Weight;
+	fn submit_record_unsigned_with_signed_payload() -> Weight;
+	fn request_document() -> Weight;
+	fn anchor_document() -> Weight;
+	fn set_attester() -> Weight;
+	fn submit_attested_data(l: u32) -> Weight;
+	fn pause() -> Weight;
+	fn unpause() -> Weight;
+	fn receive_remote_value() -> Weight;
+	fn schedule_task() -> Weight;
+	fn execute_task() -> Weight;
+	fn finalize_round(n: u32, a: u32) -> Weight;
+	fn prune_commitments(n: u32) -> Weight;
+	fn validate_signed_payload(n: u32) -> Weight;
+	fn reclaim_deposit() -> Weight;
+	fn raise_dispute() -> Weight;
+	fn resolve_dispute(n: u32) -> Weight;
+	fn set_inherent_value() -> Weight;
+	fn request_value() -> Weight;
+	fn fulfill_request() -> Weight;
+	fn refund_request() -> Weight;
+	fn expire_requests(n: u32) -> Weight;
+	fn set_change_threshold() -> Weight;
+	fn report_failure() -> Weight;
 }
 
 /// Weights for pallet_template using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ValuesByBlock (r:1 w:1)
+	/// Proof: TemplateModule ValuesByBlock (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValuesAt (r:1 w:1)
+	/// Proof: TemplateModule LatestValuesAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PreviousValuesAt (r:0 w:1)
+	/// Proof: TemplateModule PreviousValuesAt (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
 	fn do_something() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `62`
+		//  Estimated: `3606`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3606)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule LatestValuesAt (r:1 w:1)
+	/// Proof: TemplateModule LatestValuesAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ValuesByBlock (r:2 w:1)
+	/// Proof: TemplateModule ValuesByBlock (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PreviousValuesAt (r:0 w:1)
+	/// Proof: TemplateModule PreviousValuesAt (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	fn cause_error() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `62`
+		//  Estimated: `6222`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 6222)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule IndexCommitments (r:1 w:1)
+	/// Proof: TemplateModule IndexCommitments (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexedKeys (r:1 w:1)
+	/// Proof: TemplateModule IndexedKeys (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexDeposits (r:1 w:1)
+	/// Proof: TemplateModule IndexDeposits (max_values: None, max_size: Some(781), added: 3256, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn write_key_to_ocs() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `115`
+		//  Estimated: `4246`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(36_000_000, 4246)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceHistory (r:1 w:1)
+	/// Proof: TemplateModule PriceHistory (max_values: Some(1), max_size: Some(514), added: 1009, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValue (r:0 w:1)
+	/// Proof: TemplateModule LatestValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextUnsignedAt (r:0 w:1)
+	/// Proof: TemplateModule NextUnsignedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Submissions (r:0 w:1)
+	/// Proof: TemplateModule Submissions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValueUpdatedAt (r:0 w:1)
+	/// Proof: TemplateModule LatestValueUpdatedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn submit_data_signed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1101`
+		//  Estimated: `2510`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 2510)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule PriceHistory (r:1 w:1)
+	/// Proof: TemplateModule PriceHistory (max_values: Some(1), max_size: Some(514), added: 1009, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValue (r:0 w:1)
+	/// Proof: TemplateModule LatestValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextUnsignedAt (r:0 w:1)
+	/// Proof: TemplateModule NextUnsignedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValueUpdatedAt (r:0 w:1)
+	/// Proof: TemplateModule LatestValueUpdatedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn submit_number_unsigned() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1999`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 1999)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceHistory (r:1 w:1)
+	/// Proof: TemplateModule PriceHistory (max_values: Some(1), max_size: Some(514), added: 1009, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValue (r:0 w:1)
+	/// Proof: TemplateModule LatestValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextUnsignedAt (r:0 w:1)
+	/// Proof: TemplateModule NextUnsignedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Submissions (r:0 w:1)
+	/// Proof: TemplateModule Submissions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValueUpdatedAt (r:0 w:1)
+	/// Proof: TemplateModule LatestValueUpdatedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn submit_payload_unsigned_with_signed_payload() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1101`
+		//  Estimated: `2510`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 2510)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule IndexCommitments (r:1 w:1)
+	/// Proof: TemplateModule IndexCommitments (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexedKeys (r:1 w:1)
+	/// Proof: TemplateModule IndexedKeys (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexDeposits (r:1 w:1)
+	/// Proof: TemplateModule IndexDeposits (max_values: None, max_size: Some(781), added: 3256, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn queue_task() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `115`
+		//  Estimated: `4246`
+		// Minimum execution time: 37_000_000 picoseconds.
+		Weight::from_parts(37_000_000, 4246)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule OracleEndpoints (r:0 w:1)
+	/// Proof: TemplateModule OracleEndpoints (max_values: Some(1), max_size: Some(2065), added: 2560, mode: MaxEncodedLen)
+	fn set_oracle_endpoints() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	fn report_retries_exhausted() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1025`
+		//  Estimated: `2510`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 2510)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:1)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Bonds (r:0 w:1)
+	/// Proof: TemplateModule Bonds (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn add_authority() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1128`
+		//  Estimated: `3593`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:1)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Bonds (r:1 w:1)
+	/// Proof: TemplateModule Bonds (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastFailureReports (r:0 w:1)
+	/// Proof: TemplateModule LastFailureReports (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn remove_authority() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1207`
+		//  Estimated: `3593`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	fn report_submission_failure() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1025`
+		//  Estimated: `2510`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 2510)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: TemplateModule SubmittedData (r:1 w:1)
+	/// Proof: TemplateModule SubmittedData (max_values: None, max_size: Some(1090), added: 3565, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn submit_data() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `103`
+		//  Estimated: `4555`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 4555)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule SubmittedData (r:1 w:1)
+	/// Proof: TemplateModule SubmittedData (max_values: None, max_size: Some(1090), added: 3565, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn clear_data() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1177`
+		//  Estimated: `4555`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 4555)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexDeposits (r:1 w:1)
+	/// Proof: TemplateModule IndexDeposits (max_values: None, max_size: Some(781), added: 3256, mode: MaxEncodedLen)
+	/// Storage: System Account (r:16 w:16)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:16 w:16)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn confirm_tasks(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1128`
+		//  Estimated: `4246`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 4246)
+			// Standard Error: 900_000
+			.saturating_add(Weight::from_parts(18_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5158).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule LastSeen (r:0 w:1)
+	/// Proof: TemplateModule LastSeen (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn heartbeat() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule BatchCommitments (r:1 w:1)
+	/// Proof: TemplateModule BatchCommitments (max_values: None, max_size: Some(2062), added: 4537, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 64]`.
+	fn write_batch_to_ocs(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `5527`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 5527)
+			// Standard Error: 80_000
+			.saturating_add(Weight::from_parts(1_600_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule IndexedKeys (r:1 w:1)
+	/// Proof: TemplateModule IndexedKeys (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BatchCommitments (r:1 w:1)
+	/// Proof: TemplateModule BatchCommitments (max_values: None, max_size: Some(2062), added: 4537, mode: MaxEncodedLen)
+	fn clear_ocs_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2091`
+		//  Estimated: `5527`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(38_000_000, 5527)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Commitments (r:1 w:1)
+	/// Proof: TemplateModule Commitments (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CommitmentExpiries (r:1 w:1)
+	/// Proof: TemplateModule CommitmentExpiries (max_values: None, max_size: Some(1029), added: 3504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn commit_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1128`
+		//  Estimated: `4494`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(37_000_000, 4494)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Commitments (r:1 w:1)
+	/// Proof: TemplateModule Commitments (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceHistory (r:1 w:1)
+	/// Proof: TemplateModule PriceHistory (max_values: Some(1), max_size: Some(514), added: 1009, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextUnsignedAt (r:0 w:1)
+	/// Proof: TemplateModule NextUnsignedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValue (r:0 w:1)
+	/// Proof: TemplateModule LatestValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Submissions (r:0 w:1)
+	/// Proof: TemplateModule Submissions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValueUpdatedAt (r:0 w:1)
+	/// Proof: TemplateModule LatestValueUpdatedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn reveal_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1768`
+		//  Estimated: `3593`
+		// Minimum execution time: 40_000_000 picoseconds.
+		Weight::from_parts(46_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: TemplateModule Subscriptions (r:1 w:1)
+	/// Proof: TemplateModule Subscriptions (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Topics (r:1 w:1)
+	/// Proof: TemplateModule Topics (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForTopics (r:1 w:1)
+	/// Proof: TemplateModule CounterForTopics (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn subscribe() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `140`
+		//  Estimated: `3593`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(42_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule Subscriptions (r:1 w:1)
+	/// Proof: TemplateModule Subscriptions (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Topics (r:1 w:1)
+	/// Proof: TemplateModule Topics (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForTopics (r:1 w:1)
+	/// Proof: TemplateModule CounterForTopics (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn unsubscribe() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `3593`
+		// Minimum execution time: 39_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Topics (r:16 w:0)
+	/// Proof: TemplateModule Topics (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn update_topics(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1061`
+		//  Estimated: `2510`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 2510)
+			// Standard Error: 200_000
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2512).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule OcwConfig (r:0 w:1)
+	/// Proof: TemplateModule OcwConfig (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	fn set_ocw_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule Assets (r:1 w:1)
+	/// Proof: TemplateModule Assets (max_values: None, max_size: Some(536), added: 3011, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForAssets (r:1 w:1)
+	/// Proof: TemplateModule CounterForAssets (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn register_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `4001`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 4001)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule Assets (r:1 w:1)
+	/// Proof: TemplateModule Assets (max_values: None, max_size: Some(536), added: 3011, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForAssets (r:1 w:1)
+	/// Proof: TemplateModule CounterForAssets (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Prices (r:0 w:1)
+	/// Proof: TemplateModule Prices (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceTimestamps (r:0 w:1)
+	/// Proof: TemplateModule PriceTimestamps (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ChangeThresholds (r:0 w:1)
+	/// Proof: TemplateModule ChangeThresholds (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetSubmissions (r:32 w:32)
+	/// Proof: TemplateModule AssetSubmissions (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn deregister_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1581`
+		//  Estimated: `82632`
+		// Minimum execution time: 94_000_000 picoseconds.
+		Weight::from_parts(96_000_000, 82632)
+			.saturating_add(T::DbWeight::get().reads(34_u64))
+			.saturating_add(T::DbWeight::get().writes(37_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CurrentRound (r:1 w:0)
+	/// Proof: TemplateModule CurrentRound (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Assets (r:16 w:0)
+	/// Proof: TemplateModule Assets (max_values: None, max_size: Some(536), added: 3011, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetSubmissions (r:0 w:16)
+	/// Proof: TemplateModule AssetSubmissions (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn submit_asset_prices(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1109`
+		//  Estimated: `2510`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 2510)
+			// Standard Error: 150_000
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3011).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule PayloadRoots (r:1 w:1)
+	/// Proof: TemplateModule PayloadRoots (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 65536]`.
+	fn index_payload(l: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3505`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 3505)
+			// Standard Error: 10
+			.saturating_add(Weight::from_parts(2_400, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule PayloadRoots (r:1 w:0)
+	/// Proof: TemplateModule PayloadRoots (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn verify_chunk() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `120`
+		//  Estimated: `3505`
+		// Minimum execution time: 48_000_000 picoseconds.
+		Weight::from_parts(50_000_000, 3505)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: TemplateModule EndpointTemplate (r:0 w:1)
+	/// Proof: TemplateModule EndpointTemplate (max_values: Some(1), max_size: Some(258), added: 753, mode: MaxEncodedLen)
+	fn set_endpoint_template() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule HistoryCursor (r:1 w:1)
+	/// Proof: TemplateModule HistoryCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValuesAt (r:1 w:1)
+	/// Proof: TemplateModule LatestValuesAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PendingWork (r:1 w:1)
+	/// Proof: TemplateModule PendingWork (max_values: Some(1), max_size: Some(82), added: 577, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ValuesByBlock (r:0 w:1000)
+	/// Proof: TemplateModule ValuesByBlock (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PreviousValuesAt (r:0 w:1000)
+	/// Proof: TemplateModule PreviousValuesAt (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1000]`.
+	fn prune_history(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1567`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 1567)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(2_100_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: TemplateModule Assets (r:1 w:0)
+	/// Proof: TemplateModule Assets (max_values: None, max_size: Some(536), added: 3011, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Prices (r:1 w:1)
+	/// Proof: TemplateModule Prices (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceTimestamps (r:0 w:1)
+	/// Proof: TemplateModule PriceTimestamps (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn force_set_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `3986`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3986)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule LatestRecord (r:1 w:1)
+	/// Proof: TemplateModule LatestRecord (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn submit_record_unsigned() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1501`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 1501)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule LatestRecord (r:1 w:1)
+	/// Proof: TemplateModule LatestRecord (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn submit_record_unsigned_with_signed_payload() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1501`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 1501)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule DocumentRequests (r:1 w:1)
+	/// Proof: TemplateModule DocumentRequests (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Documents (r:1 w:0)
+	/// Proof: TemplateModule Documents (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	fn request_document() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3578`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 3578)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule DocumentRequests (r:1 w:1)
+	/// Proof: TemplateModule DocumentRequests (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Documents (r:0 w:1)
+	/// Proof: TemplateModule Documents (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn anchor_document() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1102`
+		//  Estimated: `3550`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 3550)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule Attesters (r:0 w:1)
+	/// Proof: TemplateModule Attesters (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	fn set_attester() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attesters (r:1 w:0)
+	/// Proof: TemplateModule Attesters (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AttestedData (r:0 w:1)
+	/// Proof: TemplateModule AttestedData (max_values: None, max_size: Some(1080), added: 3555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// The range of component `l` is `[0, 1024]`.
+	fn submit_attested_data(l: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1129`
+		//  Estimated: `3515`
+		// Minimum execution time: 66_000_000 picoseconds.
+		Weight::from_parts(67_000_000, 3515)
+			// Standard Error: 12
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule Paused (r:0 w:1)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn pause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
 		Weight::from_parts(9_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
+	/// Storage: TemplateModule Paused (r:0 w:1)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn unpause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Assets (r:1 w:0)
+	/// Proof: TemplateModule Assets (max_values: None, max_size: Some(536), added: 3011, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Prices (r:1 w:1)
+	/// Proof: TemplateModule Prices (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceTimestamps (r:0 w:1)
+	/// Proof: TemplateModule PriceTimestamps (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn receive_remote_value() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
+		//  Measured:  `180`
+		//  Estimated: `3986`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 3986)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule ScheduledTasks (r:1 w:1)
+	/// Proof: TemplateModule ScheduledTasks (max_values: None, max_size: Some(2109), added: 4584, mode: MaxEncodedLen)
+	fn schedule_task() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `5574`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 5574)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule ScheduledTasks (r:1 w:1)
+	/// Proof: TemplateModule ScheduledTasks (max_values: None, max_size: Some(2109), added: 4584, mode: MaxEncodedLen)
+	fn execute_task() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `5574`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 5574)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule OcwConfig (r:1 w:0)
+	/// Proof: TemplateModule OcwConfig (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CurrentRound (r:1 w:1)
+	/// Proof: TemplateModule CurrentRound (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Submissions (r:33 w:32)
+	/// Proof: TemplateModule Submissions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TwapObservations (r:1 w:1)
+	/// Proof: TemplateModule TwapObservations (max_values: Some(1), max_size: Some(2050), added: 2545, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Reputation (r:32 w:32)
+	/// Proof: TemplateModule Reputation (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Assets (r:17 w:0)
+	/// Proof: TemplateModule Assets (max_values: None, max_size: Some(536), added: 3011, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetSubmissions (r:528 w:512)
+	/// Proof: TemplateModule AssetSubmissions (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Prices (r:16 w:16)
+	/// Proof: TemplateModule Prices (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceTimestamps (r:0 w:16)
+	/// Proof: TemplateModule PriceTimestamps (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ChangeThresholds (r:16 w:0)
+	/// Proof: TemplateModule ChangeThresholds (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestRound (r:0 w:1)
+	/// Proof: TemplateModule LatestRound (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Snapshots (r:0 w:1)
+	/// Proof: TemplateModule Snapshots (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestSubmissions (r:0 w:1)
+	/// Proof: TemplateModule LatestSubmissions (max_values: Some(1), max_size: Some(1281), added: 1776, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	/// The range of component `a` is `[0, 16]`.
+	fn finalize_round(n: u32, a: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2310`
+		//  Estimated: `3535`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 3535)
+			// Standard Error: 95_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			// Standard Error: 190_000
+			.saturating_add(Weight::from_parts(46_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((35_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((34_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 5074).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 87154).saturating_mul(a.into()))
+	}
+	/// Storage: TemplateModule CommitmentsCursor (r:1 w:1)
+	/// Proof: TemplateModule CommitmentsCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexCommitments (r:0 w:100)
+	/// Proof: TemplateModule IndexCommitments (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexedKeys (r:0 w:100)
+	/// Proof: TemplateModule IndexedKeys (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BatchCommitments (r:0 w:100)
+	/// Proof: TemplateModule BatchCommitments (max_values: None, max_size: Some(2062), added: 4537, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexDeposits (r:100 w:100)
+	/// Proof: TemplateModule IndexDeposits (max_values: None, max_size: Some(781), added: 3256, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:16 w:16)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn prune_commitments(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
 		//  Estimated: `1489`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 1489)
+			// Standard Error: 60_000
+			.saturating_add(Weight::from_parts(24_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((17_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((20_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 44136).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextUnsignedAt (r:1 w:0)
+	/// Proof: TemplateModule NextUnsignedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CurrentRound (r:1 w:0)
+	/// Proof: TemplateModule CurrentRound (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValue (r:1 w:0)
+	/// Proof: TemplateModule LatestValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValueUpdatedAt (r:1 w:0)
+	/// Proof: TemplateModule LatestValueUpdatedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	fn validate_signed_payload(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1144`
+		//  Estimated: `2510`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(51_000_000, 2510)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(60_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+	}
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn reclaim_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `316`
+		//  Estimated: `3593`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule LatestRound (r:1 w:0)
+	/// Proof: TemplateModule LatestRound (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Disputes (r:1 w:1)
+	/// Proof: TemplateModule Disputes (max_values: None, max_size: Some(1341), added: 3816, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestSubmissions (r:1 w:0)
+	/// Proof: TemplateModule LatestSubmissions (max_values: Some(1), max_size: Some(1281), added: 1776, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn raise_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1572`
+		//  Estimated: `4806`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 4806)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule Disputes (r:1 w:1)
+	/// Proof: TemplateModule Disputes (max_values: None, max_size: Some(1341), added: 3816, mode: MaxEncodedLen)
+	/// Storage: System Account (r:33 w:33)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestRound (r:1 w:1)
+	/// Proof: TemplateModule LatestRound (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Authorities (r:1 w:32)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Bonds (r:32 w:32)
+	/// Proof: TemplateModule Bonds (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Reputation (r:0 w:32)
+	/// Proof: TemplateModule Reputation (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 32]`.
+	fn resolve_dispute(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2896`
+		//  Estimated: `4806`
+		// Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(47_000_000, 4806)
+			// Standard Error: 31_000
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5142).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestRound (r:1 w:0)
+	/// Proof: TemplateModule LatestRound (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceHistory (r:1 w:1)
+	/// Proof: TemplateModule PriceHistory (max_values: Some(1), max_size: Some(514), added: 1009, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValue (r:0 w:1)
+	/// Proof: TemplateModule LatestValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextUnsignedAt (r:0 w:1)
+	/// Proof: TemplateModule NextUnsignedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValueUpdatedAt (r:0 w:1)
+	/// Proof: TemplateModule LatestValueUpdatedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_inherent_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `92`
+		//  Estimated: `1999`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 1999)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForRequests (r:1 w:1)
+	/// Proof: TemplateModule CounterForRequests (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextRequestId (r:1 w:1)
+	/// Proof: TemplateModule NextRequestId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Requests (r:1 w:1)
+	/// Proof: TemplateModule Requests (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RequestsByExpiry (r:1 w:1)
+	/// Proof: TemplateModule RequestsByExpiry (max_values: None, max_size: Some(526), added: 3001, mode: MaxEncodedLen)
+	fn request_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `218`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(53_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Requests (r:1 w:1)
+	/// Proof: TemplateModule Requests (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForRequests (r:1 w:1)
+	/// Proof: TemplateModule CounterForRequests (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RequestsByExpiry (r:1 w:1)
+	/// Proof: TemplateModule RequestsByExpiry (max_values: None, max_size: Some(526), added: 3001, mode: MaxEncodedLen)
+	fn fulfill_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1466`
+		//  Estimated: `6196`
+		// Minimum execution time: 50_000_000 picoseconds.
+		Weight::from_parts(51_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule Requests (r:1 w:1)
+	/// Proof: TemplateModule Requests (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForRequests (r:1 w:1)
+	/// Proof: TemplateModule CounterForRequests (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn refund_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `461`
+		//  Estimated: `6196`
+		// Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(46_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplateModule RequestsByExpiry (r:2 w:2)
+	/// Proof: TemplateModule RequestsByExpiry (max_values: None, max_size: Some(526), added: 3001, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Requests (r:64 w:64)
+	/// Proof: TemplateModule Requests (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForRequests (r:1 w:1)
+	/// Proof: TemplateModule CounterForRequests (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:65 w:65)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 64]`.
+	fn expire_requests(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `204 + n * (229 ±0)`
+		//  Estimated: `3991 + n * (2932 ±0)`
 		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+		Weight::from_parts(7_000_000, 3991)
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_parts(31_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2932).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule Assets (r:1 w:0)
+	/// Proof: TemplateModule Assets (max_values: None, max_size: Some(536), added: 3011, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ChangeThresholds (r:0 w:1)
+	/// Proof: TemplateModule ChangeThresholds (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	fn set_change_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `4001`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4001)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastFailureReports (r:1 w:1)
+	/// Proof: TemplateModule LastFailureReports (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FailureReports (r:1 w:1)
+	/// Proof: TemplateModule FailureReports (max_values: Some(1), max_size: Some(4610), added: 5105, mode: MaxEncodedLen)
+	fn report_failure() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5815`
+		//  Estimated: `6095`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(32_000_000, 6095)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ValuesByBlock (r:1 w:1)
+	/// Proof: TemplateModule ValuesByBlock (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValuesAt (r:1 w:1)
+	/// Proof: TemplateModule LatestValuesAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PreviousValuesAt (r:0 w:1)
+	/// Proof: TemplateModule PreviousValuesAt (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
 	fn do_something() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `62`
+		//  Estimated: `3606`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3606)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule LatestValuesAt (r:1 w:1)
+	/// Proof: TemplateModule LatestValuesAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ValuesByBlock (r:2 w:1)
+	/// Proof: TemplateModule ValuesByBlock (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PreviousValuesAt (r:0 w:1)
+	/// Proof: TemplateModule PreviousValuesAt (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	fn cause_error() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `62`
+		//  Estimated: `6222`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 6222)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule IndexCommitments (r:1 w:1)
+	/// Proof: TemplateModule IndexCommitments (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexedKeys (r:1 w:1)
+	/// Proof: TemplateModule IndexedKeys (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexDeposits (r:1 w:1)
+	/// Proof: TemplateModule IndexDeposits (max_values: None, max_size: Some(781), added: 3256, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn write_key_to_ocs() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `115`
+		//  Estimated: `4246`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(36_000_000, 4246)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceHistory (r:1 w:1)
+	/// Proof: TemplateModule PriceHistory (max_values: Some(1), max_size: Some(514), added: 1009, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValue (r:0 w:1)
+	/// Proof: TemplateModule LatestValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextUnsignedAt (r:0 w:1)
+	/// Proof: TemplateModule NextUnsignedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Submissions (r:0 w:1)
+	/// Proof: TemplateModule Submissions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValueUpdatedAt (r:0 w:1)
+	/// Proof: TemplateModule LatestValueUpdatedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn submit_data_signed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1101`
+		//  Estimated: `2510`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 2510)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule PriceHistory (r:1 w:1)
+	/// Proof: TemplateModule PriceHistory (max_values: Some(1), max_size: Some(514), added: 1009, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValue (r:0 w:1)
+	/// Proof: TemplateModule LatestValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextUnsignedAt (r:0 w:1)
+	/// Proof: TemplateModule NextUnsignedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValueUpdatedAt (r:0 w:1)
+	/// Proof: TemplateModule LatestValueUpdatedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn submit_number_unsigned() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1999`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 1999)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceHistory (r:1 w:1)
+	/// Proof: TemplateModule PriceHistory (max_values: Some(1), max_size: Some(514), added: 1009, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValue (r:0 w:1)
+	/// Proof: TemplateModule LatestValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextUnsignedAt (r:0 w:1)
+	/// Proof: TemplateModule NextUnsignedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Submissions (r:0 w:1)
+	/// Proof: TemplateModule Submissions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValueUpdatedAt (r:0 w:1)
+	/// Proof: TemplateModule LatestValueUpdatedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn submit_payload_unsigned_with_signed_payload() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1101`
+		//  Estimated: `2510`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 2510)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule IndexCommitments (r:1 w:1)
+	/// Proof: TemplateModule IndexCommitments (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexedKeys (r:1 w:1)
+	/// Proof: TemplateModule IndexedKeys (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexDeposits (r:1 w:1)
+	/// Proof: TemplateModule IndexDeposits (max_values: None, max_size: Some(781), added: 3256, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn queue_task() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `115`
+		//  Estimated: `4246`
+		// Minimum execution time: 37_000_000 picoseconds.
+		Weight::from_parts(37_000_000, 4246)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule OracleEndpoints (r:0 w:1)
+	/// Proof: TemplateModule OracleEndpoints (max_values: Some(1), max_size: Some(2065), added: 2560, mode: MaxEncodedLen)
+	fn set_oracle_endpoints() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	fn report_retries_exhausted() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1025`
+		//  Estimated: `2510`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 2510)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:1)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Bonds (r:0 w:1)
+	/// Proof: TemplateModule Bonds (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn add_authority() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1128`
+		//  Estimated: `3593`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:1)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Bonds (r:1 w:1)
+	/// Proof: TemplateModule Bonds (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastFailureReports (r:0 w:1)
+	/// Proof: TemplateModule LastFailureReports (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn remove_authority() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1207`
+		//  Estimated: `3593`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	fn report_submission_failure() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1025`
+		//  Estimated: `2510`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 2510)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: TemplateModule SubmittedData (r:1 w:1)
+	/// Proof: TemplateModule SubmittedData (max_values: None, max_size: Some(1090), added: 3565, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn submit_data() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `103`
+		//  Estimated: `4555`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 4555)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule SubmittedData (r:1 w:1)
+	/// Proof: TemplateModule SubmittedData (max_values: None, max_size: Some(1090), added: 3565, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn clear_data() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1177`
+		//  Estimated: `4555`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 4555)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexDeposits (r:1 w:1)
+	/// Proof: TemplateModule IndexDeposits (max_values: None, max_size: Some(781), added: 3256, mode: MaxEncodedLen)
+	/// Storage: System Account (r:16 w:16)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:16 w:16)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn confirm_tasks(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1128`
+		//  Estimated: `4246`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 4246)
+			// Standard Error: 900_000
+			.saturating_add(Weight::from_parts(18_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5158).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule LastSeen (r:0 w:1)
+	/// Proof: TemplateModule LastSeen (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn heartbeat() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule BatchCommitments (r:1 w:1)
+	/// Proof: TemplateModule BatchCommitments (max_values: None, max_size: Some(2062), added: 4537, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 64]`.
+	fn write_batch_to_ocs(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `5527`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 5527)
+			// Standard Error: 80_000
+			.saturating_add(Weight::from_parts(1_600_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule IndexedKeys (r:1 w:1)
+	/// Proof: TemplateModule IndexedKeys (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BatchCommitments (r:1 w:1)
+	/// Proof: TemplateModule BatchCommitments (max_values: None, max_size: Some(2062), added: 4537, mode: MaxEncodedLen)
+	fn clear_ocs_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2091`
+		//  Estimated: `5527`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(38_000_000, 5527)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Commitments (r:1 w:1)
+	/// Proof: TemplateModule Commitments (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CommitmentExpiries (r:1 w:1)
+	/// Proof: TemplateModule CommitmentExpiries (max_values: None, max_size: Some(1029), added: 3504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn commit_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1128`
+		//  Estimated: `4494`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(37_000_000, 4494)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Commitments (r:1 w:1)
+	/// Proof: TemplateModule Commitments (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceHistory (r:1 w:1)
+	/// Proof: TemplateModule PriceHistory (max_values: Some(1), max_size: Some(514), added: 1009, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextUnsignedAt (r:0 w:1)
+	/// Proof: TemplateModule NextUnsignedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValue (r:0 w:1)
+	/// Proof: TemplateModule LatestValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Submissions (r:0 w:1)
+	/// Proof: TemplateModule Submissions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValueUpdatedAt (r:0 w:1)
+	/// Proof: TemplateModule LatestValueUpdatedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn reveal_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1768`
+		//  Estimated: `3593`
+		// Minimum execution time: 40_000_000 picoseconds.
+		Weight::from_parts(46_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: TemplateModule Subscriptions (r:1 w:1)
+	/// Proof: TemplateModule Subscriptions (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Topics (r:1 w:1)
+	/// Proof: TemplateModule Topics (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForTopics (r:1 w:1)
+	/// Proof: TemplateModule CounterForTopics (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn subscribe() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `140`
+		//  Estimated: `3593`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(42_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule Subscriptions (r:1 w:1)
+	/// Proof: TemplateModule Subscriptions (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Topics (r:1 w:1)
+	/// Proof: TemplateModule Topics (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForTopics (r:1 w:1)
+	/// Proof: TemplateModule CounterForTopics (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn unsubscribe() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `3593`
+		// Minimum execution time: 39_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Topics (r:16 w:0)
+	/// Proof: TemplateModule Topics (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn update_topics(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1061`
+		//  Estimated: `2510`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 2510)
+			// Standard Error: 200_000
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2512).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule OcwConfig (r:0 w:1)
+	/// Proof: TemplateModule OcwConfig (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	fn set_ocw_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule Assets (r:1 w:1)
+	/// Proof: TemplateModule Assets (max_values: None, max_size: Some(536), added: 3011, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForAssets (r:1 w:1)
+	/// Proof: TemplateModule CounterForAssets (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn register_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `4001`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 4001)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule Assets (r:1 w:1)
+	/// Proof: TemplateModule Assets (max_values: None, max_size: Some(536), added: 3011, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForAssets (r:1 w:1)
+	/// Proof: TemplateModule CounterForAssets (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Prices (r:0 w:1)
+	/// Proof: TemplateModule Prices (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceTimestamps (r:0 w:1)
+	/// Proof: TemplateModule PriceTimestamps (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ChangeThresholds (r:0 w:1)
+	/// Proof: TemplateModule ChangeThresholds (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetSubmissions (r:32 w:32)
+	/// Proof: TemplateModule AssetSubmissions (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn deregister_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1581`
+		//  Estimated: `82632`
+		// Minimum execution time: 94_000_000 picoseconds.
+		Weight::from_parts(96_000_000, 82632)
+			.saturating_add(RocksDbWeight::get().reads(34_u64))
+			.saturating_add(RocksDbWeight::get().writes(37_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CurrentRound (r:1 w:0)
+	/// Proof: TemplateModule CurrentRound (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Assets (r:16 w:0)
+	/// Proof: TemplateModule Assets (max_values: None, max_size: Some(536), added: 3011, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetSubmissions (r:0 w:16)
+	/// Proof: TemplateModule AssetSubmissions (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn submit_asset_prices(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1109`
+		//  Estimated: `2510`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 2510)
+			// Standard Error: 150_000
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3011).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule PayloadRoots (r:1 w:1)
+	/// Proof: TemplateModule PayloadRoots (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 65536]`.
+	fn index_payload(l: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3505`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 3505)
+			// Standard Error: 10
+			.saturating_add(Weight::from_parts(2_400, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule PayloadRoots (r:1 w:0)
+	/// Proof: TemplateModule PayloadRoots (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn verify_chunk() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `120`
+		//  Estimated: `3505`
+		// Minimum execution time: 48_000_000 picoseconds.
+		Weight::from_parts(50_000_000, 3505)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: TemplateModule EndpointTemplate (r:0 w:1)
+	/// Proof: TemplateModule EndpointTemplate (max_values: Some(1), max_size: Some(258), added: 753, mode: MaxEncodedLen)
+	fn set_endpoint_template() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule HistoryCursor (r:1 w:1)
+	/// Proof: TemplateModule HistoryCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValuesAt (r:1 w:1)
+	/// Proof: TemplateModule LatestValuesAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PendingWork (r:1 w:1)
+	/// Proof: TemplateModule PendingWork (max_values: Some(1), max_size: Some(82), added: 577, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ValuesByBlock (r:0 w:1000)
+	/// Proof: TemplateModule ValuesByBlock (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PreviousValuesAt (r:0 w:1000)
+	/// Proof: TemplateModule PreviousValuesAt (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 1000]`.
+	fn prune_history(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1567`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 1567)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(2_100_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: TemplateModule Assets (r:1 w:0)
+	/// Proof: TemplateModule Assets (max_values: None, max_size: Some(536), added: 3011, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Prices (r:1 w:1)
+	/// Proof: TemplateModule Prices (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceTimestamps (r:0 w:1)
+	/// Proof: TemplateModule PriceTimestamps (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn force_set_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `3986`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3986)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule LatestRecord (r:1 w:1)
+	/// Proof: TemplateModule LatestRecord (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn submit_record_unsigned() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1501`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 1501)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule LatestRecord (r:1 w:1)
+	/// Proof: TemplateModule LatestRecord (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn submit_record_unsigned_with_signed_payload() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1501`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 1501)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule DocumentRequests (r:1 w:1)
+	/// Proof: TemplateModule DocumentRequests (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Documents (r:1 w:0)
+	/// Proof: TemplateModule Documents (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	fn request_document() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3578`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 3578)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule DocumentRequests (r:1 w:1)
+	/// Proof: TemplateModule DocumentRequests (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Documents (r:0 w:1)
+	/// Proof: TemplateModule Documents (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn anchor_document() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1102`
+		//  Estimated: `3550`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 3550)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule Attesters (r:0 w:1)
+	/// Proof: TemplateModule Attesters (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	fn set_attester() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attesters (r:1 w:0)
+	/// Proof: TemplateModule Attesters (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AttestedData (r:0 w:1)
+	/// Proof: TemplateModule AttestedData (max_values: None, max_size: Some(1080), added: 3555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// The range of component `l` is `[0, 1024]`.
+	fn submit_attested_data(l: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1129`
+		//  Estimated: `3515`
+		// Minimum execution time: 66_000_000 picoseconds.
+		Weight::from_parts(67_000_000, 3515)
+			// Standard Error: 12
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule Paused (r:0 w:1)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn pause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
 		Weight::from_parts(9_000_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
+	/// Storage: TemplateModule Paused (r:0 w:1)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn unpause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Assets (r:1 w:0)
+	/// Proof: TemplateModule Assets (max_values: None, max_size: Some(536), added: 3011, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Prices (r:1 w:1)
+	/// Proof: TemplateModule Prices (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceTimestamps (r:0 w:1)
+	/// Proof: TemplateModule PriceTimestamps (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn receive_remote_value() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
+		//  Measured:  `180`
+		//  Estimated: `3986`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 3986)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule ScheduledTasks (r:1 w:1)
+	/// Proof: TemplateModule ScheduledTasks (max_values: None, max_size: Some(2109), added: 4584, mode: MaxEncodedLen)
+	fn schedule_task() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `5574`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 5574)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule ScheduledTasks (r:1 w:1)
+	/// Proof: TemplateModule ScheduledTasks (max_values: None, max_size: Some(2109), added: 4584, mode: MaxEncodedLen)
+	fn execute_task() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `5574`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 5574)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule OcwConfig (r:1 w:0)
+	/// Proof: TemplateModule OcwConfig (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CurrentRound (r:1 w:1)
+	/// Proof: TemplateModule CurrentRound (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Submissions (r:33 w:32)
+	/// Proof: TemplateModule Submissions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TwapObservations (r:1 w:1)
+	/// Proof: TemplateModule TwapObservations (max_values: Some(1), max_size: Some(2050), added: 2545, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Reputation (r:32 w:32)
+	/// Proof: TemplateModule Reputation (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Assets (r:17 w:0)
+	/// Proof: TemplateModule Assets (max_values: None, max_size: Some(536), added: 3011, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetSubmissions (r:528 w:512)
+	/// Proof: TemplateModule AssetSubmissions (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Prices (r:16 w:16)
+	/// Proof: TemplateModule Prices (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceTimestamps (r:0 w:16)
+	/// Proof: TemplateModule PriceTimestamps (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ChangeThresholds (r:16 w:0)
+	/// Proof: TemplateModule ChangeThresholds (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestRound (r:0 w:1)
+	/// Proof: TemplateModule LatestRound (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Snapshots (r:0 w:1)
+	/// Proof: TemplateModule Snapshots (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestSubmissions (r:0 w:1)
+	/// Proof: TemplateModule LatestSubmissions (max_values: Some(1), max_size: Some(1281), added: 1776, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	/// The range of component `a` is `[0, 16]`.
+	fn finalize_round(n: u32, a: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2310`
+		//  Estimated: `3535`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 3535)
+			// Standard Error: 95_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			// Standard Error: 190_000
+			.saturating_add(Weight::from_parts(46_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().reads((35_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((34_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 5074).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 87154).saturating_mul(a.into()))
+	}
+	/// Storage: TemplateModule CommitmentsCursor (r:1 w:1)
+	/// Proof: TemplateModule CommitmentsCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexCommitments (r:0 w:100)
+	/// Proof: TemplateModule IndexCommitments (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexedKeys (r:0 w:100)
+	/// Proof: TemplateModule IndexedKeys (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BatchCommitments (r:0 w:100)
+	/// Proof: TemplateModule BatchCommitments (max_values: None, max_size: Some(2062), added: 4537, mode: MaxEncodedLen)
+	/// Storage: TemplateModule IndexDeposits (r:100 w:100)
+	/// Proof: TemplateModule IndexDeposits (max_values: None, max_size: Some(781), added: 3256, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:16 w:16)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn prune_commitments(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
 		//  Estimated: `1489`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 1489)
+			// Standard Error: 60_000
+			.saturating_add(Weight::from_parts(24_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((17_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((20_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 44136).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextUnsignedAt (r:1 w:0)
+	/// Proof: TemplateModule NextUnsignedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CurrentRound (r:1 w:0)
+	/// Proof: TemplateModule CurrentRound (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValue (r:1 w:0)
+	/// Proof: TemplateModule LatestValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValueUpdatedAt (r:1 w:0)
+	/// Proof: TemplateModule LatestValueUpdatedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	fn validate_signed_payload(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1144`
+		//  Estimated: `2510`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(51_000_000, 2510)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(60_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+	}
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn reclaim_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `316`
+		//  Estimated: `3593`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule LatestRound (r:1 w:0)
+	/// Proof: TemplateModule LatestRound (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Disputes (r:1 w:1)
+	/// Proof: TemplateModule Disputes (max_values: None, max_size: Some(1341), added: 3816, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestSubmissions (r:1 w:0)
+	/// Proof: TemplateModule LatestSubmissions (max_values: Some(1), max_size: Some(1281), added: 1776, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn raise_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1572`
+		//  Estimated: `4806`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 4806)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule Disputes (r:1 w:1)
+	/// Proof: TemplateModule Disputes (max_values: None, max_size: Some(1341), added: 3816, mode: MaxEncodedLen)
+	/// Storage: System Account (r:33 w:33)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Deposits (r:1 w:1)
+	/// Proof: TemplateModule Deposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestRound (r:1 w:1)
+	/// Proof: TemplateModule LatestRound (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Authorities (r:1 w:32)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Bonds (r:32 w:32)
+	/// Proof: TemplateModule Bonds (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Reputation (r:0 w:32)
+	/// Proof: TemplateModule Reputation (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 32]`.
+	fn resolve_dispute(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2896`
+		//  Estimated: `4806`
+		// Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(47_000_000, 4806)
+			// Standard Error: 31_000
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5142).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestRound (r:1 w:0)
+	/// Proof: TemplateModule LatestRound (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PriceHistory (r:1 w:1)
+	/// Proof: TemplateModule PriceHistory (max_values: Some(1), max_size: Some(514), added: 1009, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValue (r:0 w:1)
+	/// Proof: TemplateModule LatestValue (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextUnsignedAt (r:0 w:1)
+	/// Proof: TemplateModule NextUnsignedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestValueUpdatedAt (r:0 w:1)
+	/// Proof: TemplateModule LatestValueUpdatedAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_inherent_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `92`
+		//  Estimated: `1999`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 1999)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForRequests (r:1 w:1)
+	/// Proof: TemplateModule CounterForRequests (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextRequestId (r:1 w:1)
+	/// Proof: TemplateModule NextRequestId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Requests (r:1 w:1)
+	/// Proof: TemplateModule Requests (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RequestsByExpiry (r:1 w:1)
+	/// Proof: TemplateModule RequestsByExpiry (max_values: None, max_size: Some(526), added: 3001, mode: MaxEncodedLen)
+	fn request_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `218`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(53_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Requests (r:1 w:1)
+	/// Proof: TemplateModule Requests (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForRequests (r:1 w:1)
+	/// Proof: TemplateModule CounterForRequests (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RequestsByExpiry (r:1 w:1)
+	/// Proof: TemplateModule RequestsByExpiry (max_values: None, max_size: Some(526), added: 3001, mode: MaxEncodedLen)
+	fn fulfill_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1466`
+		//  Estimated: `6196`
+		// Minimum execution time: 50_000_000 picoseconds.
+		Weight::from_parts(51_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule Requests (r:1 w:1)
+	/// Proof: TemplateModule Requests (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForRequests (r:1 w:1)
+	/// Proof: TemplateModule CounterForRequests (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn refund_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `461`
+		//  Estimated: `6196`
+		// Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(46_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplateModule RequestsByExpiry (r:2 w:2)
+	/// Proof: TemplateModule RequestsByExpiry (max_values: None, max_size: Some(526), added: 3001, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Requests (r:64 w:64)
+	/// Proof: TemplateModule Requests (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: TemplateModule CounterForRequests (r:1 w:1)
+	/// Proof: TemplateModule CounterForRequests (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:65 w:65)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 64]`.
+	fn expire_requests(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `204 + n * (229 ±0)`
+		//  Estimated: `3991 + n * (2932 ±0)`
 		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+		Weight::from_parts(7_000_000, 3991)
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_parts(31_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2932).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule Assets (r:1 w:0)
+	/// Proof: TemplateModule Assets (max_values: None, max_size: Some(536), added: 3011, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ChangeThresholds (r:0 w:1)
+	/// Proof: TemplateModule ChangeThresholds (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	fn set_change_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `4001`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4001)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: TemplateModule Authorities (r:1 w:0)
+	/// Proof: TemplateModule Authorities (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastFailureReports (r:1 w:1)
+	/// Proof: TemplateModule LastFailureReports (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FailureReports (r:1 w:1)
+	/// Proof: TemplateModule FailureReports (max_values: Some(1), max_size: Some(4610), added: 5105, mode: MaxEncodedLen)
+	fn report_failure() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5815`
+		//  Estimated: `6095`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(32_000_000, 6095)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -6,6 +6,7 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+use codec::Encode;
 use pallet_grandpa::AuthorityId as GrandpaId;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -13,7 +14,8 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
+		self, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One,
+		StaticLookup, Verify,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
@@ -36,7 +38,7 @@ pub use frame_support::{
 		},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -104,10 +106,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -268,12 +270,177 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const OcwHttpEndpoint: &'static str =
+		"https://min-api.cryptocompare.com/data/price?fsym=DOT&tsyms=USD";
+	pub const OcwPriceField: &'static str = "USD";
+	/// Finalized rounds are not reported anywhere by default.
+	pub const OcwReportEndpoint: &'static str = "";
+	/// Snapshots are anchored every hour, but not archived anywhere by default.
+	pub const OcwArchiveEndpoint: &'static str = "";
+	/// Topics are currency symbols, whose DOT price is read from the field named after them.
+	pub const OcwTopicEndpoint: &'static str =
+		"https://min-api.cryptocompare.com/data/price?fsym=DOT&tsyms=";
+	/// No feed is followed by default.
+	pub const OcwFeedEndpoint: &'static str = "";
+	pub const OcwIpfsGateway: &'static str = "https://ipfs.io";
+	/// Queries are currency symbols, whose USD price answers them.
+	pub const OcwQueryEndpoint: &'static str =
+		"https://min-api.cryptocompare.com/data/price?fsym={QUERY}&tsyms=USD";
+	pub const OcwPalletId: PalletId = PalletId(*b"py/ocwtp");
+	/// A tenth of the weight of a block.
+	pub OcwMaxCallbackWeight: Weight =
+		Perbill::from_percent(10) * BlockWeights::get().max_block;
+	pub const OcwMaxDeviation: Perbill = Perbill::from_percent(5);
+	pub const OcwFinalizationMode: pallet_template::FinalizationMode =
+		pallet_template::FinalizationMode::RoundEnd;
+	pub const OcwMaxChangePerRound: Perbill = Perbill::from_percent(50);
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type AuthorityId = pallet_template::crypto::OcwAuthId;
+	type UnsignedPriority = ConstU64<{ 1 << 20 }>;
+	type HttpEndpoint = OcwHttpEndpoint;
+	type PriceField = OcwPriceField;
+	type Sources = (pallet_template::sources::HttpJson,);
+	type MaxPrices = ConstU32<64>;
+	type TimeProvider = Timestamp;
+	type TwapWindow = ConstU64<{ 60 * 60 * 1000 }>;
+	type MaxTwapObservations = ConstU32<128>;
+	type LockTimeout = ConstU64<3_000>;
+	type WorkerBudgetMs = ConstU64<2_000>;
+	type UnsignedInterval = ConstU32<5>;
+	type TxLongevity = ConstU32<{ 10 * MINUTES }>;
+	type MaxIndexedPerBlock = ConstU32<16>;
+	type CommitmentRetention = ConstU32<{ 7 * DAYS }>;
+	type HistoryDepth = ConstU32<{ 30 * DAYS }>;
+	type MaxHistoryPrune = ConstU32<1_000>;
+	type MaxPendingWork = ConstU32<16>;
+	type MaxEndpoints = ConstU32<8>;
+	type MaxEndpointLen = ConstU32<256>;
+	type MaxRetries = ConstU32<5>;
+	type MaxAuthorities = ConstU32<32>;
+	type Currency = Balances;
+	type MaxDataLen = ConstU32<1024>;
+	type DataDepositBase = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+	type DataDepositPerByte = ConstU128<{ EXISTENTIAL_DEPOSIT / 10 }>;
+	type IndexingDeposit = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+	type HeartbeatPeriod = ConstU32<{ 10 * MINUTES }>;
+	type MaxBatch = ConstU32<64>;
+	type MaxBatchLabelLen = ConstU32<32>;
+	type MaxPayloadLen = ConstU32<65_536>;
+	type ChunkSize = ConstU32<4096>;
+	type MaxCallsPerBlock = ConstU32<8>;
+	type RetentionBlocks = ConstU32<DAYS>;
+	type LegacyKeysUntil = ConstU32<0>;
+	type RevealDelay = ConstU32<5>;
+	type RevealWindow = ConstU32<{ 2 * MINUTES }>;
+	type CommitDeposit = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+	type BlockNumberProvider = System;
+	type RoundLength = ConstU32<10>;
+	type ClosingPeriod = ConstU32<2>;
+	type FinalizationMode = OcwFinalizationMode;
+	type AuthorityBond = ConstU128<{ 1000 * EXISTENTIAL_DEPOSIT }>;
+	type MinDisputeBond = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+	type MaxDeviation = OcwMaxDeviation;
+	type ChangeThresholdBps = ConstU32<100>;
+	type AcceptInherentValues = ConstBool<true>;
+	type MaxStrikes = ConstU32<3>;
+	type CacheTtl = ConstU64<{ 10 * MILLISECS_PER_BLOCK }>;
+	type HttpTimeoutMs = ConstU64<2_000>;
+	type MaxOcwPayload = ConstU32<{ 64 * 1024 }>;
+	type MinRequestIntervalMs = ConstU64<1_000>;
+	type MaxJitterMs = ConstU64<1_000>;
+	type FetchersPerRound = ConstU32<0>;
+	type LeaderTimeout = ConstU32<3>;
+	type MinValue = ConstU64<1>;
+	type MaxValue = ConstU64<{ 1_000_000 * 1_000_000 }>;
+	type MaxChangePerRound = OcwMaxChangePerRound;
+	type ReportEndpoint = OcwReportEndpoint;
+	type SnapshotPeriod = ConstU32<60>;
+	type ArchiveEndpoint = OcwArchiveEndpoint;
+	type TopicEndpoint = OcwTopicEndpoint;
+	type FeedEndpoint = OcwFeedEndpoint;
+	type IpfsGateway = OcwIpfsGateway;
+	type MaxCidLen = ConstU32<64>;
+	type QueryEndpoint = OcwQueryEndpoint;
+	type MaxQueryLen = ConstU32<128>;
+	type MaxPendingRequests = ConstU32<64>;
+	type PalletId = OcwPalletId;
+	type RequestTtl = ConstU32<HOURS>;
+	type MaxExpiriesPerBlock = ConstU32<16>;
+	type MaxCallbackLen = ConstU32<256>;
+	type MaxCallbackWeight = OcwMaxCallbackWeight;
+	type MaxFailureReports = ConstU32<64>;
+	type FailureReportInterval = ConstU32<{ 10 * MINUTES }>;
+	type MaxTopicLen = ConstU32<16>;
+	type MaxTopics = ConstU32<16>;
+	type SubscriptionDeposit = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+	type TopicPeriod = ConstU32<10>;
+	type MaxValuesPerBlock = ConstU32<16>;
+	type AssetId = u32;
+	type MaxAssets = ConstU32<16>;
+	type StalenessThreshold = ConstU32<100>;
+	type OnNewValue = ();
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type RemoteOrigin = frame_system::EnsureNever<()>;
+	type SchedulerOrigin = frame_system::EnsureRoot<AccountId>;
+	type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+	type FeederOrigin = frame_system::EnsureSigned<AccountId>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
 }
 
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		public: <Signature as Verify>::Signer,
+		account: AccountId,
+		nonce: Nonce,
+	) -> Option<(RuntimeCall, <UncheckedExtrinsic as traits::Extrinsic>::SignaturePayload)> {
+		let tip = 0;
+		// The `System::block_number` is initialized with `n+1`,
+		// so the actual block number is `n`.
+		let current_block = System::block_number().saturating_sub(1);
+		let extra: SignedExtra = (
+			frame_system::CheckNonZeroSender::<Runtime>::new(),
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckEra::<Runtime>::from(TemplateModule::tx_era(current_block)),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+			pallet_template::CheckOcwRateLimit::<Runtime>::new(),
+		);
+		let raw_payload = SignedPayload::new(call, extra).ok()?;
+		let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+		let address = <Runtime as frame_system::Config>::Lookup::unlookup(account);
+		let (call, extra, _) = raw_payload.deconstruct();
+		Some((call, (address, signature, extra)))
+	}
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type OverarchingCall = RuntimeCall;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub struct Runtime {
@@ -305,6 +472,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_template::CheckOcwRateLimit<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -319,8 +487,20 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// The storage migrations applied on the next runtime upgrade.
+pub type Migrations = (
+	pallet_template::migrations::v1::MigrateToV1<Runtime>,
+	pallet_template::migrations::v2::MigrateToV2<Runtime>,
+	pallet_template::migrations::v3::MigrateToV3<Runtime>,
+	pallet_template::migrations::v4::MigrateToV4<Runtime>,
+	pallet_template::migrations::v5::MigrateToV5<Runtime>,
+	pallet_template::migrations::v6::MigrateToV6<Runtime>,
+	pallet_template::migrations::v7::MigrateToV7<Runtime>,
+);
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -461,6 +641,41 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_template::runtime_api::OcwApi<Block, AccountId, BlockNumber> for Runtime {
+		fn latest_value() -> Option<u64> {
+			TemplateModule::latest_value()
+		}
+
+		fn round_info() -> pallet_template::RoundInfo<BlockNumber> {
+			TemplateModule::current_round()
+		}
+
+		fn latest_round() -> Option<(pallet_template::RoundIndex, u64)> {
+			TemplateModule::latest_round()
+		}
+
+		fn twap() -> Option<u64> {
+			TemplateModule::twap()
+		}
+
+		fn authorities() -> Vec<AccountId> {
+			TemplateModule::authorities().into_inner()
+		}
+
+		fn pending_tasks() -> Vec<(BlockNumber, u32)> {
+			TemplateModule::pending_tasks()
+		}
+
+		fn can_submit(
+			account: AccountId,
+			round: pallet_template::RoundIndex,
+			value: u64,
+			kind: pallet_template::TransactionType,
+		) -> pallet_template::SubmissionValidity {
+			TemplateModule::can_submit(&account, round, value, kind)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,